@@ -38,7 +38,7 @@ pub fn decoding_ocular_miracle_benchmark(c: &mut Criterion) {
             let level: Level<LevelData> = Level::from_robtop_str(&response).unwrap();
             match level.level_data.level_data {
                 Thunk::Unprocessed(unprocessed) => {
-                    let decoded = base64::decode_config(unprocessed, base64::URL_SAFE).unwrap();
+                    let decoded = base64::decode_config(&*unprocessed, base64::URL_SAFE).unwrap();
                     let mut decompressed = String::new();
                     let mut decoder = GzDecoder::new(&decoded[..]);
 
@@ -58,7 +58,7 @@ pub fn decoding_spacial_rend_benchmark(c: &mut Criterion) {
             let level: Level<LevelData> = Level::from_robtop_str(&response).unwrap();
             match level.level_data.level_data {
                 Thunk::Unprocessed(unprocessed) => {
-                    let decoded = base64::decode_config(unprocessed, base64::URL_SAFE).unwrap();
+                    let decoded = base64::decode_config(&*unprocessed, base64::URL_SAFE).unwrap();
                     let mut decompressed = String::new();
                     let mut decoder = GzDecoder::new(&decoded[..]);
 
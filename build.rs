@@ -199,14 +199,14 @@ impl Index {
             if self.optional {
                 write!(
                     f,
-                    "match internal.index_{} {{None => None, Some(RefThunk::Unprocessed(unproc)) => Some(Thunk::Unprocessed(unproc)), _ \
+                    "match internal.index_{} {{None => None, Some(RefThunk::Unprocessed(unproc)) => Some(Thunk::Unprocessed(Cow::Borrowed(unproc))), _ \
                      => unreachable!()}}",
                     self.value
                 )?;
             } else {
                 write!(
                     f,
-                    "Thunk::Unprocessed(match internal.index_{} {{RefThunk::Unprocessed(unproc) => unproc, _ => unreachable!() }})",
+                    "Thunk::Unprocessed(match internal.index_{} {{RefThunk::Unprocessed(unproc) => Cow::Borrowed(unproc), _ => unreachable!() }})",
                     self.value
                 )?;
             }
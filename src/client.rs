@@ -0,0 +1,426 @@
+//! Module containing an asynchronous client for making requests to the Boomlings servers
+//!
+//! The [`GdClient`] sends the requests from [`request`](crate::request), feeds the server's response
+//! into the matching parser (see [`Endpoint::parse_response`]) and hands out owned models, so the
+//! results can outlive the response body they were parsed from.
+
+use crate::{
+    model::{
+        comment::{level::LevelComment, profile::ProfileComment},
+        level::{
+            online_level::{Level, ListedLevel},
+            score::LevelScore,
+        },
+        message::Message,
+        pack::{Gauntlet, MapPack},
+        user::{profile::Profile, ranked::RankedUser, searched::SearchedUser},
+    },
+    request::{
+        account::{
+            data_server_url, AccountSettingsRequest, AccountUrlRequest, AccountUrlType, AuthenticatedUser, AuthenticationError,
+            BackupAccountRequest, LoginRequest, RegisterAccountRequest, RegistrationError, SyncAccountRequest, SyncResponse,
+        },
+        comment::{CommentHistoryRequest, DeleteCommentRequest, LevelCommentsRequest, ProfileCommentsRequest, UploadCommentRequest},
+        level::{DailyLevelInfo, DailyLevelRequest, LevelLeaderboardRequest, LevelRequest, LevelsRequest},
+        message::{DeleteMessageRequest, DownloadMessageRequest, MessagesRequest, UploadMessageRequest},
+        moderator::SuggestStarsRequest,
+        pack::{GauntletsRequest, MapPacksRequest},
+        user::{LeaderboardRequest, UserRequest, UserSearchRequest},
+        Endpoint, PaginatedEndpoint, ServerConfig, BOOMLINGS, CONTENT_TYPE, URL_FORM_ENCODED,
+    },
+    response::{Page, ResponseError},
+};
+use futures::{stream, Stream, StreamExt};
+use std::{
+    fmt::{Display, Formatter},
+    time::Duration,
+};
+
+/// Enum modelling the different errors that can occur while using a [`GdClient`]
+#[derive(Debug)]
+pub enum ClientError {
+    /// There was an error making the request to the server
+    Request(reqwest::Error),
+
+    /// The response was of the form `"-1"`, which is RobTop's version of `HTTP 404 NOT FOUND`
+    NotFound,
+
+    /// The server's response could not be parsed.
+    ///
+    /// Since a [`ResponseError`] borrows from the response body, which does not outlive the
+    /// request, the error is stored in its rendered form
+    Response(String),
+
+    /// The servers rejected a login attempt
+    Authentication(AuthenticationError),
+
+    /// The servers rejected an account registration
+    Registration(RegistrationError),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::Request(err) => err.fmt(f),
+            ClientError::NotFound => write!(f, "not found"),
+            ClientError::Response(err) => write!(f, "{}", err),
+            ClientError::Authentication(err) => err.fmt(f),
+            ClientError::Registration(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<reqwest::Error> for ClientError {
+    fn from(err: reqwest::Error) -> Self {
+        ClientError::Request(err)
+    }
+}
+
+impl From<ResponseError<'_>> for ClientError {
+    fn from(err: ResponseError<'_>) -> Self {
+        match err {
+            ResponseError::NotFound => ClientError::NotFound,
+            ResponseError::RequestError(err) => ClientError::Request(err),
+            ResponseError::Authentication(err) => ClientError::Authentication(err),
+            ResponseError::Registration(err) => ClientError::Registration(err),
+            err => ClientError::Response(err.to_string()),
+        }
+    }
+}
+
+/// Options controlling how [`GdClient::paginate`] walks through the pages of a paginated endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PaginationOptions {
+    /// The maximum amount of items to yield. If `None`, pages are retrieved until the server
+    /// reports that there are no more items
+    pub limit: Option<usize>,
+
+    /// The time to wait between retrieving two pages, to avoid getting rate limited
+    pub delay: Duration,
+}
+
+impl PaginationOptions {
+    pub const fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub const fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+}
+
+/// Asynchronous client for the Boomlings APIs
+///
+/// Requests are sent to the server described by the client's [`ServerConfig`], which defaults to
+/// [`BOOMLINGS`]. Requests are authenticated with the secrets of that server instead of the ones set
+/// on them, see [`Endpoint::to_string_on`].
+#[derive(Debug, Clone)]
+pub struct GdClient<'a> {
+    client: reqwest::Client,
+    server: ServerConfig<'a>,
+}
+
+impl Default for GdClient<'static> {
+    fn default() -> Self {
+        GdClient::from(reqwest::Client::new())
+    }
+}
+
+impl From<reqwest::Client> for GdClient<'static> {
+    fn from(client: reqwest::Client) -> Self {
+        GdClient { client, server: BOOMLINGS }
+    }
+}
+
+impl GdClient<'static> {
+    /// Constructs a new [`GdClient`] for the Boomlings servers using a default [`reqwest::Client`]
+    pub fn new() -> Self {
+        GdClient::default()
+    }
+}
+
+impl<'a> GdClient<'a> {
+    /// Constructs a new [`GdClient`] sending its requests to the given server
+    pub fn with_server(server: ServerConfig<'a>) -> Self {
+        GdClient {
+            client: reqwest::Client::new(),
+            server,
+        }
+    }
+
+    /// Sets the server this client sends its requests to
+    pub fn server(mut self, server: ServerConfig<'a>) -> Self {
+        self.server = server;
+        self
+    }
+
+    /// The server this client sends its requests to
+    pub fn server_config(&self) -> &ServerConfig<'a> {
+        &self.server
+    }
+
+    /// Sends the given request to this client's server and returns the raw response body
+    pub async fn send<E: Endpoint>(&self, request: &E) -> Result<String, ClientError> {
+        Ok(self
+            .client
+            .post(request.to_url_on(&self.server))
+            .body(request.to_string_on(&self.server))
+            .header(CONTENT_TYPE, URL_FORM_ENCODED)
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?)
+    }
+
+    /// Sends the given request to this client's server, parses the response using
+    /// [`Endpoint::parse_response`] and converts the result into an owned value using the given
+    /// closure
+    pub async fn fetch<E, T, F>(&self, request: &E, into_owned: F) -> Result<T, ClientError>
+    where
+        E: Endpoint,
+        F: for<'r> FnOnce(E::Response<'r>) -> Result<T, ClientError>,
+    {
+        let body = self.send(request).await?;
+        let response = E::parse_response(&body)?;
+
+        into_owned(response)
+    }
+
+    /// Retrieves the items of a paginated endpoint page by page, starting at the request's current
+    /// page, and yields them one by one.
+    ///
+    /// Pages are retrieved until the server reports the last page or the limit set in the
+    /// [`PaginationOptions`] is reached. The first error encountered is yielded and ends the
+    /// stream.
+    ///
+    /// The returned stream is not [`Unpin`], so it needs to be pinned (e.g. via [`Box::pin`] or
+    /// [`futures::pin_mut`]) before calling [`StreamExt::next`] on it.
+    pub fn paginate<'s, E, T, F>(&'s self, request: E, options: PaginationOptions, into_owned: F) -> impl Stream<Item = Result<T, ClientError>> + 's
+    where
+        E: PaginatedEndpoint + 's,
+        T: 's,
+        F: for<'r> FnMut(E::Response<'r>) -> Result<Page<T>, ClientError> + 's,
+    {
+        let pages = stream::unfold(Some((request, into_owned, true)), move |state| async move {
+            let (mut request, mut into_owned, is_first) = state?;
+
+            if !is_first && !options.delay.is_zero() {
+                tokio::time::sleep(options.delay).await;
+            }
+
+            match self.fetch(&request, &mut into_owned).await {
+                Ok(page) => {
+                    let next = if page.is_last() {
+                        None
+                    } else {
+                        request.set_page(request.page_number() + 1);
+
+                        Some((request, into_owned, false))
+                    };
+
+                    Some((page.items.into_iter().map(Ok).collect::<Vec<_>>(), next))
+                },
+                Err(err) => Some((vec![Err(err)], None)),
+            }
+        });
+
+        pages.flat_map(stream::iter).take(options.limit.unwrap_or(usize::MAX))
+    }
+
+    /// Searches for levels matching the given request, walking through all pages of results
+    pub fn search_levels_paginated<'s>(
+        &'s self, request: LevelsRequest<'s>, options: PaginationOptions,
+    ) -> impl Stream<Item = Result<ListedLevel<'static>, ClientError>> + 's {
+        self.paginate(request, options, |levels| Ok(levels.map(ListedLevel::into_owned)))
+    }
+
+    /// Retrieves the comments on a level, walking through all pages of comments
+    pub fn level_comments_paginated<'s>(
+        &'s self, request: LevelCommentsRequest<'s>, options: PaginationOptions,
+    ) -> impl Stream<Item = Result<LevelComment<'static>, ClientError>> + 's {
+        self.paginate(request, options, owned_level_comments)
+    }
+
+    /// Retrieves the comments on a user's profile, walking through all pages of comments
+    pub fn profile_comments_paginated<'s>(
+        &'s self, request: ProfileCommentsRequest<'s>, options: PaginationOptions,
+    ) -> impl Stream<Item = Result<ProfileComment<'static>, ClientError>> + 's {
+        self.paginate(request, options, |comments| Ok(comments.map(ProfileComment::into_owned)))
+    }
+
+    /// Retrieves the list of map packs, walking through all pages of map packs
+    pub fn map_packs_paginated<'s>(
+        &'s self, request: MapPacksRequest<'s>, options: PaginationOptions,
+    ) -> impl Stream<Item = Result<MapPack<'static>, ClientError>> + 's {
+        self.paginate(request, options, |packs| Ok(packs.map(MapPack::into_owned)))
+    }
+
+    /// Retrieves the messages in the authenticated user's inbox or sent folder, walking through all
+    /// pages of messages
+    pub fn messages_paginated<'s>(
+        &'s self, request: MessagesRequest<'s>, options: PaginationOptions,
+    ) -> impl Stream<Item = Result<Message<'static>, ClientError>> + 's {
+        self.paginate(request, options, |messages| Ok(messages.map(Message::into_owned)))
+    }
+
+    /// Logs into the account described by the given request, returning the [`AuthenticatedUser`] to
+    /// use for requests requiring authentication
+    pub async fn login<'r>(&self, request: &LoginRequest<'r>) -> Result<AuthenticatedUser<'r>, ClientError> {
+        let response = self.fetch(request, Ok).await?;
+
+        Ok(request.authenticated_user(&response))
+    }
+
+    /// Creates a new account
+    pub async fn register(&self, request: &RegisterAccountRequest<'_>) -> Result<(), ClientError> {
+        self.fetch(request, Ok).await
+    }
+
+    /// Updates the privacy settings and social media links of an account
+    pub async fn update_account_settings(&self, request: &AccountSettingsRequest<'_>) -> Result<(), ClientError> {
+        self.fetch(request, Ok).await
+    }
+
+    /// Retrieves the base URL of the server holding an account's save data
+    pub async fn account_url(&self, request: &AccountUrlRequest<'_>) -> Result<String, ClientError> {
+        self.fetch(request, |url| Ok(url.to_string())).await
+    }
+
+    /// Looks up the base URL of the data server holding the save data of the given account
+    pub async fn data_server_url(&self, account_id: u64, url_type: AccountUrlType) -> Result<String, ClientError> {
+        let request = AccountUrlRequest::new(account_id).url_type(url_type);
+
+        Ok(data_server_url(&self.account_url(&request).await?))
+    }
+
+    /// A client sending its requests to the server at the given base URL, but otherwise using the
+    /// same secrets and endpoint overrides as this one
+    fn on_base_url<'s>(&self, base_url: &'s str) -> GdClient<'s>
+    where
+        'a: 's,
+    {
+        GdClient {
+            client: self.client.clone(),
+            server: self.server.base_url(base_url),
+        }
+    }
+
+    /// Uploads a backup of an account's save data to its data server
+    pub async fn backup_account(&self, request: &BackupAccountRequest<'_>) -> Result<(), ClientError> {
+        let base_url = self.data_server_url(request.account_id(), AccountUrlType::Backup).await?;
+
+        self.on_base_url(&base_url).fetch(request, Ok).await
+    }
+
+    /// Downloads the backed up save data of an account from its data server
+    pub async fn sync_account(&self, request: &SyncAccountRequest<'_>) -> Result<SyncResponse<'static>, ClientError> {
+        let base_url = self.data_server_url(request.account_id(), AccountUrlType::Sync).await?;
+
+        self.on_base_url(&base_url).fetch(request, |response| Ok(response.into_owned())).await
+    }
+
+    /// Downloads a single level, including its level data
+    pub async fn download_level(&self, request: &LevelRequest<'_>) -> Result<Level<'static>, ClientError> {
+        self.fetch(request, |level| Ok(level.into_owned())).await
+    }
+
+    /// Retrieves the index of the current daily, weekly or event level and the time left until it
+    /// is replaced
+    pub async fn daily_level(&self, request: &DailyLevelRequest<'_>) -> Result<DailyLevelInfo, ClientError> {
+        self.fetch(request, Ok).await
+    }
+
+    /// Retrieves the requested leaderboard of a level
+    pub async fn level_leaderboard(&self, request: &LevelLeaderboardRequest<'_>) -> Result<Vec<LevelScore<'static>>, ClientError> {
+        self.fetch(request, |scores| Ok(scores.into_iter().map(LevelScore::into_owned).collect())).await
+    }
+
+    /// Searches for levels matching the given request
+    pub async fn search_levels(&self, request: &LevelsRequest<'_>) -> Result<Page<ListedLevel<'static>>, ClientError> {
+        self.fetch(request, |levels| Ok(levels.map(ListedLevel::into_owned))).await
+    }
+
+    /// Retrieves a page of map packs
+    pub async fn map_packs(&self, request: &MapPacksRequest<'_>) -> Result<Page<MapPack<'static>>, ClientError> {
+        self.fetch(request, |packs| Ok(packs.map(MapPack::into_owned))).await
+    }
+
+    /// Retrieves the list of all gauntlets
+    pub async fn gauntlets(&self, request: &GauntletsRequest<'_>) -> Result<Vec<Gauntlet>, ClientError> {
+        self.fetch(request, Ok).await
+    }
+
+    /// Retrieves the profile of the user with the requested account ID
+    pub async fn get_user(&self, request: &UserRequest<'_>) -> Result<Profile<'static>, ClientError> {
+        self.fetch(request, |profile| Ok(profile.into_owned())).await
+    }
+
+    /// Searches for the user with the requested name
+    pub async fn search_user(&self, request: &UserSearchRequest<'_>) -> Result<SearchedUser<'static>, ClientError> {
+        self.fetch(request, |user| Ok(user.into_owned())).await
+    }
+
+    /// Retrieves the requested leaderboard
+    pub async fn leaderboard(&self, request: &LeaderboardRequest<'_>) -> Result<Vec<RankedUser<'static>>, ClientError> {
+        self.fetch(request, |users| Ok(users.into_iter().map(RankedUser::into_owned).collect())).await
+    }
+
+    /// Retrieves a page of comments on a level
+    pub async fn level_comments(&self, request: &LevelCommentsRequest<'_>) -> Result<Page<LevelComment<'static>>, ClientError> {
+        self.fetch(request, owned_level_comments).await
+    }
+
+    /// Retrieves a page of comments on a user's profile
+    pub async fn profile_comments(&self, request: &ProfileCommentsRequest<'_>) -> Result<Page<ProfileComment<'static>>, ClientError> {
+        self.fetch(request, |comments| Ok(comments.map(ProfileComment::into_owned))).await
+    }
+
+    /// Retrieves a page of the level comments a user has posted
+    pub async fn comment_history(&self, request: &CommentHistoryRequest<'_>) -> Result<Page<LevelComment<'static>>, ClientError> {
+        self.fetch(request, owned_level_comments).await
+    }
+
+    /// Posts a comment on a level, returning the ID of the new comment
+    pub async fn upload_comment(&self, request: &UploadCommentRequest<'_>) -> Result<u64, ClientError> {
+        self.fetch(request, Ok).await
+    }
+
+    /// Deletes a comment from a level
+    pub async fn delete_comment(&self, request: &DeleteCommentRequest<'_>) -> Result<(), ClientError> {
+        self.fetch(request, Ok).await
+    }
+
+    /// Sends a moderator's rating suggestion for a level
+    pub async fn suggest_stars(&self, request: &SuggestStarsRequest<'_>) -> Result<(), ClientError> {
+        self.fetch(request, Ok).await
+    }
+
+    /// Retrieves a page of messages from the authenticated user's inbox or sent folder
+    pub async fn messages(&self, request: &MessagesRequest<'_>) -> Result<Page<Message<'static>>, ClientError> {
+        self.fetch(request, |messages| Ok(messages.map(Message::into_owned))).await
+    }
+
+    /// Downloads a single message, including its body
+    pub async fn download_message(&self, request: &DownloadMessageRequest<'_>) -> Result<Message<'static>, ClientError> {
+        self.fetch(request, |message| Ok(message.into_owned())).await
+    }
+
+    /// Sends a message to another account
+    pub async fn upload_message(&self, request: &UploadMessageRequest<'_>) -> Result<(), ClientError> {
+        self.fetch(request, Ok).await
+    }
+
+    /// Deletes a message from the authenticated user's inbox or sent folder
+    pub async fn delete_message(&self, request: &DeleteMessageRequest<'_>) -> Result<(), ClientError> {
+        self.fetch(request, Ok).await
+    }
+}
+
+fn owned_level_comments(comments: Page<LevelComment<'_>>) -> Result<Page<LevelComment<'static>>, ClientError> {
+    Ok(comments.map(LevelComment::into_owned))
+}
@@ -30,9 +30,11 @@
     type_alias_bounds
 )]
 
+pub mod client;
 pub mod model;
 pub mod request;
 pub mod response;
+pub mod save;
 pub(crate) mod serde;
 pub mod util;
 
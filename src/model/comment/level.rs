@@ -84,6 +84,28 @@ pub struct LevelComment<'a> {
 
 impl<'a> RobtopForm for LevelComment<'a> {}
 
+impl<'a> LevelComment<'a> {
+    /// Converts this [`LevelComment`] into one that doesn't borrow from the response it was parsed
+    /// from.
+    ///
+    /// The comment's content stays unprocessed if it hasn't been processed yet.
+    pub fn into_owned(self) -> LevelComment<'static> {
+        LevelComment {
+            user: self.user.map(CommentUser::into_owned),
+            level_id: self.level_id,
+            content: self.content.map(|content| content.into_owned(Base64Decoded::into_owned)),
+            user_id: self.user_id,
+            likes: self.likes,
+            comment_id: self.comment_id,
+            is_flagged_spam: self.is_flagged_spam,
+            time_since_post: Cow::Owned(self.time_since_post.into_owned()),
+            progress: self.progress,
+            mod_level: self.mod_level,
+            special_color: self.special_color,
+        }
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
 pub struct CommentUser<'a> {
     /// This [`CommentUser`]'s name
@@ -134,6 +156,22 @@ pub struct CommentUser<'a> {
     pub account_id: Option<u64>,
 }
 
+impl<'a> CommentUser<'a> {
+    /// Converts this [`CommentUser`] into one that doesn't borrow from the response it was parsed
+    /// from.
+    pub fn into_owned(self) -> CommentUser<'static> {
+        CommentUser {
+            name: Cow::Owned(self.name.into_owned()),
+            icon_index: self.icon_index,
+            primary_color: self.primary_color,
+            secondary_color: self.secondary_color,
+            icon_type: self.icon_type,
+            has_glow: self.has_glow,
+            account_id: self.account_id,
+        }
+    }
+}
+
 #[allow(unused_imports)]
 mod internal {
     use std::borrow::{Borrow, Cow};
@@ -216,7 +254,7 @@ mod internal {
                     content: match internal.index_2 {
                         None => None,
                         Some (RefThunk::Unprocessed(unproc)) =>
-                            Some(Thunk::Unprocessed(unproc)),
+                            Some(Thunk::Unprocessed(Cow::Borrowed(unproc))),
                         _ => unreachable!()},
                     level_id: internal.index_1,
                     user_id: internal.index_3,
@@ -32,6 +32,21 @@ pub struct ProfileComment<'a> {
     pub time_since_post: Cow<'a, str>,
 }
 
+impl<'a> ProfileComment<'a> {
+    /// Converts this [`ProfileComment`] into one that doesn't borrow from the response it was
+    /// parsed from.
+    ///
+    /// The comment's content stays unprocessed if it hasn't been processed yet.
+    pub fn into_owned(self) -> ProfileComment<'static> {
+        ProfileComment {
+            content: self.content.map(|content| content.into_owned(Base64Decoded::into_owned)),
+            likes: self.likes,
+            comment_id: self.comment_id,
+            time_since_post: Cow::Owned(self.time_since_post.into_owned()),
+        }
+    }
+}
+
 mod internal {
     use crate::model::comment::profile::ProfileComment;
 
@@ -0,0 +1,367 @@
+//! Module containing structs modelling colors, most notably the color channels of a level
+
+use serde::{Deserialize, Serialize};
+
+/// A color channel of a level, as defined in the level's metadata section
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ColorChannel {
+    /// The ID of this color channel
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `6`. Special channels such as the background or ground have
+    /// IDs starting at `1000`
+    pub index: u16,
+
+    /// The color of this channel
+    ///
+    /// ## GD Internals:
+    /// The red, green and blue components are stored at indices `1`, `2` and `3` respectively
+    pub color: RgbColor,
+
+    /// Whether this channel takes its color from one of the player colors instead of using the
+    /// RGB value above
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `4`
+    pub player_color: PlayerColor,
+
+    /// Whether this channel uses additive blending
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `5`
+    pub blending: bool,
+
+    /// The opacity of this channel, between `0` and `1`
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `7`
+    pub opacity: f32,
+
+    /// Stored at index `8`. Seems to always be `true`
+    pub toggle_opacity: bool,
+
+    /// The ID of the channel whose color this channel copies
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `9`
+    pub copied_color: Option<u16>,
+
+    /// The HSV modification applied to the copied color
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `10`
+    pub hsv: Option<Hsv>,
+
+    /// Whether this channel also copies the opacity of the copied color
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `17`
+    pub copy_opacity: bool,
+
+    /// Unknown value stored at index `11`
+    pub index_11: Option<u8>,
+
+    /// Unknown value stored at index `12`
+    pub index_12: Option<u8>,
+
+    /// Unknown value stored at index `13`
+    pub index_13: Option<u8>,
+
+    /// Unknown value stored at index `15`
+    pub index_15: Option<bool>,
+
+    /// Unknown value stored at index `18`
+    pub index_18: Option<i32>,
+}
+
+/// A color given by its red, green and blue components
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct RgbColor {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+/// Enum modelling the player colors a [`ColorChannel`] can be bound to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PlayerColor {
+    /// The channel is not bound to a player color
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `-1`
+    #[default]
+    None,
+
+    /// The channel uses the player's primary color
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `1`
+    Player1,
+
+    /// The channel uses the player's secondary color
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `2`
+    Player2,
+
+    /// Any other value
+    Unknown(i8),
+}
+
+impl From<i8> for PlayerColor {
+    fn from(value: i8) -> Self {
+        match value {
+            -1 => PlayerColor::None,
+            1 => PlayerColor::Player1,
+            2 => PlayerColor::Player2,
+            value => PlayerColor::Unknown(value),
+        }
+    }
+}
+
+impl From<PlayerColor> for i8 {
+    fn from(color: PlayerColor) -> Self {
+        match color {
+            PlayerColor::None => -1,
+            PlayerColor::Player1 => 1,
+            PlayerColor::Player2 => 2,
+            PlayerColor::Unknown(value) => value,
+        }
+    }
+}
+
+/// A modification of a color in the HSV color space
+///
+/// ## GD Internals:
+/// HSV values are stored as an `a`-separated list of the fields of this struct, in declaration
+/// order, e.g. `60a1.4a1a0a0`
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Hsv {
+    /// The hue shift, in degrees
+    pub hue: i16,
+
+    /// The saturation factor, or the saturation offset if `saturation_additive` is set
+    pub saturation: f32,
+
+    /// The brightness factor, or the brightness offset if `value_additive` is set
+    pub value: f32,
+
+    pub saturation_additive: bool,
+
+    pub value_additive: bool,
+}
+
+mod internal {
+    use crate::{
+        model::level::color::{ColorChannel, Hsv, PlayerColor, RgbColor},
+        serde::{DeError, HasRobtopFormat, IndexedDeserializer, IndexedSerializer, SerError},
+    };
+    use serde::{Deserialize, Serialize};
+    use std::io::Write;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct InternalColorChannel<'src> {
+        #[serde(rename = "1", default)]
+        red: u8,
+
+        #[serde(rename = "2", default)]
+        green: u8,
+
+        #[serde(rename = "3", default)]
+        blue: u8,
+
+        #[serde(rename = "11", default, skip_serializing_if = "Option::is_none")]
+        index_11: Option<u8>,
+
+        #[serde(rename = "12", default, skip_serializing_if = "Option::is_none")]
+        index_12: Option<u8>,
+
+        #[serde(rename = "13", default, skip_serializing_if = "Option::is_none")]
+        index_13: Option<u8>,
+
+        #[serde(rename = "4", default = "minus_one")]
+        player_color: i8,
+
+        #[serde(rename = "6")]
+        index: u16,
+
+        #[serde(rename = "5", default, skip_serializing_if = "is_false")]
+        blending: bool,
+
+        #[serde(rename = "7", default = "one", serialize_with = "crate::util::float_as_display")]
+        opacity: f32,
+
+        #[serde(rename = "15", default, skip_serializing_if = "Option::is_none")]
+        index_15: Option<bool>,
+
+        #[serde(rename = "18", default, skip_serializing_if = "Option::is_none")]
+        index_18: Option<i32>,
+
+        #[serde(rename = "9", default, skip_serializing_if = "Option::is_none")]
+        copied_color: Option<u16>,
+
+        #[serde(rename = "10", default, skip_serializing_if = "Option::is_none")]
+        hsv: Option<&'src str>,
+
+        #[serde(rename = "17", default, skip_serializing_if = "is_false")]
+        copy_opacity: bool,
+
+        #[serde(rename = "8", default)]
+        toggle_opacity: bool,
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct InternalHsv {
+        hue: i16,
+
+        #[serde(serialize_with = "crate::util::float_as_display")]
+        saturation: f32,
+
+        #[serde(serialize_with = "crate::util::float_as_display")]
+        value: f32,
+
+        saturation_additive: bool,
+
+        value_additive: bool,
+    }
+
+    fn minus_one() -> i8 {
+        -1
+    }
+
+    fn one() -> f32 {
+        1.0
+    }
+
+    fn is_false(b: &bool) -> bool {
+        !*b
+    }
+
+    impl<'a> HasRobtopFormat<'a> for ColorChannel {
+        fn from_robtop_str(input: &'a str) -> Result<Self, DeError<'a>> {
+            let internal = InternalColorChannel::deserialize(&mut IndexedDeserializer::new(input, "_", true))?;
+
+            Ok(ColorChannel {
+                index: internal.index,
+                color: RgbColor {
+                    red: internal.red,
+                    green: internal.green,
+                    blue: internal.blue,
+                },
+                player_color: PlayerColor::from(internal.player_color),
+                blending: internal.blending,
+                opacity: internal.opacity,
+                toggle_opacity: internal.toggle_opacity,
+                copied_color: internal.copied_color,
+                hsv: internal.hsv.map(Hsv::from_robtop_str).transpose()?,
+                copy_opacity: internal.copy_opacity,
+                index_11: internal.index_11,
+                index_12: internal.index_12,
+                index_13: internal.index_13,
+                index_15: internal.index_15,
+                index_18: internal.index_18,
+            })
+        }
+
+        fn write_robtop_data<W: Write>(&self, writer: W) -> Result<(), SerError> {
+            let hsv = self.hsv.map(|hsv| hsv.to_robtop_string()).transpose()?;
+            let internal = InternalColorChannel {
+                red: self.color.red,
+                green: self.color.green,
+                blue: self.color.blue,
+                index_11: self.index_11,
+                index_12: self.index_12,
+                index_13: self.index_13,
+                player_color: self.player_color.into(),
+                index: self.index,
+                blending: self.blending,
+                opacity: self.opacity,
+                index_15: self.index_15,
+                index_18: self.index_18,
+                copied_color: self.copied_color,
+                hsv: hsv.as_deref(),
+                copy_opacity: self.copy_opacity,
+                toggle_opacity: self.toggle_opacity,
+            };
+
+            internal.serialize(&mut IndexedSerializer::new("_", writer, true))
+        }
+    }
+
+    impl<'a> HasRobtopFormat<'a> for Hsv {
+        fn from_robtop_str(input: &'a str) -> Result<Self, DeError<'a>> {
+            let internal = InternalHsv::deserialize(&mut IndexedDeserializer::new(input, "a", false))?;
+
+            Ok(Hsv {
+                hue: internal.hue,
+                saturation: internal.saturation,
+                value: internal.value,
+                saturation_additive: internal.saturation_additive,
+                value_additive: internal.value_additive,
+            })
+        }
+
+        fn write_robtop_data<W: Write>(&self, writer: W) -> Result<(), SerError> {
+            let internal = InternalHsv {
+                hue: self.hue,
+                saturation: self.saturation,
+                value: self.value,
+                saturation_additive: self.saturation_additive,
+                value_additive: self.value_additive,
+            };
+
+            internal.serialize(&mut IndexedSerializer::new("a", writer, false))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::level::color::{ColorChannel, Hsv, PlayerColor, RgbColor},
+        HasRobtopFormat,
+    };
+
+    const CHANNEL: &str = "1_143_2_0_3_63_4_-1_6_1_5_1_7_1_15_0_10_0a2a1.52a0a0_8_1";
+    const PLAYER_CHANNEL: &str = "1_255_2_125_3_0_11_255_12_255_13_255_4_1_6_1005_5_1_7_0.5_15_1_8_1";
+
+    #[test]
+    fn deserialize_color_channel() {
+        let channel = ColorChannel::from_robtop_str(CHANNEL).unwrap();
+
+        assert_eq!(channel.index, 1);
+        assert_eq!(channel.color, RgbColor { red: 143, green: 0, blue: 63 });
+        assert_eq!(channel.player_color, PlayerColor::None);
+        assert!(channel.blending);
+        assert_eq!(channel.opacity, 1.0);
+        assert_eq!(
+            channel.hsv,
+            Some(Hsv {
+                hue: 0,
+                saturation: 2.0,
+                value: 1.52,
+                saturation_additive: false,
+                value_additive: false
+            })
+        );
+        assert!(!channel.copy_opacity);
+    }
+
+    #[test]
+    fn deserialize_player_color_channel() {
+        let channel = ColorChannel::from_robtop_str(PLAYER_CHANNEL).unwrap();
+
+        assert_eq!(channel.index, 1005);
+        assert_eq!(channel.player_color, PlayerColor::Player1);
+        assert_eq!(channel.opacity, 0.5);
+        assert_eq!(channel.hsv, None);
+        assert_eq!(channel.index_11, Some(255));
+    }
+
+    #[test]
+    fn color_channel_roundtrip() {
+        for input in &[CHANNEL, PLAYER_CHANNEL] {
+            assert_eq!(ColorChannel::from_robtop_str(input).unwrap().to_robtop_string().unwrap(), *input);
+        }
+    }
+}
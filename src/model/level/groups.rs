@@ -0,0 +1,262 @@
+//! Module for analyzing which objects of a level are affected by which triggers
+//!
+//! Triggers act on groups of objects. Some triggers additionally activate the spawn triggered
+//! triggers in their target group, which in turn act on their own target groups. The
+//! [`TriggerGraph`] models these relations and allows following them through an entire level.
+//!
+//! Objects are referred to by their index in [`Objects::objects`](crate::model::level::local_level::Objects::objects).
+
+use crate::model::level::object::{trigger::Trigger, LevelObject, ObjectData};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Index from group ID to the objects in that group
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GroupIndex {
+    groups: BTreeMap<u16, Vec<usize>>,
+}
+
+impl GroupIndex {
+    /// Builds the group index for the given objects
+    pub fn new(objects: &[LevelObject]) -> GroupIndex {
+        let mut groups = BTreeMap::<u16, Vec<usize>>::new();
+
+        for (index, object) in objects.iter().enumerate() {
+            for &group in &object.groups {
+                groups.entry(group).or_default().push(index);
+            }
+        }
+
+        GroupIndex { groups }
+    }
+
+    /// The indices of the objects in the given group, in the order they appear in the level
+    pub fn objects_in(&self, group: u16) -> &[usize] {
+        self.groups.get(&group).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// The IDs of all groups containing at least one object, in ascending order
+    pub fn groups(&self) -> impl Iterator<Item = u16> + '_ {
+        self.groups.keys().copied()
+    }
+}
+
+/// The graph of triggers and the triggers they activate through their target groups
+///
+/// There is an edge from trigger `a` to trigger `b` if `a` [activates its target
+/// group](Trigger::activates_target) and `b` is a spawn triggered trigger in that group.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriggerGraph<'a> {
+    objects: &'a [LevelObject],
+    groups: GroupIndex,
+    activations: BTreeMap<usize, Vec<usize>>,
+}
+
+impl<'a> TriggerGraph<'a> {
+    /// Builds the trigger graph for the given objects
+    pub fn new(objects: &'a [LevelObject]) -> TriggerGraph<'a> {
+        let groups = GroupIndex::new(objects);
+        let mut activations = BTreeMap::new();
+
+        for (index, object) in objects.iter().enumerate() {
+            if let ObjectData::Trigger { trigger, .. } = object.metadata {
+                let activated = match trigger.target_group() {
+                    Some(group) if trigger.activates_target() =>
+                        groups
+                            .objects_in(group)
+                            .iter()
+                            .copied()
+                            .filter(|&target| is_spawn_triggered(&objects[target]))
+                            .collect(),
+                    _ => Vec::new(),
+                };
+
+                activations.insert(index, activated);
+            }
+        }
+
+        TriggerGraph {
+            objects,
+            groups,
+            activations,
+        }
+    }
+
+    /// The group index this graph was built from
+    pub fn group_index(&self) -> &GroupIndex {
+        &self.groups
+    }
+
+    /// The indices of all triggers in the level, in the order they appear in the level
+    pub fn triggers(&self) -> impl Iterator<Item = usize> + '_ {
+        self.activations.keys().copied()
+    }
+
+    /// The triggers directly activated by the given trigger. Empty if `trigger` is not the index of
+    /// a trigger
+    pub fn activated_by(&self, trigger: usize) -> &[usize] {
+        self.activations.get(&trigger).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Finds all cycles of triggers activating each other
+    ///
+    /// Each returned cycle is a strongly connected component of the graph, given as the sorted
+    /// indices of the triggers in it. A trigger activating itself forms a cycle on its own.
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        let mut cycles = strongly_connected_components(&self.activations)
+            .into_iter()
+            .filter(|component| component.len() > 1 || self.activated_by(component[0]).contains(&component[0]))
+            .map(|mut component| {
+                component.sort_unstable();
+                component
+            })
+            .collect::<Vec<_>>();
+
+        cycles.sort_unstable();
+        cycles
+    }
+
+    /// The groups that contain objects, but are never referenced by any trigger
+    pub fn unused_groups(&self) -> Vec<u16> {
+        let referenced = self.referenced_groups();
+
+        self.groups.groups().filter(|group| !referenced.contains(group)).collect()
+    }
+
+    /// The groups referenced by triggers that do not contain any objects
+    pub fn missing_groups(&self) -> Vec<u16> {
+        self.referenced_groups()
+            .into_iter()
+            .filter(|&group| self.groups.objects_in(group).is_empty())
+            .collect()
+    }
+
+    /// All objects the given trigger ultimately acts on, that is the objects in its target group
+    /// and in the target groups of all triggers it (transitively) activates
+    pub fn touched_by(&self, trigger: usize) -> BTreeSet<usize> {
+        let mut touched = BTreeSet::new();
+        let mut visited = BTreeSet::new();
+        let mut stack = vec![trigger];
+
+        while let Some(current) = stack.pop() {
+            if !visited.insert(current) {
+                continue
+            }
+
+            if let Some(group) = self.trigger(current).and_then(|trigger| trigger.target_group()) {
+                touched.extend(self.groups.objects_in(group));
+            }
+
+            stack.extend(self.activated_by(current));
+        }
+
+        touched
+    }
+
+    fn trigger(&self, index: usize) -> Option<Trigger> {
+        match self.objects.get(index)?.metadata {
+            ObjectData::Trigger { trigger, .. } => Some(trigger),
+            _ => None,
+        }
+    }
+
+    fn referenced_groups(&self) -> BTreeSet<u16> {
+        self.triggers()
+            .filter_map(|index| self.trigger(index))
+            .flat_map(|trigger| trigger.target_group().into_iter().chain(trigger.secondary_group()))
+            .collect()
+    }
+}
+
+fn is_spawn_triggered(object: &LevelObject) -> bool {
+    matches!(object.metadata, ObjectData::Trigger { activation, .. } if activation.spawn_triggered)
+}
+
+/// Computes the strongly connected components of the given graph using Tarjan's algorithm
+///
+/// The algorithm is implemented iteratively, since trigger chains in large levels can get long
+/// enough to overflow the stack otherwise.
+fn strongly_connected_components(graph: &BTreeMap<usize, Vec<usize>>) -> Vec<Vec<usize>> {
+    struct NodeState {
+        index: usize,
+        low_link: usize,
+        on_stack: bool,
+    }
+
+    let mut states = BTreeMap::<usize, NodeState>::new();
+    let mut stack = Vec::new();
+    let mut components = Vec::new();
+    let mut next_index = 0;
+
+    for &root in graph.keys() {
+        if states.contains_key(&root) {
+            continue
+        }
+
+        // (node, index of the next successor to visit)
+        let mut work = vec![(root, 0)];
+
+        while let Some(&(node, next_successor)) = work.last() {
+            if next_successor == 0 && !states.contains_key(&node) {
+                states.insert(node, NodeState {
+                    index: next_index,
+                    low_link: next_index,
+                    on_stack: true,
+                });
+                stack.push(node);
+                next_index += 1;
+            }
+
+            let successors = graph.get(&node).map(Vec::as_slice).unwrap_or(&[]);
+
+            if let Some(&successor) = successors.get(next_successor) {
+                if let Some(top) = work.last_mut() {
+                    top.1 += 1;
+                }
+
+                match states.get(&successor) {
+                    None => work.push((successor, 0)),
+                    Some(state) if state.on_stack => {
+                        let successor_index = state.index;
+                        let state = states.get_mut(&node).unwrap();
+
+                        state.low_link = state.low_link.min(successor_index);
+                    },
+                    Some(_) => {},
+                }
+
+                continue
+            }
+
+            work.pop();
+
+            let (index, low_link) = {
+                let state = &states[&node];
+
+                (state.index, state.low_link)
+            };
+
+            if let Some(&(parent, _)) = work.last() {
+                let state = states.get_mut(&parent).unwrap();
+
+                state.low_link = state.low_link.min(low_link);
+            }
+
+            if index == low_link {
+                let mut component = Vec::new();
+
+                while let Some(member) = stack.pop() {
+                    states.get_mut(&member).unwrap().on_stack = false;
+                    component.push(member);
+
+                    if member == node {
+                        break
+                    }
+                }
+
+                components.push(component);
+            }
+        }
+    }
+
+    components
+}
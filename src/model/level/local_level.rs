@@ -1,23 +1,194 @@
-use crate::model::level::object::speed::Speed;
+use crate::model::level::{
+    color::ColorChannel,
+    object::{game_mode::GameMode, speed::Speed},
+};
 use serde::{Deserialize, Serialize};
-use std::borrow::Cow;
+use std::{borrow::Cow, convert::TryFrom};
 use flate2::read::{GzDecoder, GzEncoder, ZlibDecoder};
 use flate2::Compression;
 use std::io::Read;
-use crate::model::level::{LevelProcessError, Password};
-use crate::{HasRobtopFormat, Thunk, ThunkContent};
-use crate::model::level::object::{LevelObject, ObjectData};
-
-#[derive(Debug, PartialEq, Clone, Default, Copy, Serialize, Deserialize)]
+use crate::model::level::{Featured, LevelLength, LevelProcessError, LevelRating, Password};
+use crate::{Base64Decoded, HasRobtopFormat, Thunk, ThunkContent};
+use crate::model::level::object::LevelObject;
+use crate::model::level::{
+    groups::{GroupIndex, TriggerGraph},
+    timeline::Timeline,
+};
+use crate::model::{
+    level::online_level::Level,
+    song::MainSong,
+    GameVersion,
+};
+use crate::save::{Dictionary, SaveError, Value};
+
+/// The level's metadata section, which is the first object in its level string and holds the
+/// level's settings
+///
+/// ## GD Internals:
+/// The metadata section is a comma separated list of key-value pairs, whose keys are of the form
+/// `kA<n>` or `kS<n>`. Keys not modelled by this struct are kept in [`LevelMetadata::others`], so
+/// that metadata sections written by the game survive a round trip unchanged.
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
 pub struct LevelMetadata {
+    /// The speed the player starts the level with
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `kA4`
     pub starting_speed: Speed,
+
+    /// The game mode the player starts the level in
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `kA2`
+    pub starting_game_mode: GameMode,
+
+    /// Whether the player starts the level in mini mode
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `kA3`
+    pub start_mini: bool,
+
+    /// The offset into the song at which the level starts playing it, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `kA13`
     pub song_offset: f64,
+
+    /// Stored under key `kA15`
     pub song_fade_in: bool,
+
+    /// Stored under key `kA16`
     pub song_fade_out: bool,
+
+    /// The song guidelines placed in the editor
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `kA14`. See [`Guideline`] for the encoding
+    pub guidelines: Vec<Guideline>,
+
+    /// The index of the background texture used by the level
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `kA6`
+    pub background_texture: u8,
+
+    /// The index of the ground texture used by the level
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `kA7`
+    pub ground_texture: u8,
+
+    /// The index of the ground line style used by the level
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `kA17`
+    pub ground_line: u8,
+
+    /// The index of the font used for text objects in the level
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `kA18`
+    pub font: u8,
+
+    /// The page of the color channel selection menu that was last opened in the editor
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `kS39`
+    pub color_page: u32,
+
+    /// Stored under key `kA8`
     pub dual_start: bool,
+
+    /// Whether this metadata section belongs to a start position object instead of a level
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `kA9`
+    pub is_start_position: bool,
+
+    /// Stored under key `kA10`
     pub two_player_controls: bool,
+
+    /// Stored under key `kA11`
     pub start_gravity_inverted: bool,
-    // ... other fields in the metadata section ...
+
+    /// The level's color channels
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `kS38` as a list of channels, each of which is terminated
+    /// by a `|`. Levels created before update 2.0 store their colors under keys `kS1` to `kS20`
+    /// instead, which end up in [`LevelMetadata::others`]
+    pub color_channels: Vec<ColorChannel>,
+
+    /// All key-value pairs of the metadata section not represented by any of the above fields, in
+    /// the order they appeared in
+    ///
+    /// When writing the metadata section, these pairs take the places of the unmodelled pairs in
+    /// [`LevelMetadata::source`], in order. Pairs left over after that are written last.
+    pub others: Vec<(String, String)>,
+
+    /// The metadata section this struct was parsed from. [`None`] if it was not parsed
+    ///
+    /// When writing the metadata section, only the keys appearing in here are written, in the order
+    /// they appear in. Values of fields that still hold the value parsed from here are written
+    /// exactly as they appear in here, so that e.g. `kA13,0.50` is not turned into `kA13,0.5`.
+    /// Fields whose key does not appear in here are only written if they were changed. Metadata
+    /// sections that were not parsed have all keys written in the order the game writes them.
+    pub source: Option<String>,
+}
+
+/// A song guideline placed in the level editor
+///
+/// ## GD Internals:
+/// Guidelines are stored as a `~`-separated list, alternating between the time of a guideline and
+/// its color, e.g. `1.5~0.8~3~0.9~`
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub struct Guideline {
+    /// The time into the song, in seconds, at which this guideline is placed
+    pub time: f64,
+
+    /// The color of this guideline
+    pub color: GuidelineColor,
+}
+
+/// The different colors a [`Guideline`] can have
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum GuidelineColor {
+    /// Represented by the value `0.8`
+    Orange,
+
+    /// Represented by the value `0.9`
+    Yellow,
+
+    /// Represented by the value `1`
+    Green,
+
+    /// Any other value
+    Unknown(f64),
+}
+
+impl From<f64> for GuidelineColor {
+    fn from(value: f64) -> Self {
+        if value == 0.8 {
+            GuidelineColor::Orange
+        } else if value == 0.9 {
+            GuidelineColor::Yellow
+        } else if value == 1.0 {
+            GuidelineColor::Green
+        } else {
+            GuidelineColor::Unknown(value)
+        }
+    }
+}
+
+impl From<GuidelineColor> for f64 {
+    fn from(color: GuidelineColor) -> f64 {
+        match color {
+            GuidelineColor::Orange => 0.8,
+            GuidelineColor::Yellow => 0.9,
+            GuidelineColor::Green => 1.0,
+            GuidelineColor::Unknown(value) => value,
+        }
+    }
 }
 
 /// Struct encapsulating the additional level data returned when actually downloading a level
@@ -78,6 +249,24 @@ pub struct LevelData<'a> {
     pub level_data: Thunk<'a, Objects>,
 }
 
+impl<'a> LevelData<'a> {
+    /// Converts this [`LevelData`] into one that doesn't borrow from the input it was parsed from.
+    ///
+    /// If the level's objects haven't been processed yet, they stay unprocessed.
+    pub fn into_owned(self) -> LevelData<'static> {
+        LevelData {
+            password: self.password,
+            time_since_upload: Cow::Owned(self.time_since_upload.into_owned()),
+            time_since_update: Cow::Owned(self.time_since_update.into_owned()),
+            extra_string: self.extra_string.map(|cow| Cow::Owned(cow.into_owned())),
+            has_low_detail_mode: self.has_low_detail_mode,
+            editor_time: self.editor_time,
+            copy_editor_time: self.copy_editor_time,
+            level_data: self.level_data.into_owned(|objects| objects),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Objects {
     pub meta: LevelMetadata,
@@ -133,6 +322,10 @@ impl<'a> ThunkContent<'a> for Objects {
             .map_err(|err| LevelProcessError::Deserialize(err.to_string()))
     }
 
+    fn from_owned_unprocessed(unprocessed: &str) -> Result<Self, LevelProcessError> {
+        Objects::from_unprocessed(unprocessed)
+    }
+
     fn as_unprocessed(&self) -> Result<Cow<str>, LevelProcessError> {
         let mut bytes = Vec::new();
 
@@ -159,63 +352,443 @@ impl<'a> ThunkContent<'a> for Objects {
 }
 
 impl Objects {
+    /// The time, in seconds, it takes to play through this level, from its start up to the furthest
+    /// object
     pub fn length_in_seconds(&self) -> f32 {
-        let mut portals = Vec::new();
-        let mut furthest_x = 0.0;
+        let furthest_x = self.objects.iter().map(|object| object.x).fold(0.0, f32::max);
 
-        for object in &self.objects {
-            if let ObjectData::SpeedPortal { checked: true, speed } = object.metadata {
-                portals.push((object.x, speed))
-            }
+        self.timeline().time_at(furthest_x)
+    }
 
-            furthest_x = f32::max(furthest_x, object.x);
-        }
+    /// The [`Timeline`] of this level, mapping x positions to the time at which the player reaches
+    /// them when playing the level from its start
+    pub fn timeline(&self) -> Timeline {
+        Timeline::for_level(self)
+    }
 
-        portals.sort_by(|(x1, _), (x2, _)| x1.partial_cmp(x2).unwrap());
+    /// The [`GroupIndex`] of this level, mapping group IDs to the objects in each group
+    pub fn group_index(&self) -> GroupIndex {
+        GroupIndex::new(&self.objects)
+    }
 
-        get_seconds_from_x_pos(furthest_x, self.meta.starting_speed, &portals)
+    /// The [`TriggerGraph`] of this level, describing which triggers activate which other triggers
+    pub fn trigger_graph(&self) -> TriggerGraph<'_> {
+        TriggerGraph::new(&self.objects)
     }
 }
 
-fn get_seconds_from_x_pos(pos: f32, start_speed: Speed, portals: &[(f32, Speed)]) -> f32 {
-    let mut speed: f32 = start_speed.into();
+/// A level as stored in the `CCLocalLevels.dat` save file
+///
+/// Unlike [`Level`], which is parsed from server responses, local levels are stored as plist
+/// dictionaries with keys of the form `k<n>`. Keys not modelled by this struct are kept in
+/// [`LocalLevel::others`], and the dictionary the level was read from is kept in
+/// [`LocalLevel::source`], so that writing an unmodified level back into a save file reproduces its
+/// dictionary exactly.
+#[derive(Debug, PartialEq)]
+pub struct LocalLevel<'a> {
+    /// The ID of the level on the servers, if it has been uploaded
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `k1`
+    pub level_id: Option<u64>,
+
+    /// The name of the level
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `k2`
+    pub name: Cow<'a, str>,
+
+    /// The level's description
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `k3` and base64 encoded
+    pub description: Option<Thunk<'a, Base64Decoded<'a>>>,
+
+    /// The level's actual data. Levels that have never been opened in the editor have no level
+    /// data
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `k4`, and is urlsafe base64 encoded and gzip compressed
+    pub level_data: Option<Thunk<'a, Objects>>,
+
+    /// The name of the level's creator
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `k5`
+    pub creator_name: Cow<'a, str>,
+
+    /// The user ID of the level's creator
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `k6`
+    pub creator_id: u64,
+
+    /// The main song used by the level, if it doesn't use a custom song
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `k8`. If the key is missing, the first main song is used. If
+    /// the level uses a custom song, this is `None` and the value of `k8` (if any) is kept in
+    /// [`LocalLevel::others`]
+    pub main_song: Option<MainSong>,
+
+    /// The Newgrounds ID of the custom song used by the level
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `k45`
+    pub custom_song: Option<u64>,
+
+    /// Whether the level has been verified by its creator
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `k14`
+    pub verified: bool,
+
+    /// Whether the level has been uploaded
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `k15`
+    pub uploaded: bool,
+
+    /// The level's version
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `k16`
+    pub version: u32,
+
+    /// The amount of attempts made on this level
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `k18`
+    pub attempts: u32,
+
+    /// The level's length
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `k23`
+    pub length: LevelLength,
+
+    /// The copy password of the level
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `k41` as a plain number, where `0` means the level is not
+    /// copyable and `1` means it is free to copy
+    pub password: Password,
+
+    /// The ID of the level this one is a copy of
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `k42`
+    pub copy_of: Option<u64>,
+
+    /// Whether the level is a two player level
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `k43`
+    pub is_two_player: bool,
+
+    /// The amount of objects in the level
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `k48`
+    pub object_count: Option<u32>,
+
+    /// The amount of user coins placed in the level
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `k64`
+    pub coin_amount: u8,
+
+    /// The number of seconds this level has been opened in the level editor
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `k80`
+    pub editor_time: Option<u64>,
+
+    /// All entries of the level's dictionary not represented by any of the above fields. This
+    /// includes entries of modelled keys whose value the fields above do not reflect, such as `k8`
+    /// for levels using a custom song, or a `k1` of `0`
+    pub others: Dictionary<'a>,
+
+    /// The dictionary this level was read from, or `None` if the level was constructed otherwise
+    ///
+    /// [`LocalLevel::to_dictionary`] writes the level into a copy of this dictionary, so that its
+    /// entries keep their order and formatting, and keys it did not contain are only added if the
+    /// level sets them to a non-default value
+    pub source: Option<Dictionary<'a>>,
+}
+
+impl<'a> LocalLevel<'a> {
+    /// Reads a level from its dictionary in the `CCLocalLevels.dat` save file
+    ///
+    /// The description and level data are not processed, but borrowed from the dictionary.
+    pub fn from_dictionary(dict: &'a Dictionary<'_>) -> Result<Self, SaveError> {
+        fn string<'a>(dict: &'a Dictionary<'_>, key: &'static str) -> Result<Option<&'a str>, SaveError> {
+            dict.get(key).map(|value| value.as_str().ok_or(SaveError::InvalidValue(key))).transpose()
+        }
+
+        let custom_song = dict.get_int("k45")?.filter(|&song| song != 0);
+
+        let mut level = LocalLevel {
+            level_id: dict.get_int("k1")?.filter(|&id| id != 0),
+            name: Cow::Borrowed(string(dict, "k2")?.ok_or(SaveError::MissingKey("k2"))?),
+            description: string(dict, "k3")?.filter(|desc| !desc.is_empty()).map(|desc| Thunk::Unprocessed(Cow::Borrowed(desc))),
+            level_data: string(dict, "k4")?.filter(|data| !data.is_empty()).map(|data| Thunk::Unprocessed(Cow::Borrowed(data))),
+            creator_name: Cow::Borrowed(string(dict, "k5")?.unwrap_or_default()),
+            creator_id: dict.get_int("k6")?.unwrap_or(0),
+            main_song: match custom_song {
+                Some(_) => None,
+                None => Some(MainSong::from(dict.get_int::<u8>("k8")?.unwrap_or(0))),
+            },
+            custom_song,
+            verified: dict.get("k14").is_some(),
+            uploaded: dict.get("k15").is_some(),
+            version: dict.get_int("k16")?.unwrap_or(1),
+            attempts: dict.get_int("k18")?.unwrap_or(0),
+            length: LevelLength::from(dict.get_int::<u8>("k23")?.unwrap_or(0)),
+            password: match dict.get_int::<u32>("k41")? {
+                None | Some(0) => Password::NoCopy,
+                Some(1) => Password::FreeCopy,
+                Some(password) => Password::PasswordCopy(password),
+            },
+            copy_of: dict.get_int("k42")?.filter(|&id| id != 0),
+            is_two_player: dict.get("k43").is_some(),
+            object_count: dict.get_int("k48")?,
+            coin_amount: dict.get_int("k64")?.unwrap_or(0),
+            editor_time: dict.get_int("k80")?,
+            others: Dictionary::new(),
+            source: Some(dict.clone()),
+        };
 
-    if portals.is_empty() {
-        return pos / speed
+        level.others = dict
+            .iter()
+            .filter(|(key, _)| !level.writes_key(key))
+            .map(|(key, value)| (Cow::Borrowed(key), value.clone()))
+            .collect();
+
+        Ok(level)
     }
 
-    let mut last_obj_pos = 0.0;
-    let mut total_time = 0.0;
+    /// Writes this level into a dictionary suitable for the `CCLocalLevels.dat` save file
+    ///
+    /// If the level was read from a dictionary, this updates a copy of that
+    /// [source](LocalLevel::source) dictionary: entries keep their position, entries of fields that
+    /// are no longer set are removed and new entries are appended. Processed descriptions and level
+    /// data are re-encoded.
+    pub fn to_dictionary(&self) -> Result<Dictionary<'_>, LevelProcessError> {
+        let mut dict = self.source.clone().unwrap_or_default();
+        let entries = self.modelled_entries()?;
+        let is_modelled = |key: &str| entries.iter().any(|(modelled, _)| *modelled == key);
+
+        let stale_keys = dict
+            .iter()
+            .map(|(key, _)| key)
+            .filter(|&key| !is_modelled(key) && self.others.get(key).is_none())
+            .map(str::to_owned)
+            .collect::<Vec<_>>();
+
+        for key in stale_keys {
+            dict.remove(&key);
+        }
+
+        for (key, value) in self.others.iter().filter(|&(key, _)| !is_modelled(key)) {
+            dict.insert(key, value.clone());
+        }
+
+        for (key, value) in entries {
+            dict.insert(key, value);
+        }
+
+        Ok(dict)
+    }
 
-    for (x, portal_speed) in portals {
-        // distance between last portal and this one
-        let current_segment = x - last_obj_pos;
+    /// The entries [`LocalLevel::to_dictionary`] writes for the fields of this level
+    fn modelled_entries(&self) -> Result<Vec<(&'static str, Value<'_>)>, LevelProcessError> {
+        fn int<'a>(int: impl ToString) -> Value<'a> {
+            Value::Integer(Cow::Owned(int.to_string()))
+        }
 
-        // break if we're past the position we want to calculate the position to
-        if pos <= current_segment {
-            break
+        fn string<'a, T: ThunkContent<'a>>(thunk: &'a Option<Thunk<'a, T>>) -> Result<Option<Value<'a>>, LevelProcessError>
+        where
+            LevelProcessError: From<T::Error>,
+        {
+            Ok(match thunk {
+                None => None,
+                Some(Thunk::Unprocessed(unprocessed)) => Some(Value::String(Cow::Borrowed(unprocessed.as_ref()))),
+                Some(Thunk::Processed(processed)) => Some(Value::String(Cow::Owned(processed.as_unprocessed()?.into_owned()))),
+            })
         }
 
-        // Calculate time spent in this segment and add to total time
-        total_time += current_segment / speed;
+        let password = match self.password {
+            Password::NoCopy => None,
+            Password::FreeCopy => Some(int(1)),
+            Password::PasswordCopy(password) => Some(int(password)),
+        };
+
+        let entries = vec![
+            ("k1", self.level_id.map(int)),
+            ("k2", Some(Value::String(Cow::Borrowed(&self.name)))),
+            ("k3", string(&self.description)?),
+            ("k4", string(&self.level_data)?),
+            ("k5", Some(Value::String(Cow::Borrowed(&self.creator_name)))),
+            ("k6", Some(int(self.creator_id))),
+            ("k8", self.main_song.map(|song| int(song.main_song_id))),
+            ("k45", self.custom_song.map(int)),
+            ("k14", Some(Value::True).filter(|_| self.verified)),
+            ("k15", Some(Value::True).filter(|_| self.uploaded)),
+            ("k16", Some(int(self.version))),
+            ("k18", Some(int(self.attempts))),
+            ("k23", Some(int(u8::from(self.length)))),
+            ("k41", password),
+            ("k42", self.copy_of.map(int)),
+            ("k43", Some(Value::True).filter(|_| self.is_two_player)),
+            ("k48", self.object_count.map(int)),
+            ("k64", Some(int(self.coin_amount))),
+            ("k80", self.editor_time.map(int)),
+        ];
+
+        Ok(entries
+            .into_iter()
+            .filter(|(key, _)| self.writes_key(key))
+            .filter_map(|(key, value)| value.map(|value| (key, value)))
+            .collect())
+    }
+
+    /// Whether [`LocalLevel::to_dictionary`] writes an entry for the given key based on the fields of
+    /// this level. Unset fields are not written, and fields set to the value the game assumes if
+    /// their key is missing are only written if the [source](LocalLevel::source) dictionary
+    /// contained their key
+    fn writes_key(&self, key: &str) -> bool {
+        let is_default = match key {
+            "k1" => return self.level_id.is_some(),
+            "k2" => return true,
+            "k3" => return self.description.is_some(),
+            "k4" => return self.level_data.is_some(),
+            "k5" => self.creator_name.is_empty(),
+            "k6" => self.creator_id == 0,
+            "k8" =>
+                match self.main_song {
+                    None => return false,
+                    Some(song) => song.main_song_id == 0,
+                },
+            "k45" => return self.custom_song.is_some(),
+            "k14" => return self.verified,
+            "k15" => return self.uploaded,
+            "k16" => self.version == 1,
+            "k18" => self.attempts == 0,
+            "k23" => u8::from(self.length) == 0,
+            "k41" => return self.password != Password::NoCopy,
+            "k42" => return self.copy_of.is_some(),
+            "k43" => return self.is_two_player,
+            "k48" => return self.object_count.is_some(),
+            "k64" => self.coin_amount == 0,
+            "k80" => return self.editor_time.is_some(),
+            _ => return false,
+        };
+
+        !is_default ||
+            match self.source {
+                None => true,
+                Some(ref source) => source.get(key).is_some(),
+            }
+    }
+}
 
-        speed = (*portal_speed).into();
+impl<'a> TryFrom<LocalLevel<'a>> for Level<'a> {
+    type Error = SaveError;
 
-        last_obj_pos = *x;
+    /// Converts a local level into the representation used for levels retrieved from the servers.
+    ///
+    /// Values only available for uploaded levels (likes, downloads, rating, ...) are set to the
+    /// values of an unrated level, and the level is considered to be made for Geometry Dash 2.1.
+    /// Fails if the level's version does not fit into [`Level::version`]
+    fn try_from(level: LocalLevel<'a>) -> Result<Self, SaveError> {
+        let (password, editor_time) = (level.password, level.editor_time);
+
+        Ok(Level {
+            level_id: level.level_id.unwrap_or(0),
+            name: level.name,
+            description: level.description,
+            version: u8::try_from(level.version).map_err(|_| SaveError::InvalidValue("k16"))?,
+            creator: level.creator_id,
+            downloads: 0,
+            set_completes: None,
+            main_song: level.main_song,
+            game_version: GameVersion::Version { major: 2, minor: 1 },
+            likes: 0,
+            length: level.length,
+            stars: 0,
+            feature_score: Featured::NotFeatured,
+            copy_of: level.copy_of,
+            is_two_player: level.is_two_player,
+            custom_song: level.custom_song,
+            coin_amount: level.coin_amount,
+            has_verified_coins: false,
+            stars_requested: None,
+            daily_number: None,
+            is_epic: false,
+            difficulty: LevelRating::NotAvailable,
+            in_gauntlet: None,
+            object_count: level.object_count.map(|count| count.min(u16::MAX as u32) as u16),
+            level_data: level.level_data.map(|level_data| LevelData {
+                password,
+                time_since_upload: Cow::Borrowed(""),
+                time_since_update: Cow::Borrowed(""),
+                extra_string: None,
+                has_low_detail_mode: false,
+                editor_time,
+                copy_editor_time: None,
+                level_data,
+            }),
+        })
     }
+}
 
-    // add the time spent between end and last portal to total time and return
-    (pos - last_obj_pos) / speed + total_time
+impl<'a> From<Level<'a>> for LocalLevel<'a> {
+    /// Converts a level retrieved from the servers into a local level, as the game does when
+    /// copying a level into the editor.
+    ///
+    /// Since [`Level`] only knows the creator's user ID, the creator name is left empty.
+    fn from(level: Level<'a>) -> Self {
+        let (password, editor_time, level_data) = match level.level_data {
+            Some(data) => (data.password, data.editor_time, Some(data.level_data)),
+            None => (Password::NoCopy, None, None),
+        };
+
+        LocalLevel {
+            level_id: Some(level.level_id).filter(|&id| id != 0),
+            name: level.name,
+            description: level.description,
+            level_data,
+            creator_name: Cow::Borrowed(""),
+            creator_id: level.creator,
+            main_song: level.main_song,
+            custom_song: level.custom_song,
+            verified: false,
+            uploaded: false,
+            version: u32::from(level.version),
+            attempts: 0,
+            length: level.length,
+            password,
+            copy_of: level.copy_of,
+            is_two_player: level.is_two_player,
+            object_count: level.object_count.map(u32::from),
+            coin_amount: level.coin_amount,
+            editor_time,
+            others: Dictionary::new(),
+            source: None,
+        }
+    }
 }
 
 mod internal {
     use std::borrow::Cow;
     use crate::{DeError, model::level::{
-        object::speed::Speed,
-        local_level::{LevelMetadata, LevelData},
+        color::ColorChannel,
+        object::{game_mode::GameMode, speed::Speed},
+        local_level::{Guideline, GuidelineColor, LevelMetadata, LevelData},
     }, serde::{HasRobtopFormat, IndexedDeserializer, IndexedSerializer, SerError}, Thunk};
     use serde::{Deserialize, Serialize};
-    use std::io::Write;
+    use std::{fmt::Display, io::Write, str::FromStr};
     use crate::model::level::local_level::Objects;
     use crate::model::level::Password;
     use crate::serde::{Internal, RefThunk};
@@ -226,7 +799,7 @@ mod internal {
 
             Ok(Self {
                 level_data: match internal.index_4 {
-                    RefThunk::Unprocessed(unproc) => Thunk::Unprocessed(unproc),
+                    RefThunk::Unprocessed(unproc) => Thunk::Unprocessed(Cow::Borrowed(unproc)),
                     _ => unreachable!()
                 },
                 password: internal.index_27.0,
@@ -255,48 +828,205 @@ mod internal {
         }
     }
 
+    impl LevelMetadata {
+        /// The key-value pairs of the fields of this metadata section, in the order the game writes
+        /// them. The color channels are left out if there are none
+        fn modelled_pairs(&self) -> Result<Vec<(&'static str, String)>, SerError> {
+            let mut pairs = Vec::with_capacity(17);
+
+            if !self.color_channels.is_empty() {
+                let mut channels = Vec::new();
+
+                for channel in &self.color_channels {
+                    channel.write_robtop_data(&mut channels)?;
+                    channels.push(b'|');
+                }
+
+                pairs.push(("kS38", String::from_utf8(channels)?));
+            }
+
+            let mut guidelines = String::new();
+
+            for guideline in &self.guidelines {
+                guidelines.push_str(&format!("{}~{}~", guideline.time, f64::from(guideline.color)));
+            }
+
+            pairs.extend([
+                ("kA13", self.song_offset.to_string()),
+                ("kA15", (self.song_fade_in as u8).to_string()),
+                ("kA16", (self.song_fade_out as u8).to_string()),
+                ("kA14", guidelines),
+                ("kA6", self.background_texture.to_string()),
+                ("kA7", self.ground_texture.to_string()),
+                ("kA17", self.ground_line.to_string()),
+                ("kA18", self.font.to_string()),
+                ("kS39", self.color_page.to_string()),
+                ("kA2", u8::from(self.starting_game_mode).to_string()),
+                ("kA3", (self.start_mini as u8).to_string()),
+                ("kA8", (self.dual_start as u8).to_string()),
+                ("kA4", u8::from(self.starting_speed).to_string()),
+                ("kA9", (self.is_start_position as u8).to_string()),
+                ("kA10", (self.two_player_controls as u8).to_string()),
+                ("kA11", (self.start_gravity_inverted as u8).to_string()),
+            ]);
+
+            Ok(pairs)
+        }
+    }
+
+    /// Whether the given key is represented by one of the fields of [`LevelMetadata`]
+    fn is_modelled(key: &str) -> bool {
+        matches!(
+            key,
+            "kS38" | "kA13" | "kA15" | "kA16" | "kA14" | "kA6" | "kA7" | "kA17" | "kA18" | "kS39" | "kA2" | "kA3" | "kA8" | "kA4" | "kA9" | "kA10" | "kA11"
+        )
+    }
+
+    /// Looks up the value written for the given key in the given pairs
+    fn lookup<'a>(pairs: &'a [(&str, String)], key: &str) -> Option<&'a str> {
+        pairs.iter().find(|(pair_key, _)| *pair_key == key).map(|(_, value)| value.as_str())
+    }
+
     impl<'a> HasRobtopFormat<'a> for LevelMetadata {
         fn from_robtop_str(input: &'a str) -> Result<Self, DeError> {
-            let int = InternalLevelMetadata::deserialize(&mut IndexedDeserializer::new(input, ",", true))?;
-
-            Ok(LevelMetadata {
-                starting_speed: match int.starting_speed {
-                    0 => Speed::Slow,
-                    1 => Speed::Normal,
-                    2 => Speed::Medium,
-                    3 => Speed::Fast,
-                    4 => Speed::VeryFast,
-                    unknown => Speed::Unknown(unknown),
-                },
-                song_offset: int.song_offset,
-                song_fade_in: int.song_fade_in,
-                song_fade_out: int.song_fade_out,
-                dual_start: int.dual_start,
-                two_player_controls: int.two_player_controls,
-                start_gravity_inverted: int.start_gravity_inverted,
-            })
+            let mut meta = LevelMetadata {
+                source: Some(input.to_owned()),
+                ..LevelMetadata::default()
+            };
+
+            if input.is_empty() {
+                return Ok(meta)
+            }
+
+            let mut tokens = input.split(',');
+
+            while let Some(key) = tokens.next() {
+                let value = tokens.next().ok_or(DeError::Eof)?;
+
+                match key {
+                    "kA4" => meta.starting_speed = Speed::from(parse::<u8>(key, value)?),
+                    "kA2" => meta.starting_game_mode = GameMode::from(parse::<u8>(key, value)?),
+                    "kA3" => meta.start_mini = parse_bool(key, value)?,
+                    "kA13" => meta.song_offset = parse(key, value)?,
+                    "kA15" => meta.song_fade_in = parse_bool(key, value)?,
+                    "kA16" => meta.song_fade_out = parse_bool(key, value)?,
+                    "kA14" => meta.guidelines = parse_guidelines(key, value)?,
+                    "kA6" => meta.background_texture = parse(key, value)?,
+                    "kA7" => meta.ground_texture = parse(key, value)?,
+                    "kA17" => meta.ground_line = parse(key, value)?,
+                    "kA18" => meta.font = parse(key, value)?,
+                    "kS39" => meta.color_page = parse(key, value)?,
+                    "kA8" => meta.dual_start = parse_bool(key, value)?,
+                    "kA9" => meta.is_start_position = parse_bool(key, value)?,
+                    "kA10" => meta.two_player_controls = parse_bool(key, value)?,
+                    "kA11" => meta.start_gravity_inverted = parse_bool(key, value)?,
+                    "kS38" => meta.color_channels = value.split_terminator('|').map(ColorChannel::from_robtop_str).collect::<Result<_, _>>()?,
+                    _ => meta.others.push((key.to_owned(), value.to_owned())),
+                }
+            }
+
+            Ok(meta)
         }
 
-        fn write_robtop_data<W: Write>(&self, writer: W) -> Result<(), SerError> {
-            let internal = InternalLevelMetadata {
-                starting_speed: match self.starting_speed {
-                    Speed::Slow => 0,
-                    Speed::Normal => 1,
-                    Speed::Medium => 2,
-                    Speed::Fast => 3,
-                    Speed::VeryFast => 4,
-                    Speed::Unknown(unknown) => unknown,
+        fn write_robtop_data<W: Write>(&self, mut writer: W) -> Result<(), SerError> {
+            let modelled = self.modelled_pairs()?;
+            let mut others = self.others.iter().map(|(key, value)| (key.as_str(), value.as_str()));
+            let mut pairs = Vec::with_capacity(modelled.len() + self.others.len());
+
+            match self.source {
+                None => pairs.extend(modelled.iter().map(|(key, value)| (*key, value.as_str()))),
+                Some(ref source) => {
+                    // To find out which fields were changed since parsing, the fields parsed from the
+                    // source are serialized as well
+                    let original = LevelMetadata::from_robtop_str(source).map_err(|err| SerError::Custom(err.to_string()))?;
+                    let original = original.modelled_pairs()?;
+                    let mut source_keys = Vec::new();
+                    let mut tokens = source.split(',');
+
+                    while let (Some(key), Some(raw_value)) = (tokens.next(), tokens.next()) {
+                        if !is_modelled(key) {
+                            // The pairs in `others` take the places of the unmodelled pairs
+                            pairs.extend(others.next());
+                        } else {
+                            source_keys.push(key);
+
+                            // Unchanged fields keep their original formatting
+                            match lookup(&modelled, key) {
+                                Some(value) if Some(value) != lookup(&original, key) => pairs.push((key, value)),
+                                Some(_) => pairs.push((key, raw_value)),
+                                None => (),
+                            }
+                        }
+                    }
+
+                    // Fields whose key is not in the source are only written if they were changed
+                    pairs.extend(
+                        modelled
+                            .iter()
+                            .filter(|(key, value)| !source_keys.contains(key) && lookup(&original, key) != Some(value.as_str()))
+                            .map(|(key, value)| (*key, value.as_str())),
+                    );
                 },
-                song_offset: self.song_offset,
-                song_fade_in: self.song_fade_in,
-                song_fade_out: self.song_fade_out,
-                dual_start: self.dual_start,
-                two_player_controls: self.two_player_controls,
-                start_gravity_inverted: self.start_gravity_inverted,
-            };
+            }
+
+            pairs.extend(others);
+
+            for (index, (key, value)) in pairs.into_iter().enumerate() {
+                if index != 0 {
+                    writer.write_all(b",")?;
+                }
 
-            internal.serialize(&mut IndexedSerializer::new(",", writer, true))
+                write!(writer, "{},{}", key, value)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    fn parse<'de, T: FromStr>(key: &'de str, value: &'de str) -> Result<T, DeError<'de>>
+    where
+        T::Err: Display,
+    {
+        value.parse().map_err(|err: T::Err| {
+            DeError::Custom {
+                message: err.to_string(),
+                index: Some(key),
+                value: Some(value),
+            }
+        })
+    }
+
+    fn parse_bool<'de>(key: &'de str, value: &'de str) -> Result<bool, DeError<'de>> {
+        match value {
+            "0" | "" => Ok(false),
+            "1" | "2" | "10" => Ok(true),
+            _ =>
+                Err(DeError::Custom {
+                    message: "Expected 0, 1, 2, 10 or the empty string".to_owned(),
+                    index: Some(key),
+                    value: Some(value),
+                }),
+        }
+    }
+
+    fn parse_guidelines<'de>(key: &'de str, value: &'de str) -> Result<Vec<Guideline>, DeError<'de>> {
+        let mut guidelines = Vec::new();
+        let mut tokens = value.split_terminator('~');
+
+        while let Some(time) = tokens.next() {
+            let color = tokens.next().ok_or(DeError::Custom {
+                message: "Guideline without color".to_owned(),
+                index: Some(key),
+                value: Some(value),
+            })?;
+
+            guidelines.push(Guideline {
+                time: parse(key, time)?,
+                color: GuidelineColor::from(parse::<f64>(key, color)?),
+            })
         }
+
+        Ok(guidelines)
     }
 
     #[derive(Debug, Serialize, Deserialize)]
@@ -318,51 +1048,4 @@ mod internal {
         #[serde(rename = "47")]
         index_47: Option<u64>,
     }
-
-    #[derive(Debug, Serialize, Deserialize, Clone, Copy)]
-    pub struct InternalLevelMetadata {
-        #[serde(rename = "kA4", default = "one")]
-        starting_speed: u8,
-
-        #[serde(rename = "kA13", default)]
-        song_offset: f64,
-
-        #[serde(rename = "kA15", default)]
-        song_fade_in: bool,
-
-        #[serde(rename = "kA16", default)]
-        song_fade_out: bool,
-
-        #[serde(rename = "kA8", default)]
-        dual_start: bool,
-
-        #[serde(rename = "kA10", default)]
-        two_player_controls: bool,
-
-        #[serde(rename = "kA11", default)]
-        start_gravity_inverted: bool, //.. other fields
-    }
-
-    fn one() -> u8 {
-        1
-    }
 }
-
-// starting_speed(index = kA4),
-// song_offset(index = kA13),
-// fade_in(index = kA15),
-// fade_out(index = kA16),
-// song guidelines: kA16
-// background texture index: kA6
-// ground texture index: kA7
-// ground line index: kA17
-// font: kA18
-// color page (???): kS39
-// starting game mode: kA2
-// starting size: kA3
-// dual_start(index = kA8),
-// level/start pos (???): kA9
-// two_player_controls(index = kA10),
-// start_gravity_inverted(index = kA11, optional),
-
-
@@ -15,10 +15,14 @@ use crate::{
     serde::{Internal, ProcessError},
 };
 
+pub mod color;
+pub mod groups;
 mod internal;
 pub mod local_level;
 pub mod object;
 pub mod online_level;
+pub mod score;
+pub mod timeline;
 
 /// Enum representing the possible level lengths known to dash-rs
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
@@ -496,6 +500,10 @@ pub enum LevelProcessError {
 
     /// The given level string did not contain a metadata section
     MissingMetadata,
+
+    /// Error while processing one of the level's other [`Thunk`](crate::Thunk)s, such as its
+    /// description
+    Process(ProcessError),
 }
 
 impl Display for LevelProcessError {
@@ -507,12 +515,19 @@ impl Display for LevelProcessError {
             LevelProcessError::UnknownCompression => write!(f, "Unknown compression scheme"),
             LevelProcessError::Compression(inner) => inner.fmt(f),
             LevelProcessError::MissingMetadata => write!(f, "Missing metadata section in level string"),
+            LevelProcessError::Process(inner) => inner.fmt(f),
         }
     }
 }
 
 impl<'a> std::error::Error for LevelProcessError {}
 
+impl From<ProcessError> for LevelProcessError {
+    fn from(err: ProcessError) -> Self {
+        LevelProcessError::Process(err)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use base64::URL_SAFE;
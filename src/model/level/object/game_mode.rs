@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+/// Enum modelling the different game modes a player can be in during gameplay
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GameMode {
+    #[default]
+    Cube,
+    Ship,
+    Ball,
+    Ufo,
+    Wave,
+    Robot,
+    Spider,
+    Swing,
+    Unknown(u8),
+}
+
+impl From<u8> for GameMode {
+    fn from(i: u8) -> Self {
+        match i {
+            0 => GameMode::Cube,
+            1 => GameMode::Ship,
+            2 => GameMode::Ball,
+            3 => GameMode::Ufo,
+            4 => GameMode::Wave,
+            5 => GameMode::Robot,
+            6 => GameMode::Spider,
+            7 => GameMode::Swing,
+            i => GameMode::Unknown(i),
+        }
+    }
+}
+
+impl From<GameMode> for u8 {
+    fn from(mode: GameMode) -> u8 {
+        match mode {
+            GameMode::Cube => 0,
+            GameMode::Ship => 1,
+            GameMode::Ball => 2,
+            GameMode::Ufo => 3,
+            GameMode::Wave => 4,
+            GameMode::Robot => 5,
+            GameMode::Spider => 6,
+            GameMode::Swing => 7,
+            GameMode::Unknown(idx) => idx,
+        }
+    }
+}
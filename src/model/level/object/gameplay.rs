@@ -0,0 +1,60 @@
+//! Module containing the types describing gameplay objects that are not triggers, such as orbs,
+//! pads or start positions
+
+use crate::model::level::object::{game_mode::GameMode, speed::Speed};
+use serde::{Deserialize, Serialize};
+
+/// The settings a start position places the player in
+///
+/// ## GD Internals:
+/// Start positions store their settings under the same `kA*` keys used for the starting settings
+/// in a level's [`LevelMetadata`](crate::model::level::local_level::LevelMetadata)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct StartPosition {
+    /// Stored under key `kA4`
+    pub speed: Speed,
+
+    /// Stored under key `kA2`
+    pub game_mode: GameMode,
+
+    /// Stored under key `kA3`
+    pub mini: bool,
+
+    /// Stored under key `kA8`
+    pub dual: bool,
+
+    /// Stored under key `kA11`
+    pub gravity_inverted: bool,
+}
+
+/// Enum modelling the different jump orbs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Orb {
+    Yellow,
+    Pink,
+    Red,
+    Blue,
+    Green,
+    Black,
+    DashGreen,
+    DashPink,
+}
+
+/// Enum modelling the different jump pads
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Pad {
+    Yellow,
+    Pink,
+    Red,
+    Blue,
+}
+
+/// Enum modelling the different collectible coins
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Coin {
+    /// A secret coin, as found in official levels
+    Secret,
+
+    /// A user coin, which needs to be verified by RobTop to count towards a player's stats
+    User,
+}
@@ -3,3 +3,59 @@ pub const NORMAL_PORTAL: u16 = 201;
 pub const MEDIUM_PORTAL: u16 = 202;
 pub const FAST_PORTAL: u16 = 203;
 pub const VERY_FAST_PORTAL: u16 = 1334;
+
+pub const CUBE_PORTAL: u16 = 12;
+pub const SHIP_PORTAL: u16 = 13;
+pub const BALL_PORTAL: u16 = 47;
+pub const UFO_PORTAL: u16 = 111;
+pub const WAVE_PORTAL: u16 = 660;
+pub const ROBOT_PORTAL: u16 = 745;
+pub const SPIDER_PORTAL: u16 = 1331;
+pub const SWING_PORTAL: u16 = 1933;
+
+pub const NORMAL_GRAVITY_PORTAL: u16 = 10;
+pub const INVERTED_GRAVITY_PORTAL: u16 = 11;
+pub const MIRROR_PORTAL: u16 = 45;
+pub const UNMIRROR_PORTAL: u16 = 46;
+pub const NORMAL_SIZE_PORTAL: u16 = 99;
+pub const MINI_PORTAL: u16 = 101;
+pub const DUAL_PORTAL: u16 = 286;
+pub const SINGLE_PORTAL: u16 = 287;
+pub const TELEPORT_PORTAL: u16 = 747;
+
+pub const START_POSITION: u16 = 31;
+
+pub const YELLOW_ORB: u16 = 36;
+pub const PINK_ORB: u16 = 141;
+pub const RED_ORB: u16 = 1333;
+pub const BLUE_ORB: u16 = 84;
+pub const GREEN_ORB: u16 = 1022;
+pub const BLACK_ORB: u16 = 1330;
+pub const DASH_GREEN_ORB: u16 = 1704;
+pub const DASH_PINK_ORB: u16 = 1751;
+
+pub const YELLOW_PAD: u16 = 35;
+pub const PINK_PAD: u16 = 140;
+pub const RED_PAD: u16 = 1332;
+pub const BLUE_PAD: u16 = 67;
+
+pub const SECRET_COIN: u16 = 142;
+pub const USER_COIN: u16 = 1329;
+
+pub const COLOR_TRIGGER: u16 = 899;
+pub const MOVE_TRIGGER: u16 = 901;
+pub const PULSE_TRIGGER: u16 = 1006;
+pub const ALPHA_TRIGGER: u16 = 1007;
+pub const TOGGLE_TRIGGER: u16 = 1049;
+pub const SPAWN_TRIGGER: u16 = 1268;
+pub const ROTATE_TRIGGER: u16 = 1346;
+pub const FOLLOW_TRIGGER: u16 = 1347;
+pub const SHAKE_TRIGGER: u16 = 1520;
+pub const ANIMATE_TRIGGER: u16 = 1585;
+pub const TOUCH_TRIGGER: u16 = 1595;
+pub const COUNT_TRIGGER: u16 = 1611;
+pub const STOP_TRIGGER: u16 = 1616;
+pub const INSTANT_COUNT_TRIGGER: u16 = 1811;
+pub const ON_DEATH_TRIGGER: u16 = 1812;
+pub const COLLISION_TRIGGER: u16 = 1815;
+pub const PICKUP_TRIGGER: u16 = 1817;
@@ -1,62 +1,1059 @@
 use crate::{
-    model::level::object::{ids, speed::Speed, LevelObject, ObjectData},
+    model::level::{
+        color::{Hsv, PlayerColor},
+        object::{
+            game_mode::GameMode,
+            gameplay::{Coin, Orb, Pad, StartPosition},
+            ids,
+            speed::Speed,
+            trigger::{Comparison, Easing, PulseTarget, ToggleMode, Trigger, TriggerActivation},
+            LevelObject, ObjectData, ZLayer,
+        },
+    },
     serde::{DeError, HasRobtopFormat, IndexedDeserializer, IndexedSerializer, SerError},
 };
 use serde::{Deserialize, Serialize};
 use std::io::Write;
 
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, Default)]
-pub struct InternalLevelObject {
+pub struct InternalLevelObject<'src> {
     #[serde(rename = "1")]
     id: u16,
 
-    #[serde(rename = "2")]
+    #[serde(rename = "2", serialize_with = "crate::util::float_as_display")]
     x: f32,
 
-    #[serde(rename = "3")]
+    #[serde(rename = "3", serialize_with = "crate::util::float_as_display")]
     y: f32,
 
-    #[serde(rename = "4", default)]
+    #[serde(rename = "4", default, skip_serializing_if = "is_default")]
     flipped_x: bool,
 
-    #[serde(rename = "5", default)]
+    #[serde(rename = "5", default, skip_serializing_if = "is_default")]
     flipped_y: bool,
 
-    #[serde(rename = "6", default)]
+    #[serde(rename = "6", default, skip_serializing_if = "is_default", serialize_with = "crate::util::float_as_display")]
     rotation: f32,
 
-    // ... other common fields
-
     // portal related fields
-    #[serde(rename = "13", default)]
+    #[serde(rename = "13", default, skip_serializing_if = "is_default")]
     checked: bool,
+
+    #[serde(rename = "20", default, skip_serializing_if = "is_default")]
+    editor_layer: u16,
+
+    #[serde(rename = "21", default, skip_serializing_if = "is_default")]
+    main_color: u16,
+
+    #[serde(rename = "22", default, skip_serializing_if = "is_default")]
+    detail_color: u16,
+
+    #[serde(rename = "24", default, skip_serializing_if = "is_default")]
+    z_layer: i8,
+
+    #[serde(rename = "25", default, skip_serializing_if = "is_default")]
+    z_order: i16,
+
+    #[serde(rename = "32", default = "one", skip_serializing_if = "is_one", serialize_with = "crate::util::float_as_display")]
+    scale: f32,
+
+    #[serde(rename = "34", default, skip_serializing_if = "is_default")]
+    group_parent: bool,
+
+    #[serde(rename = "41", default, skip_serializing_if = "is_default")]
+    main_hsv_enabled: bool,
+
+    #[serde(rename = "42", default, skip_serializing_if = "is_default")]
+    detail_hsv_enabled: bool,
+
+    #[serde(rename = "43", default, skip_serializing_if = "Option::is_none")]
+    main_hsv: Option<&'src str>,
+
+    #[serde(rename = "44", default, skip_serializing_if = "Option::is_none")]
+    detail_hsv: Option<&'src str>,
+
+    #[serde(rename = "57", default, skip_serializing_if = "Option::is_none")]
+    groups: Option<&'src str>,
+
+    #[serde(rename = "61", default, skip_serializing_if = "is_default")]
+    editor_layer_2: u16,
+
+    #[serde(rename = "64", default, skip_serializing_if = "is_default")]
+    dont_fade: bool,
+
+    #[serde(rename = "67", default, skip_serializing_if = "is_default")]
+    dont_enter: bool,
+
+    #[serde(rename = "103", default, skip_serializing_if = "is_default")]
+    high_detail: bool,
+
+    #[serde(rename = "108", default, skip_serializing_if = "is_default")]
+    linked_group: u32,
 }
 
-impl<'a> HasRobtopFormat<'a> for LevelObject {
-    fn from_robtop_str(input: &'a str) -> Result<Self, DeError> {
+/// The keys only relevant for teleport portals and start positions. Like [`InternalTrigger`], these
+/// are only deserialized in a second pass for the objects they belong to
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default)]
+struct InternalGameplayObject {
+    #[serde(rename = "54", default, skip_serializing_if = "is_default", serialize_with = "crate::util::float_as_display")]
+    teleport_offset: f32,
+
+    #[serde(rename = "kA2", default, skip_serializing_if = "Option::is_none")]
+    game_mode: Option<u8>,
+
+    #[serde(rename = "kA3", default, skip_serializing_if = "Option::is_none")]
+    mini: Option<bool>,
+
+    #[serde(rename = "kA8", default, skip_serializing_if = "Option::is_none")]
+    dual: Option<bool>,
+
+    #[serde(rename = "kA4", default, skip_serializing_if = "Option::is_none")]
+    speed: Option<u8>,
+
+    #[serde(rename = "kA11", default, skip_serializing_if = "Option::is_none")]
+    gravity_inverted: Option<bool>,
+}
+
+/// The keys only relevant for triggers. These are deserialized in a second pass for objects whose ID
+/// belongs to a trigger known to dash-rs, so that they end up in [`LevelObject::others`] for all
+/// other objects (such as legacy color triggers, which use some of the same keys). The same goes
+/// for keys the specific kind of trigger does not use, see [`trigger_keys`]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+struct InternalTrigger<'src> {
+    #[serde(rename = "7", default, skip_serializing_if = "is_default")]
+    red: u8,
+
+    #[serde(rename = "8", default, skip_serializing_if = "is_default")]
+    green: u8,
+
+    #[serde(rename = "9", default, skip_serializing_if = "is_default")]
+    blue: u8,
+
+    #[serde(rename = "10", default, skip_serializing_if = "is_default", serialize_with = "crate::util::float_as_display")]
+    duration: f32,
+
+    #[serde(rename = "11", default, skip_serializing_if = "is_default")]
+    touch_triggered: bool,
+
+    #[serde(rename = "15", default, skip_serializing_if = "is_default")]
+    player_color_1: bool,
+
+    #[serde(rename = "16", default, skip_serializing_if = "is_default")]
+    player_color_2: bool,
+
+    #[serde(rename = "17", default, skip_serializing_if = "is_default")]
+    blending: bool,
+
+    #[serde(rename = "23", default, skip_serializing_if = "is_default")]
+    target_channel: u16,
+
+    #[serde(rename = "28", default, skip_serializing_if = "is_default", serialize_with = "crate::util::float_as_display")]
+    move_x: f32,
+
+    #[serde(rename = "29", default, skip_serializing_if = "is_default", serialize_with = "crate::util::float_as_display")]
+    move_y: f32,
+
+    #[serde(rename = "30", default, skip_serializing_if = "is_default")]
+    easing: u8,
+
+    #[serde(rename = "35", default = "one", skip_serializing_if = "is_one", serialize_with = "crate::util::float_as_display")]
+    opacity: f32,
+
+    #[serde(rename = "45", default, skip_serializing_if = "is_default", serialize_with = "crate::util::float_as_display")]
+    fade_in: f32,
+
+    #[serde(rename = "46", default, skip_serializing_if = "is_default", serialize_with = "crate::util::float_as_display")]
+    hold: f32,
+
+    #[serde(rename = "47", default, skip_serializing_if = "is_default", serialize_with = "crate::util::float_as_display")]
+    fade_out: f32,
+
+    #[serde(rename = "48", default, skip_serializing_if = "is_default")]
+    use_hsv: bool,
+
+    #[serde(rename = "49", default, skip_serializing_if = "Option::is_none")]
+    hsv: Option<&'src str>,
+
+    #[serde(rename = "50", default, skip_serializing_if = "is_default")]
+    copied_color: u16,
+
+    #[serde(rename = "51", default, skip_serializing_if = "is_default")]
+    target_group: u16,
+
+    #[serde(rename = "52", default, skip_serializing_if = "is_default")]
+    pulse_group: bool,
+
+    #[serde(rename = "56", default, skip_serializing_if = "is_default")]
+    activate_group: bool,
+
+    #[serde(rename = "58", default, skip_serializing_if = "is_default")]
+    lock_to_player_x: bool,
+
+    #[serde(rename = "59", default, skip_serializing_if = "is_default")]
+    lock_to_player_y: bool,
+
+    #[serde(rename = "60", default, skip_serializing_if = "is_default")]
+    copy_opacity: bool,
+
+    #[serde(rename = "62", default, skip_serializing_if = "is_default")]
+    spawn_triggered: bool,
+
+    #[serde(rename = "63", default, skip_serializing_if = "is_default", serialize_with = "crate::util::float_as_display")]
+    delay: f32,
+
+    #[serde(rename = "65", default, skip_serializing_if = "is_default")]
+    main_only: bool,
+
+    #[serde(rename = "66", default, skip_serializing_if = "is_default")]
+    detail_only: bool,
+
+    #[serde(rename = "68", default, skip_serializing_if = "is_default", serialize_with = "crate::util::float_as_display")]
+    degrees: f32,
+
+    #[serde(rename = "69", default, skip_serializing_if = "is_default")]
+    full_rotations: i32,
+
+    #[serde(rename = "70", default, skip_serializing_if = "is_default")]
+    lock_rotation: bool,
+
+    #[serde(rename = "71", default, skip_serializing_if = "is_default")]
+    secondary_group: u16,
+
+    #[serde(rename = "72", default, skip_serializing_if = "is_default", serialize_with = "crate::util::float_as_display")]
+    x_modifier: f32,
+
+    #[serde(rename = "73", default, skip_serializing_if = "is_default", serialize_with = "crate::util::float_as_display")]
+    y_modifier: f32,
+
+    #[serde(rename = "75", default, skip_serializing_if = "is_default", serialize_with = "crate::util::float_as_display")]
+    strength: f32,
+
+    #[serde(rename = "76", default, skip_serializing_if = "is_default")]
+    animation: u16,
+
+    #[serde(rename = "77", default, skip_serializing_if = "is_default")]
+    count: i32,
+
+    #[serde(rename = "80", default, skip_serializing_if = "is_default")]
+    item_id: u16,
+
+    #[serde(rename = "81", default, skip_serializing_if = "is_default")]
+    hold_mode: bool,
+
+    #[serde(rename = "82", default, skip_serializing_if = "is_default")]
+    toggle_mode: u8,
+
+    #[serde(rename = "84", default, skip_serializing_if = "is_default", serialize_with = "crate::util::float_as_display")]
+    interval: f32,
+
+    #[serde(rename = "85", default, skip_serializing_if = "is_default", serialize_with = "crate::util::float_as_display")]
+    easing_rate: f32,
+
+    #[serde(rename = "86", default, skip_serializing_if = "is_default")]
+    exclusive: bool,
+
+    #[serde(rename = "87", default, skip_serializing_if = "is_default")]
+    multi_trigger: bool,
+
+    #[serde(rename = "88", default, skip_serializing_if = "is_default")]
+    comparison: u8,
+
+    #[serde(rename = "89", default, skip_serializing_if = "is_default")]
+    dual_mode: bool,
+
+    #[serde(rename = "93", default, skip_serializing_if = "is_default")]
+    trigger_on_exit: bool,
+
+    #[serde(rename = "95", default, skip_serializing_if = "is_default")]
+    block_b: u16,
+
+    #[serde(rename = "104", default, skip_serializing_if = "is_default")]
+    multi_activate: bool,
+}
+
+impl Default for InternalTrigger<'_> {
+    fn default() -> Self {
+        InternalTrigger {
+            red: 0,
+            green: 0,
+            blue: 0,
+            duration: 0.0,
+            touch_triggered: false,
+            player_color_1: false,
+            player_color_2: false,
+            blending: false,
+            target_channel: 0,
+            move_x: 0.0,
+            move_y: 0.0,
+            easing: 0,
+            opacity: 1.0,
+            fade_in: 0.0,
+            hold: 0.0,
+            fade_out: 0.0,
+            use_hsv: false,
+            hsv: None,
+            copied_color: 0,
+            target_group: 0,
+            pulse_group: false,
+            activate_group: false,
+            lock_to_player_x: false,
+            lock_to_player_y: false,
+            copy_opacity: false,
+            spawn_triggered: false,
+            delay: 0.0,
+            main_only: false,
+            detail_only: false,
+            degrees: 0.0,
+            full_rotations: 0,
+            lock_rotation: false,
+            secondary_group: 0,
+            x_modifier: 0.0,
+            y_modifier: 0.0,
+            strength: 0.0,
+            animation: 0,
+            count: 0,
+            item_id: 0,
+            hold_mode: false,
+            toggle_mode: 0,
+            interval: 0.0,
+            easing_rate: 0.0,
+            exclusive: false,
+            multi_trigger: false,
+            comparison: 0,
+            dual_mode: false,
+            trigger_on_exit: false,
+            block_b: 0,
+            multi_activate: false,
+        }
+    }
+}
+
+impl<'src> InternalTrigger<'src> {
+    /// Converts these values into the [`Trigger`] with the given object ID, which has to belong to
+    /// a trigger known to dash-rs
+    fn to_trigger(self, id: u16) -> Result<Trigger, DeError<'src>> {
+        let copied_color = Some(self.copied_color).filter(|&channel| channel != 0);
+
+        let trigger = match id {
+            ids::MOVE_TRIGGER =>
+                Trigger::Move {
+                    target_group: self.target_group,
+                    duration: self.duration,
+                    easing: Easing::from(self.easing),
+                    easing_rate: self.easing_rate,
+                    move_x: self.move_x,
+                    move_y: self.move_y,
+                    lock_to_player_x: self.lock_to_player_x,
+                    lock_to_player_y: self.lock_to_player_y,
+                },
+            ids::COLOR_TRIGGER =>
+                Trigger::Color {
+                    target_channel: self.target_channel,
+                    duration: self.duration,
+                    red: self.red,
+                    green: self.green,
+                    blue: self.blue,
+                    opacity: self.opacity,
+                    blending: self.blending,
+                    player_color: match (self.player_color_1, self.player_color_2) {
+                        (true, _) => PlayerColor::Player1,
+                        (false, true) => PlayerColor::Player2,
+                        (false, false) => PlayerColor::None,
+                    },
+                    copied_color,
+                    hsv: self.hsv.map(Hsv::from_robtop_str).transpose()?,
+                    copy_opacity: self.copy_opacity,
+                },
+            ids::ALPHA_TRIGGER =>
+                Trigger::Alpha {
+                    target_group: self.target_group,
+                    duration: self.duration,
+                    opacity: self.opacity,
+                },
+            ids::TOGGLE_TRIGGER =>
+                Trigger::Toggle {
+                    target_group: self.target_group,
+                    activate_group: self.activate_group,
+                },
+            ids::PULSE_TRIGGER =>
+                Trigger::Pulse {
+                    target: if self.pulse_group {
+                        PulseTarget::Group(self.target_group)
+                    } else {
+                        PulseTarget::Channel(self.target_group)
+                    },
+                    fade_in: self.fade_in,
+                    hold: self.hold,
+                    fade_out: self.fade_out,
+                    red: self.red,
+                    green: self.green,
+                    blue: self.blue,
+                    use_hsv: self.use_hsv,
+                    copied_color,
+                    hsv: self.hsv.map(Hsv::from_robtop_str).transpose()?,
+                    main_only: self.main_only,
+                    detail_only: self.detail_only,
+                    exclusive: self.exclusive,
+                },
+            ids::SPAWN_TRIGGER =>
+                Trigger::Spawn {
+                    target_group: self.target_group,
+                    delay: self.delay,
+                },
+            ids::ROTATE_TRIGGER =>
+                Trigger::Rotate {
+                    target_group: self.target_group,
+                    center_group: self.secondary_group,
+                    duration: self.duration,
+                    easing: Easing::from(self.easing),
+                    easing_rate: self.easing_rate,
+                    degrees: self.degrees,
+                    full_rotations: self.full_rotations,
+                    lock_rotation: self.lock_rotation,
+                },
+            ids::FOLLOW_TRIGGER =>
+                Trigger::Follow {
+                    target_group: self.target_group,
+                    follow_group: self.secondary_group,
+                    duration: self.duration,
+                    x_modifier: self.x_modifier,
+                    y_modifier: self.y_modifier,
+                },
+            ids::SHAKE_TRIGGER =>
+                Trigger::Shake {
+                    duration: self.duration,
+                    strength: self.strength,
+                    interval: self.interval,
+                },
+            ids::ANIMATE_TRIGGER =>
+                Trigger::Animate {
+                    target_group: self.target_group,
+                    animation: self.animation,
+                },
+            ids::TOUCH_TRIGGER =>
+                Trigger::Touch {
+                    target_group: self.target_group,
+                    hold_mode: self.hold_mode,
+                    toggle_mode: ToggleMode::from(self.toggle_mode),
+                    dual_mode: self.dual_mode,
+                },
+            ids::COUNT_TRIGGER =>
+                Trigger::Count {
+                    item_id: self.item_id,
+                    target_count: self.count,
+                    target_group: self.target_group,
+                    activate_group: self.activate_group,
+                    multi_activate: self.multi_activate,
+                },
+            ids::INSTANT_COUNT_TRIGGER =>
+                Trigger::InstantCount {
+                    item_id: self.item_id,
+                    target_count: self.count,
+                    target_group: self.target_group,
+                    activate_group: self.activate_group,
+                    comparison: Comparison::from(self.comparison),
+                },
+            ids::COLLISION_TRIGGER =>
+                Trigger::Collision {
+                    block_a: self.item_id,
+                    block_b: self.block_b,
+                    target_group: self.target_group,
+                    activate_group: self.activate_group,
+                    trigger_on_exit: self.trigger_on_exit,
+                },
+            ids::STOP_TRIGGER =>
+                Trigger::Stop {
+                    target_group: self.target_group,
+                },
+            ids::ON_DEATH_TRIGGER =>
+                Trigger::OnDeath {
+                    target_group: self.target_group,
+                    activate_group: self.activate_group,
+                },
+            ids::PICKUP_TRIGGER =>
+                Trigger::Pickup {
+                    item_id: self.item_id,
+                    count: self.count,
+                },
+            _ => unreachable!("{} is not the ID of a known trigger", id),
+        };
+
+        Ok(trigger)
+    }
+
+    /// Constructs the values to write for the given trigger. `hsv` is the already serialized HSV
+    /// value of the trigger, if any
+    fn from_trigger(trigger: &Trigger, activation: TriggerActivation, hsv: Option<&'src str>) -> Self {
+        let mut internal = InternalTrigger {
+            touch_triggered: activation.touch_triggered,
+            spawn_triggered: activation.spawn_triggered,
+            multi_trigger: activation.multi_trigger,
+            hsv,
+            ..InternalTrigger::default()
+        };
+
+        match *trigger {
+            Trigger::Move {
+                target_group,
+                duration,
+                easing,
+                easing_rate,
+                move_x,
+                move_y,
+                lock_to_player_x,
+                lock_to_player_y,
+            } => {
+                internal.target_group = target_group;
+                internal.duration = duration;
+                internal.easing = easing.into();
+                internal.easing_rate = easing_rate;
+                internal.move_x = move_x;
+                internal.move_y = move_y;
+                internal.lock_to_player_x = lock_to_player_x;
+                internal.lock_to_player_y = lock_to_player_y;
+            },
+            Trigger::Color {
+                target_channel,
+                duration,
+                red,
+                green,
+                blue,
+                opacity,
+                blending,
+                player_color,
+                copied_color,
+                copy_opacity,
+                ..
+            } => {
+                internal.target_channel = target_channel;
+                internal.duration = duration;
+                internal.red = red;
+                internal.green = green;
+                internal.blue = blue;
+                internal.opacity = opacity;
+                internal.blending = blending;
+                internal.player_color_1 = player_color == PlayerColor::Player1;
+                internal.player_color_2 = player_color == PlayerColor::Player2;
+                internal.copied_color = copied_color.unwrap_or(0);
+                internal.copy_opacity = copy_opacity;
+            },
+            Trigger::Alpha {
+                target_group,
+                duration,
+                opacity,
+            } => {
+                internal.target_group = target_group;
+                internal.duration = duration;
+                internal.opacity = opacity;
+            },
+            Trigger::Toggle {
+                target_group,
+                activate_group,
+            } => {
+                internal.target_group = target_group;
+                internal.activate_group = activate_group;
+            },
+            Trigger::Pulse {
+                target,
+                fade_in,
+                hold,
+                fade_out,
+                red,
+                green,
+                blue,
+                use_hsv,
+                copied_color,
+                main_only,
+                detail_only,
+                exclusive,
+                ..
+            } => {
+                match target {
+                    PulseTarget::Channel(channel) => internal.target_group = channel,
+                    PulseTarget::Group(group) => {
+                        internal.target_group = group;
+                        internal.pulse_group = true;
+                    },
+                }
+                internal.fade_in = fade_in;
+                internal.hold = hold;
+                internal.fade_out = fade_out;
+                internal.red = red;
+                internal.green = green;
+                internal.blue = blue;
+                internal.use_hsv = use_hsv;
+                internal.copied_color = copied_color.unwrap_or(0);
+                internal.main_only = main_only;
+                internal.detail_only = detail_only;
+                internal.exclusive = exclusive;
+            },
+            Trigger::Spawn { target_group, delay } => {
+                internal.target_group = target_group;
+                internal.delay = delay;
+            },
+            Trigger::Rotate {
+                target_group,
+                center_group,
+                duration,
+                easing,
+                easing_rate,
+                degrees,
+                full_rotations,
+                lock_rotation,
+            } => {
+                internal.target_group = target_group;
+                internal.secondary_group = center_group;
+                internal.duration = duration;
+                internal.easing = easing.into();
+                internal.easing_rate = easing_rate;
+                internal.degrees = degrees;
+                internal.full_rotations = full_rotations;
+                internal.lock_rotation = lock_rotation;
+            },
+            Trigger::Follow {
+                target_group,
+                follow_group,
+                duration,
+                x_modifier,
+                y_modifier,
+            } => {
+                internal.target_group = target_group;
+                internal.secondary_group = follow_group;
+                internal.duration = duration;
+                internal.x_modifier = x_modifier;
+                internal.y_modifier = y_modifier;
+            },
+            Trigger::Shake {
+                duration,
+                strength,
+                interval,
+            } => {
+                internal.duration = duration;
+                internal.strength = strength;
+                internal.interval = interval;
+            },
+            Trigger::Animate { target_group, animation } => {
+                internal.target_group = target_group;
+                internal.animation = animation;
+            },
+            Trigger::Touch {
+                target_group,
+                hold_mode,
+                toggle_mode,
+                dual_mode,
+            } => {
+                internal.target_group = target_group;
+                internal.hold_mode = hold_mode;
+                internal.toggle_mode = toggle_mode.into();
+                internal.dual_mode = dual_mode;
+            },
+            Trigger::Count {
+                item_id,
+                target_count,
+                target_group,
+                activate_group,
+                multi_activate,
+            } => {
+                internal.item_id = item_id;
+                internal.count = target_count;
+                internal.target_group = target_group;
+                internal.activate_group = activate_group;
+                internal.multi_activate = multi_activate;
+            },
+            Trigger::InstantCount {
+                item_id,
+                target_count,
+                target_group,
+                activate_group,
+                comparison,
+            } => {
+                internal.item_id = item_id;
+                internal.count = target_count;
+                internal.target_group = target_group;
+                internal.activate_group = activate_group;
+                internal.comparison = comparison.into();
+            },
+            Trigger::Collision {
+                block_a,
+                block_b,
+                target_group,
+                activate_group,
+                trigger_on_exit,
+            } => {
+                internal.item_id = block_a;
+                internal.block_b = block_b;
+                internal.target_group = target_group;
+                internal.activate_group = activate_group;
+                internal.trigger_on_exit = trigger_on_exit;
+            },
+            Trigger::Stop { target_group } => internal.target_group = target_group,
+            Trigger::OnDeath {
+                target_group,
+                activate_group,
+            } => {
+                internal.target_group = target_group;
+                internal.activate_group = activate_group;
+            },
+            Trigger::Pickup { item_id, count } => {
+                internal.item_id = item_id;
+                internal.count = count;
+            },
+        }
+
+        internal
+    }
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+fn is_one(value: &f32) -> bool {
+    *value == 1.0
+}
+
+fn one() -> f32 {
+    1.0
+}
+
+/// Deserializes `T` in a second pass over the given object string
+fn second_pass<'src, T: Deserialize<'src>>(input: &'src str) -> Result<T, DeError<'src>> {
+    T::deserialize(&mut IndexedDeserializer::new(input, ",", true))
+}
+
+/// Splits an object string into its key-value pairs
+fn key_value_pairs(input: &str) -> impl Iterator<Item = (&str, &str)> {
+    let mut tokens = input.split(',');
+
+    std::iter::from_fn(move || Some((tokens.next()?, tokens.next()?)))
+}
+
+/// Looks up the value of the given key in a list of key-value pairs sorted by key
+fn lookup<'a>(sorted_pairs: &[(&str, &'a str)], key: &str) -> Option<&'a str> {
+    sorted_pairs
+        .binary_search_by_key(&key, |&(pair_key, _)| pair_key)
+        .ok()
+        .map(|index| sorted_pairs[index].1)
+}
+
+/// Whether the given key is represented by one of the fields of a [`LevelObject`] with the given
+/// metadata. All other keys end up in [`LevelObject::others`]
+fn is_modelled(key: &str, metadata: &ObjectData) -> bool {
+    if matches!(
+        key,
+        "1" | "2" | "3" | "4" | "5" | "6" | "20" | "21" | "22" | "24" | "25" | "32" | "34" | "41" | "42" | "43" | "44" | "57" | "61" | "64"
+            | "67" | "103" | "108"
+    ) {
+        return true;
+    }
+
+    match *metadata {
+        ObjectData::None | ObjectData::Unknown | ObjectData::Orb(_) | ObjectData::Pad(_) | ObjectData::Coin(_) => false,
+        ObjectData::SpeedPortal { .. }
+        | ObjectData::GameModePortal { .. }
+        | ObjectData::GravityPortal { .. }
+        | ObjectData::MirrorPortal { .. }
+        | ObjectData::DualPortal { .. }
+        | ObjectData::SizePortal { .. } => key == "13",
+        ObjectData::TeleportPortal { .. } => matches!(key, "13" | "54"),
+        ObjectData::StartPosition(_) => matches!(key, "kA2" | "kA3" | "kA8" | "kA4" | "kA11"),
+        ObjectData::Trigger { trigger: ref kind, .. } => matches!(key, "11" | "62" | "87") || trigger_keys(kind).contains(&key),
+    }
+}
+
+/// The keys [`InternalTrigger::from_trigger`] writes for the given kind of trigger, apart from the
+/// ones describing its [`TriggerActivation`]
+fn trigger_keys(trigger: &Trigger) -> &'static [&'static str] {
+    match trigger {
+        Trigger::Move { .. } => &["51", "10", "30", "85", "28", "29", "58", "59"],
+        Trigger::Color { .. } => &["23", "10", "7", "8", "9", "35", "17", "15", "16", "50", "49", "60"],
+        Trigger::Alpha { .. } => &["51", "10", "35"],
+        Trigger::Toggle { .. } => &["51", "56"],
+        Trigger::Pulse { .. } => &["51", "52", "45", "46", "47", "7", "8", "9", "48", "50", "49", "65", "66", "86"],
+        Trigger::Spawn { .. } => &["51", "63"],
+        Trigger::Rotate { .. } => &["51", "71", "10", "30", "85", "68", "69", "70"],
+        Trigger::Follow { .. } => &["51", "71", "10", "72", "73"],
+        Trigger::Shake { .. } => &["10", "75", "84"],
+        Trigger::Animate { .. } => &["51", "76"],
+        Trigger::Touch { .. } => &["51", "81", "82", "89"],
+        Trigger::Count { .. } => &["80", "77", "51", "56", "104"],
+        Trigger::InstantCount { .. } => &["80", "77", "51", "56", "88"],
+        Trigger::Collision { .. } => &["80", "95", "51", "56", "93"],
+        Trigger::Stop { .. } => &["51"],
+        Trigger::OnDeath { .. } => &["51", "56"],
+        Trigger::Pickup { .. } => &["80", "77"],
+    }
+}
+
+impl LevelObject {
+    /// Serializes the fields of this object, without [`LevelObject::others`], into a comma
+    /// separated list of key-value pairs. Keys whose value is the default are left out
+    fn modelled_string(&self) -> Result<String, SerError> {
+        let main_hsv = self.main_hsv.map(|hsv| hsv.to_robtop_string()).transpose()?;
+        let detail_hsv = self.detail_hsv.map(|hsv| hsv.to_robtop_string()).transpose()?;
+        let groups = self.groups.iter().map(ToString::to_string).collect::<Vec<_>>().join(".");
+
+        let mut internal = InternalLevelObject {
+            id: self.id,
+            x: self.x,
+            y: self.y,
+            flipped_x: self.flipped_x,
+            flipped_y: self.flipped_y,
+            rotation: self.rotation,
+            editor_layer: self.editor_layer,
+            main_color: self.main_color,
+            detail_color: self.detail_color,
+            z_layer: self.z_layer.map(i8::from).unwrap_or(0),
+            z_order: self.z_order,
+            scale: self.scale,
+            group_parent: self.group_parent,
+            main_hsv_enabled: self.main_hsv_enabled,
+            detail_hsv_enabled: self.detail_hsv_enabled,
+            main_hsv: main_hsv.as_deref(),
+            detail_hsv: detail_hsv.as_deref(),
+            groups: Some(groups.as_str()).filter(|groups| !groups.is_empty()),
+            editor_layer_2: self.editor_layer_2,
+            dont_fade: self.dont_fade,
+            dont_enter: self.dont_enter,
+            high_detail: self.high_detail,
+            linked_group: self.linked_group.unwrap_or(0),
+            ..InternalLevelObject::default()
+        };
+
+        // Keys only written for some kinds of objects are serialized into a separate buffer, which is
+        // appended after the common keys
+        let mut extra = Vec::new();
+
+        match self.metadata {
+            ObjectData::None | ObjectData::Unknown | ObjectData::Orb(_) | ObjectData::Pad(_) | ObjectData::Coin(_) => {},
+            ObjectData::SpeedPortal { checked, .. }
+            | ObjectData::GameModePortal { checked, .. }
+            | ObjectData::GravityPortal { checked, .. }
+            | ObjectData::MirrorPortal { checked, .. }
+            | ObjectData::DualPortal { checked, .. }
+            | ObjectData::SizePortal { checked, .. } => {
+                internal.checked = checked;
+            },
+            ObjectData::TeleportPortal { checked, y_offset } => {
+                internal.checked = checked;
+
+                InternalGameplayObject {
+                    teleport_offset: y_offset,
+                    ..InternalGameplayObject::default()
+                }
+                .serialize(&mut IndexedSerializer::new(",", &mut extra, true))?;
+            },
+            ObjectData::StartPosition(start_position) => {
+                InternalGameplayObject {
+                    teleport_offset: 0.0,
+                    game_mode: Some(start_position.game_mode.into()),
+                    mini: Some(start_position.mini),
+                    dual: Some(start_position.dual),
+                    speed: Some(start_position.speed.into()),
+                    gravity_inverted: Some(start_position.gravity_inverted),
+                }
+                .serialize(&mut IndexedSerializer::new(",", &mut extra, true))?;
+            },
+            ObjectData::Trigger {
+                activation,
+                trigger: ref kind,
+            } => {
+                let hsv = match *kind {
+                    Trigger::Color { hsv, .. } | Trigger::Pulse { hsv, .. } => hsv.map(|hsv| hsv.to_robtop_string()).transpose()?,
+                    _ => None,
+                };
+
+                InternalTrigger::from_trigger(kind, activation, hsv.as_deref()).serialize(&mut IndexedSerializer::new(",", &mut extra, true))?;
+            },
+        };
+
+        let mut buffer = Vec::new();
+
+        internal.serialize(&mut IndexedSerializer::new(",", &mut buffer, true))?;
+
+        if !extra.is_empty() {
+            buffer.push(b',');
+            buffer.extend(extra);
+        }
+
+        Ok(String::from_utf8(buffer)?)
+    }
+
+    /// Parses the fields of an object from the given object string, without filling
+    /// [`LevelObject::others`] and [`LevelObject::source`]
+    fn from_modelled_keys(input: &str) -> Result<LevelObject, DeError<'_>> {
         let internal = InternalLevelObject::deserialize(&mut IndexedDeserializer::new(input, ",", true))?;
+        let checked = internal.checked;
 
         let metadata = match internal.id {
             ids::SLOW_PORTAL =>
                 ObjectData::SpeedPortal {
-                    checked: internal.checked,
+                    checked,
                     speed: Speed::Slow,
                 },
             ids::NORMAL_PORTAL =>
                 ObjectData::SpeedPortal {
-                    checked: internal.checked,
+                    checked,
                     speed: Speed::Normal,
                 },
+            ids::MEDIUM_PORTAL =>
+                ObjectData::SpeedPortal {
+                    checked,
+                    speed: Speed::Medium,
+                },
             ids::FAST_PORTAL =>
                 ObjectData::SpeedPortal {
-                    checked: internal.checked,
+                    checked,
                     speed: Speed::Fast,
                 },
             ids::VERY_FAST_PORTAL =>
                 ObjectData::SpeedPortal {
-                    checked: internal.checked,
+                    checked,
                     speed: Speed::VeryFast,
                 },
+            ids::CUBE_PORTAL =>
+                ObjectData::GameModePortal {
+                    checked,
+                    game_mode: GameMode::Cube,
+                },
+            ids::SHIP_PORTAL =>
+                ObjectData::GameModePortal {
+                    checked,
+                    game_mode: GameMode::Ship,
+                },
+            ids::BALL_PORTAL =>
+                ObjectData::GameModePortal {
+                    checked,
+                    game_mode: GameMode::Ball,
+                },
+            ids::UFO_PORTAL =>
+                ObjectData::GameModePortal {
+                    checked,
+                    game_mode: GameMode::Ufo,
+                },
+            ids::WAVE_PORTAL =>
+                ObjectData::GameModePortal {
+                    checked,
+                    game_mode: GameMode::Wave,
+                },
+            ids::ROBOT_PORTAL =>
+                ObjectData::GameModePortal {
+                    checked,
+                    game_mode: GameMode::Robot,
+                },
+            ids::SPIDER_PORTAL =>
+                ObjectData::GameModePortal {
+                    checked,
+                    game_mode: GameMode::Spider,
+                },
+            ids::SWING_PORTAL =>
+                ObjectData::GameModePortal {
+                    checked,
+                    game_mode: GameMode::Swing,
+                },
+            ids::NORMAL_GRAVITY_PORTAL =>
+                ObjectData::GravityPortal {
+                    checked,
+                    inverted: false,
+                },
+            ids::INVERTED_GRAVITY_PORTAL =>
+                ObjectData::GravityPortal {
+                    checked,
+                    inverted: true,
+                },
+            ids::MIRROR_PORTAL =>
+                ObjectData::MirrorPortal {
+                    checked,
+                    mirrored: true,
+                },
+            ids::UNMIRROR_PORTAL =>
+                ObjectData::MirrorPortal {
+                    checked,
+                    mirrored: false,
+                },
+            ids::DUAL_PORTAL =>
+                ObjectData::DualPortal {
+                    checked,
+                    dual: true,
+                },
+            ids::SINGLE_PORTAL =>
+                ObjectData::DualPortal {
+                    checked,
+                    dual: false,
+                },
+            ids::MINI_PORTAL =>
+                ObjectData::SizePortal {
+                    checked,
+                    mini: true,
+                },
+            ids::NORMAL_SIZE_PORTAL =>
+                ObjectData::SizePortal {
+                    checked,
+                    mini: false,
+                },
+            ids::TELEPORT_PORTAL => {
+                let gameplay = second_pass::<InternalGameplayObject>(input)?;
+
+                ObjectData::TeleportPortal {
+                    checked,
+                    y_offset: gameplay.teleport_offset,
+                }
+            },
+            ids::START_POSITION => {
+                let gameplay = second_pass::<InternalGameplayObject>(input)?;
+
+                ObjectData::StartPosition(StartPosition {
+                    speed: gameplay.speed.map(Speed::from).unwrap_or_default(),
+                    game_mode: gameplay.game_mode.map(GameMode::from).unwrap_or_default(),
+                    mini: gameplay.mini.unwrap_or_default(),
+                    dual: gameplay.dual.unwrap_or_default(),
+                    gravity_inverted: gameplay.gravity_inverted.unwrap_or_default(),
+                })
+            },
+            ids::YELLOW_ORB => ObjectData::Orb(Orb::Yellow),
+            ids::PINK_ORB => ObjectData::Orb(Orb::Pink),
+            ids::RED_ORB => ObjectData::Orb(Orb::Red),
+            ids::BLUE_ORB => ObjectData::Orb(Orb::Blue),
+            ids::GREEN_ORB => ObjectData::Orb(Orb::Green),
+            ids::BLACK_ORB => ObjectData::Orb(Orb::Black),
+            ids::DASH_GREEN_ORB => ObjectData::Orb(Orb::DashGreen),
+            ids::DASH_PINK_ORB => ObjectData::Orb(Orb::DashPink),
+            ids::YELLOW_PAD => ObjectData::Pad(Pad::Yellow),
+            ids::PINK_PAD => ObjectData::Pad(Pad::Pink),
+            ids::RED_PAD => ObjectData::Pad(Pad::Red),
+            ids::BLUE_PAD => ObjectData::Pad(Pad::Blue),
+            ids::SECRET_COIN => ObjectData::Coin(Coin::Secret),
+            ids::USER_COIN => ObjectData::Coin(Coin::User),
+            // Only objects that are one of the known triggers get their trigger specific keys
+            // deserialized
+            id @ (ids::MOVE_TRIGGER
+            | ids::COLOR_TRIGGER
+            | ids::ALPHA_TRIGGER
+            | ids::TOGGLE_TRIGGER
+            | ids::PULSE_TRIGGER
+            | ids::SPAWN_TRIGGER
+            | ids::ROTATE_TRIGGER
+            | ids::FOLLOW_TRIGGER
+            | ids::SHAKE_TRIGGER
+            | ids::ANIMATE_TRIGGER
+            | ids::TOUCH_TRIGGER
+            | ids::COUNT_TRIGGER
+            | ids::INSTANT_COUNT_TRIGGER
+            | ids::COLLISION_TRIGGER
+            | ids::STOP_TRIGGER
+            | ids::ON_DEATH_TRIGGER
+            | ids::PICKUP_TRIGGER) => {
+                let trigger = second_pass::<InternalTrigger>(input)?;
+
+                ObjectData::Trigger {
+                    activation: TriggerActivation {
+                        touch_triggered: trigger.touch_triggered,
+                        spawn_triggered: trigger.spawn_triggered,
+                        multi_trigger: trigger.multi_trigger,
+                    },
+                    trigger: trigger.to_trigger(id)?,
+                }
+            },
             _ => ObjectData::Unknown,
         };
 
@@ -67,28 +1064,125 @@ impl<'a> HasRobtopFormat<'a> for LevelObject {
             flipped_x: internal.flipped_x,
             flipped_y: internal.flipped_y,
             rotation: internal.rotation,
+            editor_layer: internal.editor_layer,
+            editor_layer_2: internal.editor_layer_2,
+            main_color: internal.main_color,
+            detail_color: internal.detail_color,
+            z_layer: Some(internal.z_layer).filter(|&layer| layer != 0).map(ZLayer::from),
+            z_order: internal.z_order,
+            scale: internal.scale,
+            group_parent: internal.group_parent,
+            main_hsv_enabled: internal.main_hsv_enabled,
+            detail_hsv_enabled: internal.detail_hsv_enabled,
+            main_hsv: internal.main_hsv.map(Hsv::from_robtop_str).transpose()?,
+            detail_hsv: internal.detail_hsv.map(Hsv::from_robtop_str).transpose()?,
+            groups: match internal.groups {
+                Some(groups) =>
+                    groups
+                        .split('.')
+                        .map(|group| {
+                            group.parse().map_err(|_| {
+                                DeError::Custom {
+                                    message: "Expected a list of group IDs".to_owned(),
+                                    index: Some("57"),
+                                    value: Some(groups),
+                                }
+                            })
+                        })
+                        .collect::<Result<_, _>>()?,
+                None => Vec::new(),
+            },
+            dont_fade: internal.dont_fade,
+            dont_enter: internal.dont_enter,
+            high_detail: internal.high_detail,
+            linked_group: Some(internal.linked_group).filter(|&group| group != 0),
             metadata,
+            others: Vec::new(),
+            source: None,
         })
     }
+}
 
-    fn write_robtop_data<W: Write>(&self, writer: W) -> Result<(), SerError> {
-        let mut internal = InternalLevelObject {
-            id: self.id,
-            x: self.x,
-            y: self.y,
-            flipped_x: self.flipped_x,
-            flipped_y: self.flipped_y,
-            rotation: self.rotation,
-            ..InternalLevelObject::default()
-        };
+impl<'a> HasRobtopFormat<'a> for LevelObject {
+    fn from_robtop_str(input: &'a str) -> Result<Self, DeError> {
+        let mut object = LevelObject::from_modelled_keys(input)?;
 
-        match self.metadata {
-            ObjectData::None | ObjectData::Unknown => {},
-            ObjectData::SpeedPortal { checked, .. } => {
-                internal.checked = checked;
+        object.others = key_value_pairs(input)
+            .filter(|(key, _)| !is_modelled(key, &object.metadata))
+            .map(|(key, value)| (key.to_owned(), value.to_owned()))
+            .collect();
+        object.source = Some(input.to_owned());
+
+        Ok(object)
+    }
+
+    fn write_robtop_data<W: Write>(&self, mut writer: W) -> Result<(), SerError> {
+        let modelled = self.modelled_string()?;
+        let modelled: Vec<_> = key_value_pairs(&modelled).collect();
+        let mut sorted_modelled = modelled.clone();
+
+        sorted_modelled.sort_unstable_by_key(|&(key, _)| key);
+
+        // Pairs in `others` whose key is written by one of the fields are superseded by that field
+        let mut others = self
+            .others
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .filter(|&(key, _)| lookup(&sorted_modelled, key).is_none());
+        let mut pairs = Vec::with_capacity(modelled.len() + self.others.len());
+
+        match self.source {
+            None => pairs.extend(modelled.iter().copied()),
+            Some(ref source) => {
+                // To find out which fields were changed since parsing, the fields parsed from the
+                // source are serialized as well
+                let original = LevelObject::from_modelled_keys(source).map_err(|err| SerError::Custom(err.to_string()))?;
+                let original_string = original.modelled_string()?;
+                let mut original_modelled: Vec<_> = key_value_pairs(&original_string).collect();
+                let mut source_keys: Vec<_> = key_value_pairs(source)
+                    .map(|(key, _)| key)
+                    .filter(|key| is_modelled(key, &original.metadata))
+                    .collect();
+
+                original_modelled.sort_unstable_by_key(|&(key, _)| key);
+                source_keys.sort_unstable();
+
+                for (key, raw_value) in key_value_pairs(source) {
+                    if !is_modelled(key, &original.metadata) {
+                        // The pairs in `others` take the places of the unmodelled pairs
+                        pairs.extend(others.next());
+                    } else {
+                        let value = lookup(&sorted_modelled, key);
+
+                        // Unchanged fields keep their original formatting, changed ones are left out
+                        // if they hold their default value now
+                        if value == lookup(&original_modelled, key) {
+                            pairs.push((key, raw_value));
+                        } else if let Some(value) = value {
+                            pairs.push((key, value));
+                        }
+                    }
+                }
+
+                pairs.extend(
+                    modelled
+                        .iter()
+                        .copied()
+                        .filter(|(key, _)| source_keys.binary_search(key).is_err()),
+                );
             },
-        };
+        }
+
+        pairs.extend(others);
+
+        for (index, (key, value)) in pairs.into_iter().enumerate() {
+            if index != 0 {
+                writer.write_all(b",")?;
+            }
+
+            write!(writer, "{},{}", key, value)?;
+        }
 
-        internal.serialize(&mut IndexedSerializer::new(",", writer, true))
+        Ok(())
     }
 }
@@ -1,25 +1,319 @@
-use crate::model::level::object::speed::Speed;
+use crate::model::level::{
+    color::Hsv,
+    object::{
+        game_mode::GameMode,
+        gameplay::{Coin, Orb, Pad, StartPosition},
+        speed::Speed,
+        trigger::{Trigger, TriggerActivation},
+    },
+};
 use serde::{Deserialize, Serialize};
 
+pub mod game_mode;
+pub mod gameplay;
 pub mod ids;
 mod internal;
 pub mod speed;
+pub mod trigger;
 
-#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+/// A single object placed in a level
+///
+/// ## GD Internals:
+/// Objects are stored as comma separated lists of key-value pairs. Keys not modelled by this
+/// struct are kept in [`LevelObject::others`], so that no data is lost when writing a level back.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct LevelObject {
+    /// The ID of this object, determining what kind of object it is. See [`ids`] for some known
+    /// object IDs
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `1`
     pub id: u16,
+
+    /// Stored at index `2`
     pub x: f32,
+
+    /// Stored at index `3`
     pub y: f32,
+
+    /// Stored at index `4`
     pub flipped_x: bool,
+
+    /// Stored at index `5`
     pub flipped_y: bool,
+
+    /// The rotation of this object, in degrees
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `6`
     pub rotation: f32,
-    // ... other fields they all have ...
+
+    /// The editor layer this object is on
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `20`
+    pub editor_layer: u16,
+
+    /// The second editor layer this object is on
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `61`
+    pub editor_layer_2: u16,
+
+    /// The ID of the color channel used for the object's main color, or `0` if the object uses
+    /// its default channel
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `21`
+    pub main_color: u16,
+
+    /// The ID of the color channel used for the object's detail color, or `0` if the object uses
+    /// its default channel
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `22`
+    pub detail_color: u16,
+
+    /// The z-layer this object is drawn on, or [`None`] if the object uses its default layer
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `24`
+    pub z_layer: Option<ZLayer>,
+
+    /// The z-order of this object within its z-layer
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `25`
+    pub z_order: i16,
+
+    /// Stored at index `32`
+    pub scale: f32,
+
+    /// Whether this object is the parent of its groups
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `34`
+    pub group_parent: bool,
+
+    /// Whether [`LevelObject::main_hsv`] is applied to the object's main color
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `41`
+    pub main_hsv_enabled: bool,
+
+    /// Whether [`LevelObject::detail_hsv`] is applied to the object's detail color
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `42`
+    pub detail_hsv_enabled: bool,
+
+    /// The HSV modification of the object's main color
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `43`
+    pub main_hsv: Option<Hsv>,
+
+    /// The HSV modification of the object's detail color
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `44`
+    pub detail_hsv: Option<Hsv>,
+
+    /// The IDs of the groups this object is part of
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `57`, as a `.`-separated list
+    pub groups: Vec<u16>,
+
+    /// Whether this object ignores the level's fade effects
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `64`
+    pub dont_fade: bool,
+
+    /// Whether this object ignores the level's enter effects
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `67`
+    pub dont_enter: bool,
+
+    /// Whether this object is hidden in low detail mode
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `103`
+    pub high_detail: bool,
+
+    /// The ID of the group of objects this object was linked to in the editor
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `108`
+    pub linked_group: Option<u32>,
+
+    /// Data specific to the kind of object this is
     pub metadata: ObjectData,
+
+    /// All key-value pairs of this object whose key is not represented by any of the above fields
+    /// for this kind of object, in the order they appeared in
+    ///
+    /// When writing this object, these pairs take the places of the unmodelled pairs in
+    /// [`LevelObject::source`], in order. Pairs left over after that are written last. Pairs whose
+    /// key is also written by one of the above fields are left out.
+    pub others: Vec<(String, String)>,
+
+    /// The object string this object was parsed from. [`None`] for objects that were not parsed
+    ///
+    /// When writing this object, its keys are written in the order they appear in here. Values of
+    /// fields that still hold the value parsed from here are written exactly as they appear in here,
+    /// so that e.g. `2,15.0` is not turned into `2,15`. Fields that were changed to their default
+    /// value are left out, and fields whose key does not appear in here are written after it.
+    pub source: Option<String>,
+}
+
+impl Default for LevelObject {
+    fn default() -> Self {
+        LevelObject {
+            id: 0,
+            x: 0.0,
+            y: 0.0,
+            flipped_x: false,
+            flipped_y: false,
+            rotation: 0.0,
+            editor_layer: 0,
+            editor_layer_2: 0,
+            main_color: 0,
+            detail_color: 0,
+            z_layer: None,
+            z_order: 0,
+            scale: 1.0,
+            group_parent: false,
+            main_hsv_enabled: false,
+            detail_hsv_enabled: false,
+            main_hsv: None,
+            detail_hsv: None,
+            groups: Vec::new(),
+            dont_fade: false,
+            dont_enter: false,
+            high_detail: false,
+            linked_group: None,
+            metadata: ObjectData::None,
+            others: Vec::new(),
+            source: None,
+        }
+    }
+}
+
+/// The layers an object can be drawn on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ZLayer {
+    /// Represented by the value `-3`
+    B4,
+
+    /// Represented by the value `-1`
+    B3,
+
+    /// Represented by the value `1`
+    B2,
+
+    /// Represented by the value `3`
+    B1,
+
+    /// Represented by the value `5`
+    T1,
+
+    /// Represented by the value `7`
+    T2,
+
+    /// Represented by the value `9`
+    T3,
+
+    Unknown(i8),
+}
+
+impl From<i8> for ZLayer {
+    fn from(value: i8) -> Self {
+        match value {
+            -3 => ZLayer::B4,
+            -1 => ZLayer::B3,
+            1 => ZLayer::B2,
+            3 => ZLayer::B1,
+            5 => ZLayer::T1,
+            7 => ZLayer::T2,
+            9 => ZLayer::T3,
+            value => ZLayer::Unknown(value),
+        }
+    }
+}
+
+impl From<ZLayer> for i8 {
+    fn from(layer: ZLayer) -> Self {
+        match layer {
+            ZLayer::B4 => -3,
+            ZLayer::B3 => -1,
+            ZLayer::B2 => 1,
+            ZLayer::B1 => 3,
+            ZLayer::T1 => 5,
+            ZLayer::T2 => 7,
+            ZLayer::T3 => 9,
+            ZLayer::Unknown(value) => value,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize)]
 pub enum ObjectData {
     None,
     Unknown,
-    SpeedPortal { checked: bool, speed: Speed },
+    SpeedPortal {
+        checked: bool,
+        speed: Speed,
+    },
+
+    /// A portal changing the player's game mode
+    GameModePortal {
+        checked: bool,
+        game_mode: GameMode,
+    },
+
+    /// A portal flipping the player's gravity upside down (if `inverted` is set) or back to normal
+    GravityPortal {
+        checked: bool,
+        inverted: bool,
+    },
+
+    /// A portal mirroring the screen horizontally (if `mirrored` is set) or back to normal
+    MirrorPortal {
+        checked: bool,
+        mirrored: bool,
+    },
+
+    /// A portal turning dual mode on (if `dual` is set) or off
+    DualPortal {
+        checked: bool,
+        dual: bool,
+    },
+
+    /// A portal making the player mini (if `mini` is set) or back to normal size
+    SizePortal {
+        checked: bool,
+        mini: bool,
+    },
+
+    /// A teleport portal
+    ///
+    /// ## GD Internals:
+    /// The exit of a teleport portal is not an object of its own. Instead, the entrance stores the
+    /// vertical distance to its exit under key `54`
+    TeleportPortal {
+        checked: bool,
+        y_offset: f32,
+    },
+
+    StartPosition(StartPosition),
+    Orb(Orb),
+    Pad(Pad),
+    Coin(Coin),
+    Trigger {
+        activation: TriggerActivation,
+        trigger: Trigger,
+    },
 }
@@ -18,6 +18,34 @@ impl Default for Speed {
     }
 }
 
+/// Converts the value the game uses to store speeds (for instance in a level's starting settings)
+/// into a [`Speed`]
+impl From<u8> for Speed {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Speed::Slow,
+            1 => Speed::Normal,
+            2 => Speed::Medium,
+            3 => Speed::Fast,
+            4 => Speed::VeryFast,
+            unknown => Speed::Unknown(unknown),
+        }
+    }
+}
+
+impl From<Speed> for u8 {
+    fn from(speed: Speed) -> Self {
+        match speed {
+            Speed::Slow => 0,
+            Speed::Normal => 1,
+            Speed::Medium => 2,
+            Speed::Fast => 3,
+            Speed::VeryFast => 4,
+            Speed::Unknown(unknown) => unknown,
+        }
+    }
+}
+
 /// Converts the speed to the game-internal "pixel  / second" value represented by some [`Speed`]
 /// variant
 impl From<Speed> for f32 {
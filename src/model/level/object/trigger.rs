@@ -0,0 +1,576 @@
+//! Module containing the trigger objects known to dash-rs
+
+use crate::model::level::color::{Hsv, PlayerColor};
+use serde::{Deserialize, Serialize};
+
+/// The ways a trigger can be activated, shared by all triggers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TriggerActivation {
+    /// Whether the trigger is activated by the player touching it instead of passing its x
+    /// position
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `11`
+    pub touch_triggered: bool,
+
+    /// Whether the trigger is only activated by spawn triggers targeting one of its groups
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `62`
+    pub spawn_triggered: bool,
+
+    /// Whether a touch- or spawn-triggered trigger can be activated more than once
+    ///
+    /// ## GD Internals:
+    /// This value is stored at index `87`
+    pub multi_trigger: bool,
+}
+
+/// The different triggers known to dash-rs, together with their parameters
+///
+/// Field names are shared between variants where they have the same meaning, and map to the same
+/// index in the object string. Durations, delays and intervals are in seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Trigger {
+    /// ## GD Internals:
+    /// Object ID [`MOVE_TRIGGER`](super::ids::MOVE_TRIGGER)
+    Move {
+        /// Stored at index `51`
+        target_group: u16,
+
+        /// Stored at index `10`
+        duration: f32,
+
+        /// Stored at index `30`
+        easing: Easing,
+
+        /// Stored at index `85`
+        easing_rate: f32,
+
+        /// The horizontal distance to move by, in units of 1/3 of a block. Stored at index `28`
+        move_x: f32,
+
+        /// The vertical distance to move by, in units of 1/3 of a block. Stored at index `29`
+        move_y: f32,
+
+        /// Stored at index `58`
+        lock_to_player_x: bool,
+
+        /// Stored at index `59`
+        lock_to_player_y: bool,
+    },
+
+    /// ## GD Internals:
+    /// Object ID [`COLOR_TRIGGER`](super::ids::COLOR_TRIGGER)
+    Color {
+        /// The ID of the color channel to change. Stored at index `23`
+        target_channel: u16,
+
+        /// Stored at index `10`
+        duration: f32,
+
+        /// Stored at index `7`
+        red: u8,
+
+        /// Stored at index `8`
+        green: u8,
+
+        /// Stored at index `9`
+        blue: u8,
+
+        /// Stored at index `35`
+        opacity: f32,
+
+        /// Stored at index `17`
+        blending: bool,
+
+        /// Stored at indices `15` (player color 1) and `16` (player color 2)
+        player_color: PlayerColor,
+
+        /// The ID of the channel whose color should be copied. Stored at index `50`
+        copied_color: Option<u16>,
+
+        /// The HSV modification applied to the copied color. Stored at index `49`
+        hsv: Option<Hsv>,
+
+        /// Stored at index `60`
+        copy_opacity: bool,
+    },
+
+    /// ## GD Internals:
+    /// Object ID [`ALPHA_TRIGGER`](super::ids::ALPHA_TRIGGER)
+    Alpha {
+        /// Stored at index `51`
+        target_group: u16,
+
+        /// Stored at index `10`
+        duration: f32,
+
+        /// Stored at index `35`
+        opacity: f32,
+    },
+
+    /// ## GD Internals:
+    /// Object ID [`TOGGLE_TRIGGER`](super::ids::TOGGLE_TRIGGER)
+    Toggle {
+        /// Stored at index `51`
+        target_group: u16,
+
+        /// Whether the target group is toggled on instead of off. Stored at index `56`
+        activate_group: bool,
+    },
+
+    /// ## GD Internals:
+    /// Object ID [`PULSE_TRIGGER`](super::ids::PULSE_TRIGGER)
+    Pulse {
+        /// Stored at indices `51` and `52`
+        target: PulseTarget,
+
+        /// Stored at index `45`
+        fade_in: f32,
+
+        /// Stored at index `46`
+        hold: f32,
+
+        /// Stored at index `47`
+        fade_out: f32,
+
+        /// Stored at index `7`
+        red: u8,
+
+        /// Stored at index `8`
+        green: u8,
+
+        /// Stored at index `9`
+        blue: u8,
+
+        /// Whether the pulse uses `copied_color` and `hsv` instead of the RGB value. Stored at index
+        /// `48`
+        use_hsv: bool,
+
+        /// Stored at index `50`
+        copied_color: Option<u16>,
+
+        /// Stored at index `49`
+        hsv: Option<Hsv>,
+
+        /// Stored at index `65`
+        main_only: bool,
+
+        /// Stored at index `66`
+        detail_only: bool,
+
+        /// Stored at index `86`
+        exclusive: bool,
+    },
+
+    /// ## GD Internals:
+    /// Object ID [`SPAWN_TRIGGER`](super::ids::SPAWN_TRIGGER)
+    Spawn {
+        /// Stored at index `51`
+        target_group: u16,
+
+        /// Stored at index `63`
+        delay: f32,
+    },
+
+    /// ## GD Internals:
+    /// Object ID [`ROTATE_TRIGGER`](super::ids::ROTATE_TRIGGER)
+    Rotate {
+        /// Stored at index `51`
+        target_group: u16,
+
+        /// The group to rotate around. Stored at index `71`
+        center_group: u16,
+
+        /// Stored at index `10`
+        duration: f32,
+
+        /// Stored at index `30`
+        easing: Easing,
+
+        /// Stored at index `85`
+        easing_rate: f32,
+
+        /// Stored at index `68`
+        degrees: f32,
+
+        /// Additional full rotations. Stored at index `69`
+        full_rotations: i32,
+
+        /// Stored at index `70`
+        lock_rotation: bool,
+    },
+
+    /// ## GD Internals:
+    /// Object ID [`FOLLOW_TRIGGER`](super::ids::FOLLOW_TRIGGER)
+    Follow {
+        /// Stored at index `51`
+        target_group: u16,
+
+        /// The group whose movement is followed. Stored at index `71`
+        follow_group: u16,
+
+        /// Stored at index `10`
+        duration: f32,
+
+        /// Stored at index `72`
+        x_modifier: f32,
+
+        /// Stored at index `73`
+        y_modifier: f32,
+    },
+
+    /// ## GD Internals:
+    /// Object ID [`SHAKE_TRIGGER`](super::ids::SHAKE_TRIGGER)
+    Shake {
+        /// Stored at index `10`
+        duration: f32,
+
+        /// Stored at index `75`
+        strength: f32,
+
+        /// Stored at index `84`
+        interval: f32,
+    },
+
+    /// ## GD Internals:
+    /// Object ID [`ANIMATE_TRIGGER`](super::ids::ANIMATE_TRIGGER)
+    Animate {
+        /// Stored at index `51`
+        target_group: u16,
+
+        /// Stored at index `76`
+        animation: u16,
+    },
+
+    /// ## GD Internals:
+    /// Object ID [`TOUCH_TRIGGER`](super::ids::TOUCH_TRIGGER)
+    Touch {
+        /// Stored at index `51`
+        target_group: u16,
+
+        /// Stored at index `81`
+        hold_mode: bool,
+
+        /// Stored at index `82`
+        toggle_mode: ToggleMode,
+
+        /// Stored at index `89`
+        dual_mode: bool,
+    },
+
+    /// ## GD Internals:
+    /// Object ID [`COUNT_TRIGGER`](super::ids::COUNT_TRIGGER)
+    Count {
+        /// Stored at index `80`
+        item_id: u16,
+
+        /// Stored at index `77`
+        target_count: i32,
+
+        /// Stored at index `51`
+        target_group: u16,
+
+        /// Stored at index `56`
+        activate_group: bool,
+
+        /// Stored at index `104`
+        multi_activate: bool,
+    },
+
+    /// ## GD Internals:
+    /// Object ID [`INSTANT_COUNT_TRIGGER`](super::ids::INSTANT_COUNT_TRIGGER)
+    InstantCount {
+        /// Stored at index `80`
+        item_id: u16,
+
+        /// Stored at index `77`
+        target_count: i32,
+
+        /// Stored at index `51`
+        target_group: u16,
+
+        /// Stored at index `56`
+        activate_group: bool,
+
+        /// Stored at index `88`
+        comparison: Comparison,
+    },
+
+    /// ## GD Internals:
+    /// Object ID [`COLLISION_TRIGGER`](super::ids::COLLISION_TRIGGER)
+    Collision {
+        /// Stored at index `80`
+        block_a: u16,
+
+        /// Stored at index `95`
+        block_b: u16,
+
+        /// Stored at index `51`
+        target_group: u16,
+
+        /// Stored at index `56`
+        activate_group: bool,
+
+        /// Stored at index `93`
+        trigger_on_exit: bool,
+    },
+
+    /// ## GD Internals:
+    /// Object ID [`STOP_TRIGGER`](super::ids::STOP_TRIGGER)
+    Stop {
+        /// Stored at index `51`
+        target_group: u16,
+    },
+
+    /// ## GD Internals:
+    /// Object ID [`ON_DEATH_TRIGGER`](super::ids::ON_DEATH_TRIGGER)
+    OnDeath {
+        /// Stored at index `51`
+        target_group: u16,
+
+        /// Stored at index `56`
+        activate_group: bool,
+    },
+
+    /// ## GD Internals:
+    /// Object ID [`PICKUP_TRIGGER`](super::ids::PICKUP_TRIGGER)
+    Pickup {
+        /// Stored at index `80`
+        item_id: u16,
+
+        /// The amount to add to the item's count. Stored at index `77`
+        count: i32,
+    },
+}
+
+impl Trigger {
+    /// The group whose objects this trigger acts on, if any
+    ///
+    /// A target group of `0` means the trigger has not been given a target in the editor, in which
+    /// case [`None`] is returned.
+    pub fn target_group(&self) -> Option<u16> {
+        let group = match *self {
+            Trigger::Move { target_group, .. }
+            | Trigger::Alpha { target_group, .. }
+            | Trigger::Toggle { target_group, .. }
+            | Trigger::Spawn { target_group, .. }
+            | Trigger::Rotate { target_group, .. }
+            | Trigger::Follow { target_group, .. }
+            | Trigger::Animate { target_group, .. }
+            | Trigger::Touch { target_group, .. }
+            | Trigger::Count { target_group, .. }
+            | Trigger::InstantCount { target_group, .. }
+            | Trigger::Collision { target_group, .. }
+            | Trigger::Stop { target_group }
+            | Trigger::OnDeath { target_group, .. } => target_group,
+            Trigger::Pulse {
+                target: PulseTarget::Group(group),
+                ..
+            } => group,
+            Trigger::Pulse { .. } | Trigger::Color { .. } | Trigger::Shake { .. } | Trigger::Pickup { .. } => 0,
+        };
+
+        Some(group).filter(|&group| group != 0)
+    }
+
+    /// The group this trigger references in addition to its target group, such as the center of a
+    /// rotate trigger or the group followed by a follow trigger
+    pub fn secondary_group(&self) -> Option<u16> {
+        match *self {
+            Trigger::Rotate { center_group: group, .. } | Trigger::Follow { follow_group: group, .. } => Some(group).filter(|&group| group != 0),
+            _ => None,
+        }
+    }
+
+    /// Whether this trigger activates the spawn triggered triggers in its target group
+    ///
+    /// This is always the case for spawn triggers. Toggle, count, instant count, collision and on
+    /// death triggers do so if they are set to activate their target group, and touch triggers if
+    /// they toggle it on.
+    pub fn activates_target(&self) -> bool {
+        match *self {
+            Trigger::Spawn { .. } => true,
+            Trigger::Toggle { activate_group, .. }
+            | Trigger::Count { activate_group, .. }
+            | Trigger::InstantCount { activate_group, .. }
+            | Trigger::Collision { activate_group, .. }
+            | Trigger::OnDeath { activate_group, .. } => activate_group,
+            Trigger::Touch { toggle_mode, .. } => toggle_mode != ToggleMode::Off,
+            _ => false,
+        }
+    }
+}
+
+/// The easing functions available for move and rotate triggers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Easing {
+    #[default]
+    None,
+    EaseInOut,
+    EaseIn,
+    EaseOut,
+    ElasticInOut,
+    ElasticIn,
+    ElasticOut,
+    BounceInOut,
+    BounceIn,
+    BounceOut,
+    ExponentialInOut,
+    ExponentialIn,
+    ExponentialOut,
+    SineInOut,
+    SineIn,
+    SineOut,
+    BackInOut,
+    BackIn,
+    BackOut,
+    Unknown(u8),
+}
+
+impl From<u8> for Easing {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Easing::None,
+            1 => Easing::EaseInOut,
+            2 => Easing::EaseIn,
+            3 => Easing::EaseOut,
+            4 => Easing::ElasticInOut,
+            5 => Easing::ElasticIn,
+            6 => Easing::ElasticOut,
+            7 => Easing::BounceInOut,
+            8 => Easing::BounceIn,
+            9 => Easing::BounceOut,
+            10 => Easing::ExponentialInOut,
+            11 => Easing::ExponentialIn,
+            12 => Easing::ExponentialOut,
+            13 => Easing::SineInOut,
+            14 => Easing::SineIn,
+            15 => Easing::SineOut,
+            16 => Easing::BackInOut,
+            17 => Easing::BackIn,
+            18 => Easing::BackOut,
+            value => Easing::Unknown(value),
+        }
+    }
+}
+
+impl From<Easing> for u8 {
+    fn from(easing: Easing) -> Self {
+        match easing {
+            Easing::None => 0,
+            Easing::EaseInOut => 1,
+            Easing::EaseIn => 2,
+            Easing::EaseOut => 3,
+            Easing::ElasticInOut => 4,
+            Easing::ElasticIn => 5,
+            Easing::ElasticOut => 6,
+            Easing::BounceInOut => 7,
+            Easing::BounceIn => 8,
+            Easing::BounceOut => 9,
+            Easing::ExponentialInOut => 10,
+            Easing::ExponentialIn => 11,
+            Easing::ExponentialOut => 12,
+            Easing::SineInOut => 13,
+            Easing::SineIn => 14,
+            Easing::SineOut => 15,
+            Easing::BackInOut => 16,
+            Easing::BackIn => 17,
+            Easing::BackOut => 18,
+            Easing::Unknown(value) => value,
+        }
+    }
+}
+
+/// What a pulse trigger targets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PulseTarget {
+    /// The color channel with the given ID
+    ///
+    /// ## GD Internals:
+    /// Represented by the value `0` at index `52`
+    Channel(u16),
+
+    /// All objects in the group with the given ID
+    ///
+    /// ## GD Internals:
+    /// Represented by the value `1` at index `52`
+    Group(u16),
+}
+
+/// What a touch trigger does to its target group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ToggleMode {
+    /// Toggle the group, represented by the value `0`
+    #[default]
+    Toggle,
+
+    /// Represented by the value `1`
+    On,
+
+    /// Represented by the value `2`
+    Off,
+
+    Unknown(u8),
+}
+
+impl From<u8> for ToggleMode {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => ToggleMode::Toggle,
+            1 => ToggleMode::On,
+            2 => ToggleMode::Off,
+            value => ToggleMode::Unknown(value),
+        }
+    }
+}
+
+impl From<ToggleMode> for u8 {
+    fn from(mode: ToggleMode) -> Self {
+        match mode {
+            ToggleMode::Toggle => 0,
+            ToggleMode::On => 1,
+            ToggleMode::Off => 2,
+            ToggleMode::Unknown(value) => value,
+        }
+    }
+}
+
+/// How an instant count trigger compares the item's count to its target count
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Comparison {
+    /// Represented by the value `0`
+    #[default]
+    Equals,
+
+    /// Represented by the value `1`
+    Larger,
+
+    /// Represented by the value `2`
+    Smaller,
+
+    Unknown(u8),
+}
+
+impl From<u8> for Comparison {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Comparison::Equals,
+            1 => Comparison::Larger,
+            2 => Comparison::Smaller,
+            value => Comparison::Unknown(value),
+        }
+    }
+}
+
+impl From<Comparison> for u8 {
+    fn from(comparison: Comparison) -> Self {
+        match comparison {
+            Comparison::Equals => 0,
+            Comparison::Larger => 1,
+            Comparison::Smaller => 2,
+            Comparison::Unknown(value) => value,
+        }
+    }
+}
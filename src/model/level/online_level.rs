@@ -186,6 +186,58 @@ pub struct Level<'a, Data = Option<LevelData<'a>>, Song = Option<u64>, User = u6
     pub level_data: Data,
 }
 
+impl<'a> Level<'a> {
+    /// Converts this [`Level`] into one that doesn't borrow from the response it was parsed from.
+    ///
+    /// The level's description and objects stay unprocessed if they haven't been processed yet.
+    pub fn into_owned(self) -> Level<'static> {
+        self.map_into_owned(|song| song, |creator| creator)
+    }
+}
+
+impl<'a> ListedLevel<'a> {
+    /// Converts this [`ListedLevel`] into one that doesn't borrow from the response it was parsed
+    /// from.
+    ///
+    /// The level's description, objects and the link of its custom song stay unprocessed if they
+    /// haven't been processed yet.
+    pub fn into_owned(self) -> ListedLevel<'static> {
+        self.map_into_owned(|song| song.map(NewgroundsSong::into_owned), |creator| creator.map(Creator::into_owned))
+    }
+}
+
+impl<'a, Song, User> Level<'a, Option<LevelData<'a>>, Song, User> {
+    fn map_into_owned<S, U>(self, song: impl FnOnce(Song) -> S, user: impl FnOnce(User) -> U) -> Level<'static, Option<LevelData<'static>>, S, U> {
+        Level {
+            level_id: self.level_id,
+            name: Cow::Owned(self.name.into_owned()),
+            description: self.description.map(|description| description.into_owned(Base64Decoded::into_owned)),
+            version: self.version,
+            creator: user(self.creator),
+            downloads: self.downloads,
+            set_completes: self.set_completes,
+            main_song: self.main_song,
+            game_version: self.game_version,
+            likes: self.likes,
+            length: self.length,
+            stars: self.stars,
+            feature_score: self.feature_score,
+            copy_of: self.copy_of,
+            is_two_player: self.is_two_player,
+            custom_song: song(self.custom_song),
+            coin_amount: self.coin_amount,
+            has_verified_coins: self.has_verified_coins,
+            stars_requested: self.stars_requested,
+            daily_number: self.daily_number,
+            is_epic: self.is_epic,
+            difficulty: self.difficulty,
+            in_gauntlet: self.in_gauntlet,
+            object_count: self.object_count,
+            level_data: self.level_data.map(LevelData::into_owned),
+        }
+    }
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -301,7 +353,7 @@ mod internal {
                     name: Cow::Borrowed(internal.index_2),
                     description: match internal.index_3 {
                         None => None,
-                        Some(RefThunk::Unprocessed(unproc)) => Some(Thunk::Unprocessed(unproc)),
+                        Some(RefThunk::Unprocessed(unproc)) => Some(Thunk::Unprocessed(Cow::Borrowed(unproc))),
                         _ => unreachable!()
                     },
                     level_data: match internal.index_4 {
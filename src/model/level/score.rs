@@ -0,0 +1,113 @@
+//! Module containing the model of entries on a level's leaderboard
+
+use crate::model::user::{Color, IconType};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Struct modelling an entry on a level's leaderboard, as returned by the `getGJLevelScores`
+/// endpoint
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LevelScore<'a> {
+    /// The name of the user who set this [`LevelScore`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `1`
+    pub name: Cow<'a, str>,
+
+    /// The user ID of the user who set this [`LevelScore`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `2`
+    pub user_id: u64,
+
+    /// The score itself. Its meaning depends on the
+    /// [`LevelLeaderboardMode`](crate::request::level::LevelLeaderboardMode) of the leaderboard:
+    /// The best percentage for classic levels, and the best time in milliseconds or the most points
+    /// collected for platformer levels
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `3`
+    pub score: u32,
+
+    /// The position of this [`LevelScore`] on the leaderboard, starting at `1`
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `6`
+    pub rank: u32,
+
+    /// The index of the icon being displayed.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `9`
+    pub icon_index: u16,
+
+    /// The primary color of the user who set this [`LevelScore`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `10`
+    pub primary_color: Color,
+
+    /// The secondary color of the user who set this [`LevelScore`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `11`
+    pub secondary_color: Color,
+
+    /// The amount of coins collected in the run that set this [`LevelScore`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `13`
+    pub coins: u8,
+
+    /// The type of icon being displayed
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `14`
+    pub icon_type: IconType,
+
+    /// Whether the user who set this [`LevelScore`] has glow activated or not.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `15`. A value of `"2"` means `true`, a value of `"0"` means
+    /// `false`
+    pub has_glow: bool,
+
+    /// The account ID of the user who set this [`LevelScore`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `16`
+    pub account_id: u64,
+
+    /// Robtop's string describing how long ago this [`LevelScore`] was set, e.g. `"2 days"`
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `42`
+    pub age: Cow<'a, str>,
+}
+
+impl<'a> LevelScore<'a> {
+    /// Converts this [`LevelScore`] into one that doesn't borrow from the response it was parsed
+    /// from.
+    pub fn into_owned(self) -> LevelScore<'static> {
+        LevelScore {
+            name: Cow::Owned(self.name.into_owned()),
+            user_id: self.user_id,
+            score: self.score,
+            rank: self.rank,
+            icon_index: self.icon_index,
+            primary_color: self.primary_color,
+            secondary_color: self.secondary_color,
+            coins: self.coins,
+            icon_type: self.icon_type,
+            has_glow: self.has_glow,
+            account_id: self.account_id,
+            age: Cow::Owned(self.age.into_owned()),
+        }
+    }
+}
+
+mod internal {
+    use crate::model::level::score::LevelScore;
+
+    include!(concat!(env!("OUT_DIR"), "/level_score.boilerplate"));
+}
@@ -0,0 +1,136 @@
+//! Module for mapping between positions in a level and the time at which the player reaches them
+//!
+//! The player moves through a level at a constant horizontal speed that only changes when passing
+//! through a speed portal. A [`Timeline`] splits a level into [`SpeedSegment`]s of constant speed,
+//! which allows converting x positions into times (e.g. to compute a level's duration, or when
+//! triggers and music cues happen) and back.
+
+use crate::model::level::{
+    local_level::Objects,
+    object::{speed::Speed, LevelObject, ObjectData},
+};
+
+/// A part of a level throughout which the player moves at a constant speed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpeedSegment {
+    /// The x position at which this segment starts
+    pub x: f32,
+
+    /// The time, in seconds, at which the player reaches the start of this segment
+    pub time: f32,
+
+    /// The speed of the player throughout this segment
+    pub speed: Speed,
+}
+
+/// The mapping between x positions and time for a level
+///
+/// Positions before the start of the timeline are extrapolated using the starting speed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timeline {
+    /// The segments of this timeline, sorted by x position. There is always at least one segment,
+    /// which starts at the timeline's starting position at time `0`
+    segments: Vec<SpeedSegment>,
+}
+
+impl Timeline {
+    /// Constructs the timeline of a player starting at position `start_x` with speed `start_speed`
+    /// and passing through the given speed portals, given as pairs of x position and speed.
+    ///
+    /// Portals placed before `start_x` are ignored. Portals do not need to be sorted.
+    pub fn new<I: IntoIterator<Item = (f32, Speed)>>(start_x: f32, start_speed: Speed, portals: I) -> Timeline {
+        let mut portals = portals.into_iter().filter(|&(x, _)| x >= start_x).collect::<Vec<_>>();
+
+        portals.sort_by(|(x1, _), (x2, _)| x1.total_cmp(x2));
+
+        let mut segments = vec![SpeedSegment {
+            x: start_x,
+            time: 0.0,
+            speed: start_speed,
+        }];
+
+        for (x, speed) in portals {
+            let last = segments[segments.len() - 1];
+
+            segments.push(SpeedSegment {
+                x,
+                time: last.time + (x - last.x) / f32::from(last.speed),
+                speed,
+            });
+        }
+
+        Timeline { segments }
+    }
+
+    /// Constructs the timeline of the given level, starting at its beginning
+    pub fn for_level(objects: &Objects) -> Timeline {
+        Timeline::new(0.0, objects.meta.starting_speed, speed_portals(objects))
+    }
+
+    /// Constructs the timeline of the given level when starting from the given start position, as
+    /// the game does when playtesting from it.
+    ///
+    /// Returns [`None`] if `start_position` is not a start position
+    pub fn for_start_position(objects: &Objects, start_position: &LevelObject) -> Option<Timeline> {
+        match start_position.metadata {
+            ObjectData::StartPosition(settings) => Some(Timeline::new(start_position.x, settings.speed, speed_portals(objects))),
+            _ => None,
+        }
+    }
+
+    /// The segments of constant speed this timeline consists of, sorted by x position
+    pub fn segments(&self) -> &[SpeedSegment] {
+        &self.segments
+    }
+
+    /// The x position at which this timeline starts
+    pub fn start_x(&self) -> f32 {
+        self.segments[0].x
+    }
+
+    /// The speed of the player at the given x position
+    pub fn speed_at(&self, x: f32) -> Speed {
+        self.segment_at(x).speed
+    }
+
+    /// The time, in seconds, it takes the player to get from the start of this timeline to the given
+    /// x position
+    pub fn time_at(&self, x: f32) -> f32 {
+        let segment = self.segment_at(x);
+
+        segment.time + (x - segment.x) / f32::from(segment.speed)
+    }
+
+    /// The x position the player is at after the given amount of seconds
+    pub fn x_at(&self, time: f32) -> f32 {
+        let segment = self
+            .segments
+            .iter()
+            .rev()
+            .find(|segment| segment.time <= time)
+            .unwrap_or(&self.segments[0]);
+
+        segment.x + (time - segment.time) * f32::from(segment.speed)
+    }
+
+    fn segment_at(&self, x: f32) -> &SpeedSegment {
+        self.segments
+            .iter()
+            .rev()
+            .find(|segment| segment.x <= x)
+            .unwrap_or(&self.segments[0])
+    }
+}
+
+/// The speed portals in the given level that change the player's speed
+///
+/// ## GD Internals:
+/// Only speed portals that are "checked" (key `13`) are taken into account.
+fn speed_portals(objects: &Objects) -> impl Iterator<Item = (f32, Speed)> + '_ {
+    objects.objects.iter().filter_map(|object| {
+        match object.metadata {
+            ObjectData::SpeedPortal { checked: true, speed } => Some((object.x, speed)),
+            _ => None,
+        }
+    })
+}
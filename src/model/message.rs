@@ -0,0 +1,177 @@
+//! Module containing structs modelling private messages exchanged between Geometry Dash accounts
+
+use crate::{util, Base64Decoded, ProcessError, Thunk, ThunkContent};
+use base64::URL_SAFE;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// The XOR key the game uses to encode the bodies of messages
+pub const MESSAGE_XOR_KEY: &str = "14251";
+
+/// The folder a [`Message`] is in, from the point of view of the authenticated user
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MessageFolder {
+    /// The message was received by the authenticated user
+    #[default]
+    Inbox,
+
+    /// The message was sent by the authenticated user
+    Sent,
+}
+
+impl From<bool> for MessageFolder {
+    fn from(is_sender: bool) -> Self {
+        if is_sender {
+            MessageFolder::Sent
+        } else {
+            MessageFolder::Inbox
+        }
+    }
+}
+
+impl From<MessageFolder> for bool {
+    fn from(folder: MessageFolder) -> Self {
+        folder == MessageFolder::Sent
+    }
+}
+
+/// Struct modelling a private message
+///
+/// ## GD Internals:
+/// Messages are listed in responses to `getGJMessages20.php` requests, without their
+/// [`body`](Message::body). The full message is returned by `downloadGJMessage20.php`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Message<'a> {
+    /// The unique ID of this [`Message`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `1`
+    pub message_id: u64,
+
+    /// The account ID of the other party of this [`Message`], that is of its sender if it is in the
+    /// [`MessageFolder::Inbox`], and of its recipient if it is in the [`MessageFolder::Sent`] folder
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `2`
+    pub account_id: u64,
+
+    /// The user ID of the other party of this [`Message`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `3`
+    pub user_id: u64,
+
+    /// The subject of this [`Message`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `4` and base64 encoded
+    #[serde(borrow)]
+    pub subject: Thunk<'a, Base64Decoded<'a>>,
+
+    /// The body of this [`Message`], if it was downloaded
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `5`, but only in responses to `downloadGJMessage20.php`
+    /// requests. It is XOR-encoded with [`MESSAGE_XOR_KEY`] and then base64 encoded
+    #[serde(borrow)]
+    pub body: Option<Thunk<'a, MessageBody<'a>>>,
+
+    /// The name of the other party of this [`Message`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `6`
+    pub user_name: Cow<'a, str>,
+
+    /// Robtop's string describing how long ago this [`Message`] was sent, e.g. `"5 hours"`
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `7`
+    pub age: Cow<'a, str>,
+
+    /// Whether this [`Message`] has been read by its recipient
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `8`
+    pub is_read: bool,
+
+    /// The folder this [`Message`] is in
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `9`, as `1` if the authenticated user sent the message and
+    /// `0` otherwise
+    pub folder: MessageFolder,
+}
+
+impl<'a> Message<'a> {
+    /// Converts this [`Message`] into one that doesn't borrow from the response it was parsed from.
+    ///
+    /// The subject and body stay unprocessed if they haven't been processed yet.
+    pub fn into_owned(self) -> Message<'static> {
+        Message {
+            message_id: self.message_id,
+            account_id: self.account_id,
+            user_id: self.user_id,
+            subject: self.subject.into_owned(Base64Decoded::into_owned),
+            body: self.body.map(|body| body.into_owned(MessageBody::into_owned)),
+            user_name: Cow::Owned(self.user_name.into_owned()),
+            age: Cow::Owned(self.age.into_owned()),
+            is_read: self.is_read,
+            folder: self.folder,
+        }
+    }
+}
+
+/// The decoded body of a [`Message`]
+///
+/// ## GD Internals:
+/// Message bodies are XOR-encoded with [`MESSAGE_XOR_KEY`] and then base64 encoded. An unprocessed
+/// [`Thunk`] contains the body in this encoded form
+#[derive(Debug, Eq, PartialEq, Serialize, Deserialize, Clone)]
+#[serde(transparent)]
+pub struct MessageBody<'a>(#[serde(borrow)] pub Cow<'a, str>);
+
+impl MessageBody<'_> {
+    pub fn into_owned(self) -> MessageBody<'static> {
+        MessageBody(Cow::Owned(self.0.into_owned()))
+    }
+}
+
+impl<'a> ThunkContent<'a> for MessageBody<'a> {
+    type Error = ProcessError;
+
+    fn from_unprocessed(unprocessed: &'a str) -> Result<Self, ProcessError> {
+        MessageBody::from_owned_unprocessed(unprocessed)
+    }
+
+    fn from_owned_unprocessed(unprocessed: &str) -> Result<Self, ProcessError> {
+        robtop_decode_message_body(unprocessed).map(|body| MessageBody(Cow::Owned(body)))
+    }
+
+    fn as_unprocessed(&self) -> Result<Cow<'_, str>, ProcessError> {
+        Ok(Cow::Owned(robtop_encode_message_body(self.0.as_bytes())))
+    }
+}
+
+/// Encodes the given message body the way the game does: XOR-encoding with [`MESSAGE_XOR_KEY`],
+/// followed by base64 encoding
+pub(crate) fn robtop_encode_message_body(body: &[u8]) -> String {
+    let mut encoded = body.to_vec();
+
+    util::cyclic_xor(&mut encoded[..], MESSAGE_XOR_KEY);
+
+    base64::encode_config(&encoded, URL_SAFE)
+}
+
+fn robtop_decode_message_body(raw_body: &str) -> Result<String, ProcessError> {
+    let mut decoded = base64::decode_config(raw_body, URL_SAFE).map_err(ProcessError::Base64)?;
+
+    util::cyclic_xor(&mut decoded[..], MESSAGE_XOR_KEY);
+
+    String::from_utf8(decoded).map_err(ProcessError::FromUtf8)
+}
+
+mod internal {
+    use crate::model::message::{Message, MessageBody};
+
+    include!(concat!(env!("OUT_DIR"), "/message.boilerplate"));
+}
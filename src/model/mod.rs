@@ -20,6 +20,8 @@ use std::fmt::{Display, Formatter};
 pub mod comment;
 pub mod creator;
 pub mod level;
+pub mod message;
+pub mod pack;
 pub mod song;
 pub mod user;
 
@@ -0,0 +1,247 @@
+//! Module containing structs modelling the collections of levels featured in Geometry Dash: map
+//! packs and gauntlets
+
+use crate::model::level::{color::RgbColor, DemonRating, LevelRating};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+mod internal {
+    use crate::model::{
+        level::{color::RgbColor, LevelRating},
+        pack::{Gauntlet, MapPack},
+    };
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+    use std::borrow::Cow;
+
+    /// A comma separated list of level IDs
+    #[derive(Debug)]
+    pub struct LevelIds<'a>(pub Cow<'a, [u64]>);
+
+    impl<'de, 'a> Deserialize<'de> for LevelIds<'a> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let ids = <&'de str>::deserialize(deserializer)?;
+
+            ids.split(',')
+                .filter(|id| !id.is_empty())
+                .map(|id| id.parse().map_err(|_| D::Error::custom(format!("Expected a list of level IDs, got '{}'", ids))))
+                .collect::<Result<Vec<_>, _>>()
+                .map(|ids| LevelIds(Cow::Owned(ids)))
+        }
+    }
+
+    impl Serialize for LevelIds<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&self.0.iter().map(ToString::to_string).collect::<Vec<_>>().join(","))
+        }
+    }
+
+    /// A color in the form `r,g,b`
+    #[derive(Debug, Clone, Copy)]
+    pub struct RgbString(RgbColor);
+
+    impl<'de> Deserialize<'de> for RgbString {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let color = <&'de str>::deserialize(deserializer)?;
+            let mut components = color.split(',').map(str::parse);
+
+            match (components.next(), components.next(), components.next(), components.next()) {
+                (Some(Ok(red)), Some(Ok(green)), Some(Ok(blue)), None) => Ok(RgbString(RgbColor { red, green, blue })),
+                _ => Err(D::Error::custom(format!("Expected a color of the form 'r,g,b', got '{}'", color))),
+            }
+        }
+    }
+
+    impl Serialize for RgbString {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&format!("{},{},{}", self.0.red, self.0.green, self.0.blue))
+        }
+    }
+
+    impl From<RgbString> for RgbColor {
+        fn from(color: RgbString) -> Self {
+            color.0
+        }
+    }
+
+    impl From<RgbColor> for RgbString {
+        fn from(color: RgbColor) -> Self {
+            RgbString(color)
+        }
+    }
+
+    /// The difficulty of a map pack, as a single value
+    #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+    #[serde(transparent)]
+    pub struct PackDifficulty(u8);
+
+    impl From<PackDifficulty> for LevelRating {
+        fn from(difficulty: PackDifficulty) -> Self {
+            super::difficulty_from_pack_value(difficulty.0)
+        }
+    }
+
+    impl From<LevelRating> for PackDifficulty {
+        fn from(rating: LevelRating) -> Self {
+            PackDifficulty(super::difficulty_into_pack_value(rating))
+        }
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/map_pack.boilerplate"));
+    include!(concat!(env!("OUT_DIR"), "/gauntlet.boilerplate"));
+}
+
+/// Struct modelling a map pack, a fixed collection of levels with its own rewards
+///
+/// ## GD Internals:
+/// Map packs are listed in responses to `getGJMapPacks21.php` requests. The levels of a map pack
+/// can be retrieved via a `getGJLevels21` request using
+/// [`LevelRequestType::MapPack`](crate::request::level::LevelRequestType::MapPack).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MapPack<'a> {
+    /// The ID of this [`MapPack`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `1`
+    pub pack_id: u64,
+
+    /// The name of this [`MapPack`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `2`
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+
+    /// The IDs of the levels in this [`MapPack`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `3`, as a comma separated list
+    pub levels: Vec<u64>,
+
+    /// The amount of stars awarded for completing all levels in this [`MapPack`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `4`
+    pub stars: u8,
+
+    /// The amount of coins awarded for completing all levels in this [`MapPack`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `5`
+    pub coins: u8,
+
+    /// The difficulty displayed for this [`MapPack`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `6`. Unlike the difficulty of levels, it is a single value:
+    /// `0` to `5` are auto to insane, `6` is a hard demon, and `7` to `10` are the easy, medium,
+    /// insane and extreme demon difficulties
+    pub difficulty: LevelRating,
+
+    /// The color of this [`MapPack`]'s name
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `7`, as a string of the form `r,g,b`
+    pub text_color: RgbColor,
+
+    /// The color of this [`MapPack`]'s progress bar
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `8`, as a string of the form `r,g,b`
+    pub bar_color: RgbColor,
+}
+
+impl<'a> MapPack<'a> {
+    /// Converts this [`MapPack`] into one that doesn't borrow from the response it was parsed from.
+    pub fn into_owned(self) -> MapPack<'static> {
+        MapPack {
+            pack_id: self.pack_id,
+            name: Cow::Owned(self.name.into_owned()),
+            levels: self.levels,
+            stars: self.stars,
+            coins: self.coins,
+            difficulty: self.difficulty,
+            text_color: self.text_color,
+            bar_color: self.bar_color,
+        }
+    }
+}
+
+/// Struct modelling a gauntlet, a themed collection of five levels
+///
+/// ## GD Internals:
+/// Gauntlets are listed in responses to `getGJGauntlets21.php` requests.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Gauntlet {
+    /// The ID of this [`Gauntlet`], which determines its theme (see [`Gauntlet::name`])
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `1`
+    pub gauntlet_id: u64,
+
+    /// The IDs of the levels in this [`Gauntlet`], in the order they need to be completed
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `3`, as a comma separated list
+    pub levels: Vec<u64>,
+}
+
+impl Gauntlet {
+    /// The name the game displays for this [`Gauntlet`], or [`None`] if its ID is not known to
+    /// dash-rs
+    pub fn name(&self) -> Option<&'static str> {
+        let name = match self.gauntlet_id {
+            1 => "Fire",
+            2 => "Ice",
+            3 => "Poison",
+            4 => "Shadow",
+            5 => "Lava",
+            6 => "Bonus",
+            7 => "Chaos",
+            8 => "Demon",
+            9 => "Time",
+            10 => "Crystal",
+            11 => "Magic",
+            12 => "Spike",
+            13 => "Monster",
+            14 => "Doom",
+            15 => "Death",
+            _ => return None,
+        };
+
+        Some(name)
+    }
+}
+
+fn difficulty_from_pack_value(value: u8) -> LevelRating {
+    match value {
+        0 => LevelRating::Auto,
+        1 => LevelRating::Easy,
+        2 => LevelRating::Normal,
+        3 => LevelRating::Hard,
+        4 => LevelRating::Harder,
+        5 => LevelRating::Insane,
+        6 => LevelRating::Demon(DemonRating::Hard),
+        7 => LevelRating::Demon(DemonRating::Easy),
+        8 => LevelRating::Demon(DemonRating::Medium),
+        9 => LevelRating::Demon(DemonRating::Insane),
+        10 => LevelRating::Demon(DemonRating::Extreme),
+        value => LevelRating::Unknown(value as i8),
+    }
+}
+
+fn difficulty_into_pack_value(rating: LevelRating) -> u8 {
+    match rating {
+        LevelRating::Auto | LevelRating::NotAvailable => 0,
+        LevelRating::Easy => 1,
+        LevelRating::Normal => 2,
+        LevelRating::Hard => 3,
+        LevelRating::Harder => 4,
+        LevelRating::Insane => 5,
+        LevelRating::Demon(DemonRating::Easy) => 7,
+        LevelRating::Demon(DemonRating::Medium) => 8,
+        LevelRating::Demon(DemonRating::Insane) => 9,
+        LevelRating::Demon(DemonRating::Extreme) => 10,
+        LevelRating::Demon(_) => 6,
+        LevelRating::Unknown(value) => value as u8,
+    }
+}
@@ -1,4 +1,4 @@
-use crate::serde::{PercentDecoded, ProcessError, Thunk};
+use crate::serde::{PercentDecoded, Thunk};
 use serde::{Deserialize, Serialize};
 use std::{
     borrow::Cow,
@@ -49,7 +49,7 @@ mod internal {
                     index_6: internal.index_6.map(Cow::Borrowed),
                     index_7: internal.index_7.map(Cow::Borrowed),
                     index_8: Cow::Borrowed(internal.index_8),
-                    link: Thunk::Unprocessed(match internal.index_10 {RefThunk::Unprocessed(unproc) => unproc, _ => unreachable!() }),
+                    link: Thunk::Unprocessed(match internal.index_10 {RefThunk::Unprocessed(unproc) => Cow::Borrowed(unproc), _ => unreachable!() }),
                 })
             }
             fn write_robtop_data<W: Write>(&self, writer: W) -> Result<(), SerError> {
@@ -129,8 +129,8 @@ pub struct NewgroundsSong<'a> {
 }
 
 impl<'a> NewgroundsSong<'a> {
-    pub fn into_owned(self) -> Result<NewgroundsSong<'static>, ProcessError> {
-        Ok(NewgroundsSong {
+    pub fn into_owned(self) -> NewgroundsSong<'static> {
+        NewgroundsSong {
             song_id: self.song_id,
             name: Cow::Owned(self.name.into_owned()),
             index_3: self.index_3,
@@ -139,8 +139,8 @@ impl<'a> NewgroundsSong<'a> {
             index_6: self.index_6.map(|cow| Cow::Owned(cow.into_owned())),
             index_7: self.index_7.map(|cow| Cow::Owned(cow.into_owned())),
             index_8: Cow::Owned(self.index_8.into_owned()),
-            link: Thunk::Processed(PercentDecoded(Cow::Owned(self.link.into_processed()?.0.into_owned()))),
-        })
+            link: self.link.into_owned(PercentDecoded::into_owned),
+        }
     }
 }
 
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 pub mod profile;
+pub mod ranked;
 pub mod searched;
 
 /// Enum representing the different types of moderator a user can be
@@ -53,6 +54,7 @@ pub enum IconType {
     Wave,
     Robot,
     Spider,
+    Swing,
     Unknown(u8),
 }
 
@@ -66,6 +68,7 @@ impl From<u8> for IconType {
             4 => IconType::Wave,
             5 => IconType::Robot,
             6 => IconType::Spider,
+            7 => IconType::Swing,
             i => IconType::Unknown(i),
         }
     }
@@ -81,6 +84,7 @@ impl From<IconType> for u8 {
             IconType::Wave => 4,
             IconType::Robot => 5,
             IconType::Spider => 6,
+            IconType::Swing => 7,
             IconType::Unknown(idx) => idx,
         }
     }
@@ -296,6 +296,53 @@ pub struct Profile<'a> {
     pub comment_history_state: CommentHistoryState,
 }
 
+impl<'a> Profile<'a> {
+    /// Converts this [`Profile`] into one that doesn't borrow from the response it was parsed from.
+    pub fn into_owned(self) -> Profile<'static> {
+        Profile {
+            name: Cow::Owned(self.name.into_owned()),
+            user_id: self.user_id,
+            stars: self.stars,
+            demons: self.demons,
+            leaderboard_position: self.leaderboard_position,
+            account_highlight: self.account_highlight,
+            creator_points: self.creator_points,
+            icon_id: self.icon_id,
+            primary_color: self.primary_color,
+            secondary_color: self.secondary_color,
+            secret_coins: self.secret_coins,
+            icon_type: self.icon_type,
+            account_id: self.account_id,
+            user_coins: self.user_coins,
+            message_state: self.message_state,
+            friends_state: self.friends_state,
+            youtube_url: self.youtube_url.map(|youtube| Youtube(Cow::Owned(youtube.0.into_owned()))),
+            cube_index: self.cube_index,
+            ship_index: self.ship_index,
+            ball_index: self.ball_index,
+            ufo_index: self.ufo_index,
+            wave_index: self.wave_index,
+            robot_index: self.robot_index,
+            has_glow: self.has_glow,
+            is_registered: self.is_registered,
+            global_rank: self.global_rank,
+            friend_state: self.friend_state,
+            unread_messages_count: self.unread_messages_count,
+            unread_friend_request_count: self.unread_friend_request_count,
+            new_friends_count: self.new_friends_count,
+            new_friend_request: self.new_friend_request,
+            time_since_score_update: self.time_since_score_update.map(|cow| Cow::Owned(cow.into_owned())),
+            spider_index: self.spider_index,
+            twitter_url: self.twitter_url.map(|twitter| Twitter(Cow::Owned(twitter.0.into_owned()))),
+            twitch_url: self.twitch_url.map(|twitch| Twitch(Cow::Owned(twitch.0.into_owned()))),
+            diamonds: self.diamonds,
+            death_effect_index: self.death_effect_index,
+            mod_level: self.mod_level,
+            comment_history_state: self.comment_history_state,
+        }
+    }
+}
+
 mod internal {
     use crate::model::user::profile::{Profile, Twitch, Twitter, Youtube};
 
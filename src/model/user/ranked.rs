@@ -0,0 +1,130 @@
+use crate::model::user::{Color, IconType};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Struct modelling an entry of the leaderboards returned by the `getGJScores` endpoint.
+///
+/// Apart from the rank and the diamond count, this carries the same data as a [`SearchedUser`](crate::model::user::searched::SearchedUser).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RankedUser<'a> {
+    /// This [`RankedUser`]'s name
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `1`
+    pub name: Cow<'a, str>,
+
+    /// The [`RankedUser`]'s unique user ID
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `2`
+    pub user_id: u64,
+
+    /// This [`RankedUser`]'s stars
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `3`
+    pub stars: u32,
+
+    /// This [`RankedUser`]'s beaten demons
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `4`
+    pub demons: u16,
+
+    /// This [`RankedUser`]'s position on the requested leaderboard, starting at `1`
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `6`
+    pub rank: u32,
+
+    /// This [`RankedUser`]'s creator points
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `8`
+    pub creator_points: u16,
+
+    /// The index of the icon being displayed.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `9`
+    pub icon_index: u16,
+
+    /// This [`RankedUser`]'s primary color
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `10`. See [`SearchedUser::primary_color`](crate::model::user::searched::SearchedUser::primary_color)
+    pub primary_color: Color,
+
+    /// This [`RankedUser`]'s secondary color
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `11`
+    pub secondary_color: Color,
+
+    /// The amount of secret coins this [`RankedUser`] has collected.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `13`
+    pub secret_coins: u8,
+
+    /// The type of icon being displayed
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `14`
+    pub icon_type: IconType,
+
+    /// Values indicating whether this [`RankedUser`] has glow activated or not.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `15`. A value of `"2"` means `true`, a value of `"0"` means
+    /// `false`
+    pub has_glow: bool,
+
+    /// The [`RankedUser`]'s unique account ID
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `16`
+    pub account_id: u64,
+
+    /// The amount of user coins this [`RankedUser`] has collected.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `17`
+    pub user_coins: u16,
+
+    /// The amount of diamonds this [`RankedUser`] has collected.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `46`
+    pub diamonds: u32,
+}
+
+impl<'a> RankedUser<'a> {
+    /// Converts this [`RankedUser`] into one that doesn't borrow from the response it was parsed
+    /// from.
+    pub fn into_owned(self) -> RankedUser<'static> {
+        RankedUser {
+            name: Cow::Owned(self.name.into_owned()),
+            user_id: self.user_id,
+            stars: self.stars,
+            demons: self.demons,
+            rank: self.rank,
+            creator_points: self.creator_points,
+            icon_index: self.icon_index,
+            primary_color: self.primary_color,
+            secondary_color: self.secondary_color,
+            secret_coins: self.secret_coins,
+            icon_type: self.icon_type,
+            has_glow: self.has_glow,
+            account_id: self.account_id,
+            user_coins: self.user_coins,
+            diamonds: self.diamonds,
+        }
+    }
+}
+
+mod internal {
+    use crate::model::user::ranked::RankedUser;
+
+    include!(concat!(env!("OUT_DIR"), "/ranked.boilerplate"));
+}
@@ -97,6 +97,29 @@ pub struct SearchedUser<'a> {
     pub user_coins: u16,
 }
 
+impl<'a> SearchedUser<'a> {
+    /// Converts this [`SearchedUser`] into one that doesn't borrow from the response it was parsed
+    /// from.
+    pub fn into_owned(self) -> SearchedUser<'static> {
+        SearchedUser {
+            name: Cow::Owned(self.name.into_owned()),
+            user_id: self.user_id,
+            stars: self.stars,
+            demons: self.demons,
+            index_6: self.index_6.map(|cow| Cow::Owned(cow.into_owned())),
+            creator_points: self.creator_points,
+            icon_index: self.icon_index,
+            primary_color: self.primary_color,
+            secondary_color: self.secondary_color,
+            secret_coins: self.secret_coins,
+            icon_type: self.icon_type,
+            has_glow: self.has_glow,
+            account_id: self.account_id,
+            user_coins: self.user_coins,
+        }
+    }
+}
+
 mod internal {
     use crate::model::user::searched::SearchedUser;
 
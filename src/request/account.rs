@@ -1,18 +1,48 @@
 use std::borrow::Cow;
 use std::fmt;
 use std::fmt::Formatter;
-use serde::{Serialize};
+use serde::{ser::SerializeStruct, Serialize, Serializer};
 
 use crate::{
-    request::{REQUEST_BASE_URL}
+    model::user::{
+        profile::{Twitch, Twitter, Youtube},
+        CommentHistoryState, FriendsState, MessageState,
+    },
+    request::{BaseRequest, Endpoint, ServerConfig, ACCOUNT_GD_21},
+    response::{self, ResponseError},
+    util,
 };
 
 pub const ACCOUNT_LOGIN_ENPOINT: &str = "accounts/loginGJAccount.php";
+pub const ACCOUNT_REGISTER_ENDPOINT: &str = "accounts/registerGJAccount.php";
+pub const ACCOUNT_SETTINGS_ENDPOINT: &str = "updateGJAccSettings20.php";
+pub const ACCOUNT_URL_ENDPOINT: &str = "getAccountURL.php";
+pub const ACCOUNT_BACKUP_ENDPOINT: &str = "accounts/backupGJAccountNew.php";
+pub const ACCOUNT_SYNC_ENDPOINT: &str = "accounts/syncGJAccountNew.php";
 pub const XOR_KEY: &str = "37526";
 pub const CONTENT_TYPE: &str = "Content-Type";
 pub const URL_FORM_ENCODED: &str = "application/x-www-form-urlencoded";
 
-#[derive(Debug, Clone, Serialize, Hash)]
+/// The salt appended to a password before hashing it into a `gjp2`
+pub const GJP2_SALT: &str = "mI29fmAnxgTs";
+
+/// Encodes a plain text password into the legacy `gjp` format
+///
+/// ## GD Internals:
+/// The `gjp` is the password XOR-ed with the key `37526` and then URL-safe base64 encoded
+pub fn encode_gjp(password: &str) -> String {
+    base64::encode_config(&util::xor(password.as_bytes().to_vec(), XOR_KEY.as_bytes()), base64::URL_SAFE)
+}
+
+/// Encodes a plain text password into the `gjp2` format used since Geometry Dash 2.2
+///
+/// ## GD Internals:
+/// The `gjp2` is the hex encoded SHA-1 hash of the password with [`GJP2_SALT`] appended
+pub fn encode_gjp2(password: &str) -> String {
+    util::sha_encrypt(&Cow::Owned(format!("{}{}", password, GJP2_SALT)))
+}
+
+#[derive(Debug, Clone, Hash)]
 pub struct LoginRequest<'a> {
 
     /// The Unique Device IDentifier (UDID) of the user to authenticate
@@ -28,15 +58,18 @@ pub struct LoginRequest<'a> {
     ///
     /// ## GD Internals:
     /// This field is called `userName` in the Boomlings API
-    #[serde(rename = "userName")]
     pub user_name: &'a str,
 
     /// The unencrypted password of the user to authenticate
     ///
     /// ## GD Internals:
-    /// This field is called `password` in the Boomlings API
+    /// This field is called `password` in the Boomlings API. If [`LoginRequest::use_gjp2`] is set,
+    /// the password is instead sent hashed as `gjp2`
     password: &'a str,
 
+    /// Whether to send the password as a `gjp2` hash instead of in plain text
+    pub use_gjp2: bool,
+
     /// The secret token to call /database/accounts routes
     pub secret: &'a str,
 }
@@ -44,25 +77,72 @@ pub struct LoginRequest<'a> {
 impl<'a> LoginRequest<'a> {
     const_setter!(user_name: &'a str);
     const_setter!(password: &'a str);
+    const_setter!(secret: &'a str);
+    const_setter!(use_gjp2: bool);
 
     pub fn default() -> Self {
         LoginRequest{
             udid: "100000",
             user_name: "",
             password: "",
+            use_gjp2: false,
             secret: super::ACCOUNT_SECRET
         }
     }
 
-    fn to_url(&self) -> String {
-        format!("{}{}", REQUEST_BASE_URL, ACCOUNT_LOGIN_ENPOINT)
+    /// Constructs the [`AuthenticatedUser`] for the account this request logged into, given the
+    /// server's response to it
+    pub fn authenticated_user(&self, response: &LoginResponse) -> AuthenticatedUser<'a> {
+        AuthenticatedUser::from_password(self.user_name, response.account_id, self.password)
+    }
+}
+
+impl Serialize for LoginRequest<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("LoginRequest", 4)?;
+
+        state.serialize_field("udid", self.udid)?;
+        state.serialize_field("userName", self.user_name)?;
+
+        if self.use_gjp2 {
+            state.serialize_field("gjp2", &encode_gjp2(self.password))?;
+        } else {
+            state.serialize_field("password", self.password)?;
+        }
+
+        state.serialize_field("secret", self.secret)?;
+        state.end()
+    }
+}
+
+impl<'a> Endpoint for LoginRequest<'a> {
+    const ENDPOINT: &'static str = ACCOUNT_LOGIN_ENPOINT;
+
+    type Response<'r> = LoginResponse;
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        LoginRequest {
+            secret: server.account_secret,
+            ..self.clone()
+        }
+        .serialize(serializer)
     }
 
-    fn to_string(&self) -> String {
-        super::to_string(&self)
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_login_gj_account_response(response)
     }
 }
 
+/// The IDs the servers respond with after a successful login
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LoginResponse {
+    /// The account ID of the user that logged in
+    pub account_id: u64,
+
+    /// The user ID of the user that logged in
+    pub user_id: u64,
+}
+
 #[derive(Debug, Serialize, Default, PartialEq, Eq, Clone, Hash)]
 pub struct AuthenticatedUser<'a> {
     /// The username of the authenticated user
@@ -84,33 +164,618 @@ pub struct AuthenticatedUser<'a> {
     /// ## GD Internals:
     /// This field is called `gjp` in the Boomlings API
     #[serde(rename = "gjp")]
-    password_hash: Cow<'a, str>
+    password_hash: Cow<'a, str>,
+
+    /// The salted SHA-1 hash of the authenticated user's password, this is just as sensitive as the `gjp`.
+    /// Only sent along if present
+    ///
+    /// ## GD Internals:
+    /// This field is called `gjp2` in the Boomlings API
+    #[serde(rename = "gjp2", skip_serializing_if = "Option::is_none")]
+    password_hash2: Option<Cow<'a, str>>,
 }
 
 impl<'a> AuthenticatedUser<'a> {
-    pub fn new(user_name: &'a str, account_id: u64, password_hash: Cow<'a, str>) -> Self {
+    pub const fn new(user_name: &'a str, account_id: u64, password_hash: Cow<'a, str>) -> Self {
         AuthenticatedUser{
             user_name,
             account_id,
             password_hash,
+            password_hash2: None,
+        }
+    }
+
+    /// Constructs an [`AuthenticatedUser`] from a plain text password, computing both the `gjp`
+    /// and the `gjp2`
+    pub fn from_password(user_name: &'a str, account_id: u64, password: &str) -> Self {
+        AuthenticatedUser {
+            user_name,
+            account_id,
+            password_hash: Cow::Owned(encode_gjp(password)),
+            password_hash2: Some(Cow::Owned(encode_gjp2(password))),
         }
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct AuthenticationError(String);
+/// Enum modelling the different ways a login attempt can be rejected by the servers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AuthenticationError {
+    /// The login failed for an unspecified reason
+    ///
+    /// ## GD Internals:
+    /// This is indicated by the response code `-1`
+    LoginFailed,
+
+    /// The password is too short
+    ///
+    /// ## GD Internals:
+    /// This is indicated by the response code `-8`
+    PasswordTooShort,
+
+    /// The username is too short
+    ///
+    /// ## GD Internals:
+    /// This is indicated by the response code `-9`
+    UserNameTooShort,
+
+    /// The account is linked to a different account
+    ///
+    /// ## GD Internals:
+    /// This is indicated by the response code `-10`
+    LinkedToDifferentAccount,
+
+    /// The username or password is incorrect
+    ///
+    /// ## GD Internals:
+    /// This is indicated by the response code `-11`
+    InvalidCredentials,
+
+    /// The account has been disabled
+    ///
+    /// ## GD Internals:
+    /// This is indicated by the response code `-12`
+    AccountDisabled,
+
+    /// The account is linked to a different Steam account
+    ///
+    /// ## GD Internals:
+    /// This is indicated by the response code `-13`
+    LinkedToDifferentSteamAccount,
+
+    /// The servers responded with an error code not covered by the other variants
+    Unknown(i32),
+}
+
+impl AuthenticationError {
+    /// Maps a negative response code of `loginGJAccount.php` to the error it represents
+    pub fn from_code(code: i32) -> Self {
+        match code {
+            -1 => AuthenticationError::LoginFailed,
+            -8 => AuthenticationError::PasswordTooShort,
+            -9 => AuthenticationError::UserNameTooShort,
+            -10 => AuthenticationError::LinkedToDifferentAccount,
+            -11 => AuthenticationError::InvalidCredentials,
+            -12 => AuthenticationError::AccountDisabled,
+            -13 => AuthenticationError::LinkedToDifferentSteamAccount,
+            code => AuthenticationError::Unknown(code),
+        }
+    }
+}
 
 impl std::error::Error for AuthenticationError {}
 
 impl fmt::Display for AuthenticationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        match self {
+            AuthenticationError::LoginFailed => write!(f, "login failed"),
+            AuthenticationError::PasswordTooShort => write!(f, "password too short"),
+            AuthenticationError::UserNameTooShort => write!(f, "username too short"),
+            AuthenticationError::LinkedToDifferentAccount => write!(f, "account linked to a different account"),
+            AuthenticationError::InvalidCredentials => write!(f, "invalid username or password"),
+            AuthenticationError::AccountDisabled => write!(f, "account disabled"),
+            AuthenticationError::LinkedToDifferentSteamAccount => write!(f, "account linked to a different steam account"),
+            AuthenticationError::Unknown(code) => write!(f, "login failed with unknown error code {}", code),
+        }
+    }
+}
+
+/// Struct modelled after a request to `registerGJAccount.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to create new accounts
+#[derive(Debug, Clone, Serialize, Hash)]
+pub struct RegisterAccountRequest<'a> {
+    /// The username of the account to create
+    ///
+    /// ## GD Internals:
+    /// This field is called `userName` in the Boomlings API
+    #[serde(rename = "userName")]
+    pub user_name: &'a str,
+
+    /// The unencrypted password of the account to create
+    ///
+    /// ## GD Internals:
+    /// This field is called `password` in the Boomlings API
+    password: &'a str,
+
+    /// The email address to link to the account
+    ///
+    /// ## GD Internals:
+    /// This field is called `email` in the Boomlings API
+    pub email: &'a str,
+
+    /// The secret token to call /database/accounts routes
+    pub secret: &'a str,
+}
+
+impl<'a> RegisterAccountRequest<'a> {
+    const_setter!(secret: &'a str);
+
+    pub const fn new(user_name: &'a str, password: &'a str, email: &'a str) -> Self {
+        RegisterAccountRequest {
+            user_name,
+            password,
+            email,
+            secret: super::ACCOUNT_SECRET,
+        }
+    }
+}
+
+impl<'a> Endpoint for RegisterAccountRequest<'a> {
+    const ENDPOINT: &'static str = ACCOUNT_REGISTER_ENDPOINT;
+
+    type Response<'r> = ();
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        RegisterAccountRequest {
+            secret: server.account_secret,
+            ..self.clone()
+        }
+        .serialize(serializer)
+    }
+
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_register_gj_account_response(response)
+    }
+}
+
+/// Struct modelled after a request to `updateGJAccSettings20.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to update an account's privacy settings and the
+/// social media handles shown on its profile
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountSettingsRequest<'a> {
+    /// The authenticated user whose settings to update
+    #[serde(flatten)]
+    authenticated_user: AuthenticatedUser<'a>,
+
+    /// Who can send the account messages
+    ///
+    /// ## GD Internals:
+    /// This field is called `mS` in the Boomlings API
+    #[serde(rename = "mS", serialize_with = "util::as_u8")]
+    pub message_state: MessageState,
+
+    /// Who can send the account friend requests
+    ///
+    /// ## GD Internals:
+    /// This field is called `frS` in the Boomlings API
+    #[serde(rename = "frS", serialize_with = "util::as_u8")]
+    pub friends_state: FriendsState,
+
+    /// Who can view the account's comment history
+    ///
+    /// ## GD Internals:
+    /// This field is called `cS` in the Boomlings API
+    #[serde(rename = "cS", serialize_with = "util::as_u8")]
+    pub comment_history_state: CommentHistoryState,
+
+    /// The account's YouTube channel. `None` removes the link from the profile
+    ///
+    /// ## GD Internals:
+    /// This field is called `yt` in the Boomlings API
+    #[serde(rename = "yt")]
+    pub youtube: Option<Youtube<'a>>,
+
+    /// The account's Twitter handle. `None` removes the link from the profile
+    ///
+    /// ## GD Internals:
+    /// This field is called `twitter` in the Boomlings API
+    pub twitter: Option<Twitter<'a>>,
+
+    /// The account's Twitch channel. `None` removes the link from the profile
+    ///
+    /// ## GD Internals:
+    /// This field is called `twitch` in the Boomlings API
+    pub twitch: Option<Twitch<'a>>,
+
+    /// The secret token to call /database/accounts routes
+    pub secret: &'a str,
+}
+
+impl<'a> AccountSettingsRequest<'a> {
+    const_setter!(message_state: MessageState);
+    const_setter!(friends_state: FriendsState);
+    const_setter!(comment_history_state: CommentHistoryState);
+    const_setter!(secret: &'a str);
+
+    /// Constructs a request that opens messages, friend requests and comment history to anyone and
+    /// clears all social media links
+    pub const fn new(authenticated_user: AuthenticatedUser<'a>) -> Self {
+        AccountSettingsRequest {
+            authenticated_user,
+            message_state: MessageState::Anyone,
+            friends_state: FriendsState::Anyone,
+            comment_history_state: CommentHistoryState::Anyone,
+            youtube: None,
+            twitter: None,
+            twitch: None,
+            secret: super::ACCOUNT_SECRET,
+        }
+    }
+
+    pub fn youtube(mut self, youtube: Youtube<'a>) -> Self {
+        self.youtube = Some(youtube);
+        self
+    }
+
+    pub fn twitter(mut self, twitter: Twitter<'a>) -> Self {
+        self.twitter = Some(twitter);
+        self
+    }
+
+    pub fn twitch(mut self, twitch: Twitch<'a>) -> Self {
+        self.twitch = Some(twitch);
+        self
+    }
+}
+
+impl<'a> Endpoint for AccountSettingsRequest<'a> {
+    const ENDPOINT: &'static str = ACCOUNT_SETTINGS_ENDPOINT;
+
+    type Response<'r> = ();
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        AccountSettingsRequest {
+            secret: server.account_secret,
+            ..self.clone()
+        }
+        .serialize(serializer)
+    }
+
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_update_gj_acc_settings_response(response)
+    }
+}
+
+/// The different kinds of account data server a client can ask for
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(into = "u8")]
+pub enum AccountUrlType {
+    /// The server to upload save data backups to
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the numeric value `1` in the Boomlings API
+    Backup,
+
+    /// The server to download (sync) save data from
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the numeric value `2` in the Boomlings API
+    Sync,
+}
+
+impl From<AccountUrlType> for u8 {
+    fn from(url_type: AccountUrlType) -> Self {
+        match url_type {
+            AccountUrlType::Backup => 1,
+            AccountUrlType::Sync => 2,
+        }
+    }
+}
+
+/// Struct modelled after a request to `getAccountURL.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to find out which server holds the save data of
+/// an account. Its response is the base URL of that server
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+pub struct AccountUrlRequest<'a> {
+    /// The account ID of the account whose data server to look up
+    ///
+    /// ## GD Internals:
+    /// This field is called `accountID` in the Boomlings API
+    #[serde(rename = "accountID")]
+    pub account_id: u64,
+
+    /// What the data server will be used for
+    ///
+    /// ## GD Internals:
+    /// This field is called `type` in the Boomlings API
+    #[serde(rename = "type")]
+    pub url_type: AccountUrlType,
+
+    /// The secret token of the Boomlings API
+    pub secret: &'a str,
+}
+
+impl<'a> AccountUrlRequest<'a> {
+    const_setter!(url_type: AccountUrlType);
+    const_setter!(secret: &'a str);
+
+    pub const fn new(account_id: u64) -> Self {
+        AccountUrlRequest {
+            account_id,
+            url_type: AccountUrlType::Backup,
+            secret: super::SECRET,
+        }
+    }
+}
+
+impl<'a> Endpoint for AccountUrlRequest<'a> {
+    const ENDPOINT: &'static str = ACCOUNT_URL_ENDPOINT;
+
+    type Response<'r> = &'r str;
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        AccountUrlRequest {
+            secret: server.secret,
+            ..*self
+        }
+        .serialize(serializer)
+    }
+
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_get_account_url_response(response)
+    }
+}
+
+/// Constructs the base URL of an account data server from the response to a [`AccountUrlRequest`]
+///
+/// ## GD Internals:
+/// `getAccountURL.php` responds with the bare host of the data server (e.g.
+/// `http://www.robtopgames.org`), to which the client appends `/database/` before the endpoint path
+pub fn data_server_url(account_url: &str) -> String {
+    format!("{}/database/", account_url.trim_end_matches('/'))
+}
+
+/// Struct modelled after a request to `backupGJAccountNew.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to upload a backup of an account's save data.
+/// Note that this endpoint lives on the account's data server, which has to be looked up using an
+/// [`AccountUrlRequest`] first
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupAccountRequest<'a> {
+    /// The base request data
+    pub base: BaseRequest<'a>,
+
+    /// The authenticated user whose save data to back up
+    #[serde(flatten)]
+    authenticated_user: AuthenticatedUser<'a>,
+
+    /// The save data to upload
+    ///
+    /// ## GD Internals:
+    /// This field is called `saveData` in the Boomlings API. It consists of the encoded
+    /// `CCGameManager.dat` and `CCLocalLevels.dat` files, separated by a `;`
+    #[serde(rename = "saveData")]
+    pub save_data: Cow<'a, str>,
+}
+
+impl<'a> BackupAccountRequest<'a> {
+    const_setter!(with_base, base, BaseRequest<'a>);
+
+    /// Constructs a new backup request from the encoded contents of the `CCGameManager.dat` and
+    /// `CCLocalLevels.dat` files
+    pub fn new(authenticated_user: AuthenticatedUser<'a>, game_manager: &str, local_levels: &str) -> Self {
+        BackupAccountRequest {
+            base: ACCOUNT_GD_21,
+            authenticated_user,
+            save_data: Cow::Owned(format!("{};{}", game_manager, local_levels)),
+        }
+    }
+
+    /// The account ID of the account whose data is backed up
+    pub fn account_id(&self) -> u64 {
+        self.authenticated_user.account_id
+    }
+}
+
+impl<'a> Endpoint for BackupAccountRequest<'a> {
+    const ENDPOINT: &'static str = ACCOUNT_BACKUP_ENDPOINT;
+
+    type Response<'r> = ();
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        BackupAccountRequest {
+            base: server.account_base_request(),
+            ..self.clone()
+        }
+        .serialize(serializer)
+    }
+
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_backup_gj_account_response(response)
+    }
+}
+
+/// Struct modelled after a request to `syncGJAccountNew.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to download the backed up save data of an
+/// account. Like [`BackupAccountRequest`], it has to be sent to the account's data server
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncAccountRequest<'a> {
+    /// The base request data
+    pub base: BaseRequest<'a>,
+
+    /// The authenticated user whose save data to download
+    #[serde(flatten)]
+    authenticated_user: AuthenticatedUser<'a>,
+}
+
+impl<'a> SyncAccountRequest<'a> {
+    const_setter!(with_base, base, BaseRequest<'a>);
+
+    pub const fn new(authenticated_user: AuthenticatedUser<'a>) -> Self {
+        SyncAccountRequest {
+            base: ACCOUNT_GD_21,
+            authenticated_user,
+        }
+    }
+
+    /// The account ID of the account whose data is downloaded
+    pub fn account_id(&self) -> u64 {
+        self.authenticated_user.account_id
+    }
+}
+
+impl<'a> Endpoint for SyncAccountRequest<'a> {
+    const ENDPOINT: &'static str = ACCOUNT_SYNC_ENDPOINT;
+
+    type Response<'r> = SyncResponse<'r>;
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        SyncAccountRequest {
+            base: server.account_base_request(),
+            ..self.clone()
+        }
+        .serialize(serializer)
+    }
+
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_sync_gj_account_response(response)
+    }
+}
+
+/// The save data the servers respond with to a [`SyncAccountRequest`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SyncResponse<'a> {
+    /// The encoded contents of the account's `CCGameManager.dat` file
+    pub game_manager: Cow<'a, str>,
+
+    /// The encoded contents of the account's `CCLocalLevels.dat` file
+    pub local_levels: Cow<'a, str>,
+
+    /// The version of the game that uploaded the save data, if included in the response
+    pub game_version: Option<u32>,
+
+    /// The binary version of the game that uploaded the save data, if included in the response
+    pub binary_version: Option<u32>,
+}
+
+impl<'a> SyncResponse<'a> {
+    pub fn into_owned(self) -> SyncResponse<'static> {
+        SyncResponse {
+            game_manager: Cow::Owned(self.game_manager.into_owned()),
+            local_levels: Cow::Owned(self.local_levels.into_owned()),
+            game_version: self.game_version,
+            binary_version: self.binary_version,
+        }
+    }
+}
+
+/// Enum modelling the different ways an account registration can be rejected by the servers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegistrationError {
+    /// The registration failed for an unspecified reason
+    ///
+    /// ## GD Internals:
+    /// This is indicated by the response code `-1`
+    RegistrationFailed,
+
+    /// The username is already taken
+    ///
+    /// ## GD Internals:
+    /// This is indicated by the response code `-2`
+    UserNameTaken,
+
+    /// The email address is already linked to another account
+    ///
+    /// ## GD Internals:
+    /// This is indicated by the response code `-3`
+    EmailTaken,
+
+    /// The username is too long or contains invalid characters
+    ///
+    /// ## GD Internals:
+    /// This is indicated by the response code `-4`
+    InvalidUserName,
+
+    /// The password contains invalid characters
+    ///
+    /// ## GD Internals:
+    /// This is indicated by the response code `-5`
+    InvalidPassword,
+
+    /// The email address is invalid
+    ///
+    /// ## GD Internals:
+    /// This is indicated by the response code `-6`
+    InvalidEmail,
+
+    /// The password is too short
+    ///
+    /// ## GD Internals:
+    /// This is indicated by the response code `-8`
+    PasswordTooShort,
+
+    /// The username is too short
+    ///
+    /// ## GD Internals:
+    /// This is indicated by the response code `-9`
+    UserNameTooShort,
+
+    /// The servers responded with an error code not covered by the other variants
+    Unknown(i32),
+}
+
+impl RegistrationError {
+    /// Maps a negative response code of `registerGJAccount.php` to the error it represents
+    pub fn from_code(code: i32) -> Self {
+        match code {
+            -1 => RegistrationError::RegistrationFailed,
+            -2 => RegistrationError::UserNameTaken,
+            -3 => RegistrationError::EmailTaken,
+            -4 => RegistrationError::InvalidUserName,
+            -5 => RegistrationError::InvalidPassword,
+            -6 => RegistrationError::InvalidEmail,
+            -8 => RegistrationError::PasswordTooShort,
+            -9 => RegistrationError::UserNameTooShort,
+            code => RegistrationError::Unknown(code),
+        }
+    }
+}
+
+impl std::error::Error for RegistrationError {}
+
+impl fmt::Display for RegistrationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistrationError::RegistrationFailed => write!(f, "registration failed"),
+            RegistrationError::UserNameTaken => write!(f, "username already taken"),
+            RegistrationError::EmailTaken => write!(f, "email already taken"),
+            RegistrationError::InvalidUserName => write!(f, "invalid username"),
+            RegistrationError::InvalidPassword => write!(f, "invalid password"),
+            RegistrationError::InvalidEmail => write!(f, "invalid email"),
+            RegistrationError::PasswordTooShort => write!(f, "password too short"),
+            RegistrationError::UserNameTooShort => write!(f, "username too short"),
+            RegistrationError::Unknown(code) => write!(f, "registration failed with unknown error code {}", code),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::request::account::LoginRequest;
+    use crate::{
+        model::user::{profile::Youtube, CommentHistoryState, MessageState},
+        request::{
+            account::{
+                data_server_url, encode_gjp, encode_gjp2, AccountSettingsRequest, AccountUrlRequest, AccountUrlType,
+                AuthenticatedUser, AuthenticationError, BackupAccountRequest, LoginRequest, RegisterAccountRequest,
+                SyncAccountRequest,
+            },
+            Endpoint,
+        },
+    };
+    use std::borrow::Cow;
 
     #[tokio::test]
     async fn serialize_login_request() {
@@ -123,4 +788,91 @@ mod tests {
             "udid=100000&userName=TestUser&password=PLAIN_TEXT_PASS_HERE&secret=Wmfv3899gc9"
         );
     }
+
+    #[test]
+    fn serialize_login_request_gjp2() {
+        let request = LoginRequest::default()
+            .user_name("TestUser")
+            .password("PLAIN_TEXT_PASS_HERE")
+            .use_gjp2(true);
+
+        assert_eq!(
+            request.to_string(),
+            "udid=100000&userName=TestUser&gjp2=8b15a4f6079a7b211c83f4ee027ac9c5411cbcde&secret=Wmfv3899gc9"
+        );
+    }
+
+    #[test]
+    fn gjp_encoding() {
+        assert_eq!(encode_gjp("PLAIN_TEXT_PASS_HERE"), "Y3t0e3hsY3BqYmxndGFlbH9wYHM=");
+        assert_eq!(encode_gjp2("PLAIN_TEXT_PASS_HERE"), "8b15a4f6079a7b211c83f4ee027ac9c5411cbcde");
+    }
+
+    #[test]
+    fn authentication_error_codes() {
+        assert_eq!(AuthenticationError::from_code(-1), AuthenticationError::LoginFailed);
+        assert_eq!(AuthenticationError::from_code(-10), AuthenticationError::LinkedToDifferentAccount);
+        assert_eq!(AuthenticationError::from_code(-11), AuthenticationError::InvalidCredentials);
+        assert_eq!(AuthenticationError::from_code(-12), AuthenticationError::AccountDisabled);
+        assert_eq!(AuthenticationError::from_code(-13), AuthenticationError::LinkedToDifferentSteamAccount);
+        assert_eq!(AuthenticationError::from_code(-42), AuthenticationError::Unknown(-42));
+    }
+
+    #[test]
+    fn serialize_register_account_request() {
+        let request = RegisterAccountRequest::new("TestUser", "PLAIN_TEXT_PASS_HERE", "test@example.com");
+
+        assert_eq!(
+            request.to_string(),
+            "userName=TestUser&password=PLAIN_TEXT_PASS_HERE&email=test@example.com&secret=Wmfv3899gc9"
+        );
+    }
+
+    #[test]
+    fn serialize_account_settings_request() {
+        let user = AuthenticatedUser::new("TestUser", 472634, Cow::Borrowed("VGhpc0lzQUZha2VQYXNzd29yZA=="));
+        let request = AccountSettingsRequest::new(user)
+            .message_state(MessageState::FriendsOnly)
+            .comment_history_state(CommentHistoryState::NoOne)
+            .youtube(Youtube(Cow::Borrowed("UCabc")));
+
+        assert_eq!(
+            request.to_string(),
+            "userName=TestUser&accountID=472634&gjp=VGhpc0lzQUZha2VQYXNzd29yZA==&mS=1&frS=0&cS=2&yt=UCabc&twitter=&twitch=&secret=Wmfv3899gc9"
+        );
+    }
+
+    #[test]
+    fn serialize_account_url_request() {
+        let request = AccountUrlRequest::new(472634).url_type(AccountUrlType::Sync);
+
+        assert_eq!(request.to_string(), "accountID=472634&type=2&secret=Wmfd2893gb7");
+    }
+
+    #[test]
+    fn serialize_backup_account_request() {
+        let user = AuthenticatedUser::new("TestUser", 472634, Cow::Borrowed("VGhpc0lzQUZha2VQYXNzd29yZA=="));
+        let request = BackupAccountRequest::new(user, "H4sIAAAAAAAAC_manager", "H4sIAAAAAAAAC_levels");
+
+        assert_eq!(
+            request.to_string(),
+            "gameVersion=21&binaryVersion=33&secret=Wmfv3899gc9&userName=TestUser&accountID=472634&gjp=VGhpc0lzQUZha2VQYXNzd29yZA==&saveData=H4sIAAAAAAAAC_manager;H4sIAAAAAAAAC_levels"
+        );
+    }
+
+    #[test]
+    fn serialize_sync_account_request() {
+        let user = AuthenticatedUser::new("TestUser", 472634, Cow::Borrowed("VGhpc0lzQUZha2VQYXNzd29yZA=="));
+
+        assert_eq!(
+            SyncAccountRequest::new(user).to_string(),
+            "gameVersion=21&binaryVersion=33&secret=Wmfv3899gc9&userName=TestUser&accountID=472634&gjp=VGhpc0lzQUZha2VQYXNzd29yZA=="
+        );
+    }
+
+    #[test]
+    fn account_data_server_url() {
+        assert_eq!(data_server_url("http://www.robtopgames.org"), "http://www.robtopgames.org/database/");
+        assert_eq!(data_server_url("http://localhost:8000/"), "http://localhost:8000/database/");
+    }
 }
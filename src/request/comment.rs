@@ -2,10 +2,12 @@
 
 use std::borrow::Cow;
 use crate::{
-    request::{BaseRequest, GD_21, REQUEST_BASE_URL},
+    model::comment::{level::LevelComment, profile::ProfileComment},
+    request::{BaseRequest, GD_21, Endpoint, PaginatedEndpoint, ServerConfig},
+    response::{self, Page, ResponseError},
     util
 };
-use serde::Serialize;
+use serde::{Serialize, Serializer};
 use crate::request::account::AuthenticatedUser;
 
 pub const LEVEL_COMMENTS_ENDPOINT: &str = "getGJComments21.php";
@@ -94,7 +96,7 @@ impl<'a> LevelCommentsRequest<'a> {
         Self::with_base(GD_21, level)
     }
 
-    const fn with_base(base: BaseRequest<'a>, level: u64) -> Self {
+    pub const fn with_base(base: BaseRequest<'a>, level: u64) -> Self {
         LevelCommentsRequest {
             level_id: level,
             base,
@@ -114,13 +116,33 @@ impl<'a> LevelCommentsRequest<'a> {
         self.sort_mode = SortMode::Recent;
         self
     }
+}
+
+impl<'a> Endpoint for LevelCommentsRequest<'a> {
+    const ENDPOINT: &'static str = LEVEL_COMMENTS_ENDPOINT;
 
-    pub fn to_url(&self) -> String {
-        format!("{}{}", REQUEST_BASE_URL, LEVEL_COMMENTS_ENDPOINT)
+    type Response<'r> = Page<LevelComment<'r>>;
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        LevelCommentsRequest {
+            base: server.base_request(),
+            ..*self
+        }
+        .serialize(serializer)
     }
 
-    pub fn to_string(&self) -> String {
-        super::to_string(&self)
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_get_gj_comments_response(response)
+    }
+}
+
+impl<'a> PaginatedEndpoint for LevelCommentsRequest<'a> {
+    fn page_number(&self) -> u32 {
+        self.page
+    }
+
+    fn set_page(&mut self, page: u32) {
+        self.page = page;
     }
 }
 
@@ -160,7 +182,7 @@ impl<'a> ProfileCommentsRequest<'a> {
         Self::with_base(GD_21, account)
     }
 
-    const fn with_base(base: BaseRequest<'a>, account: u64) -> Self {
+    pub const fn with_base(base: BaseRequest<'a>, account: u64) -> Self {
         ProfileCommentsRequest {
             account_id: account,
             base,
@@ -168,15 +190,34 @@ impl<'a> ProfileCommentsRequest<'a> {
             total: 0,
         }
     }
+}
+
+impl<'a> Endpoint for ProfileCommentsRequest<'a> {
+    const ENDPOINT: &'static str = PROFILE_COMMENT_ENDPOINT;
+
+    type Response<'r> = Page<ProfileComment<'r>>;
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        ProfileCommentsRequest {
+            base: server.base_request(),
+            ..*self
+        }
+        .serialize(serializer)
+    }
 
-    pub fn to_url(&self) -> String {
-        format!("{}{}", REQUEST_BASE_URL, PROFILE_COMMENT_ENDPOINT)
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_get_gj_acccount_comments_response(response)
     }
+}
 
-    pub fn to_string(&self) -> String {
-        super::to_string(&self)
+impl<'a> PaginatedEndpoint for ProfileCommentsRequest<'a> {
+    fn page_number(&self) -> u32 {
+        self.page
     }
 
+    fn set_page(&mut self, page: u32) {
+        self.page = page;
+    }
 }
 
 #[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
@@ -236,17 +277,37 @@ impl<'a> CommentHistoryRequest<'a> {
         Self::with_base(GD_21, player)
     }
 
-    pub fn to_url(&self) -> String {
-        format!("{}{}", REQUEST_BASE_URL, COMMENT_HISTORY_ENDPOINT)
+    pub const fn sort_mode(mut self, sort_mode: SortMode) -> Self {
+        self.sort_mode = sort_mode;
+        self
     }
+}
 
-    pub fn to_string(&self) -> String {
-        super::to_string(&self)
+impl<'a> Endpoint for CommentHistoryRequest<'a> {
+    const ENDPOINT: &'static str = COMMENT_HISTORY_ENDPOINT;
+
+    type Response<'r> = Page<LevelComment<'r>>;
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        CommentHistoryRequest {
+            base: server.base_request(),
+            ..*self
+        }
+        .serialize(serializer)
     }
 
-    pub const fn sort_mode(mut self, sort_mode: SortMode) -> Self {
-        self.sort_mode = sort_mode;
-        self
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_get_gj_comments_response(response)
+    }
+}
+
+impl<'a> PaginatedEndpoint for CommentHistoryRequest<'a> {
+    fn page_number(&self) -> u32 {
+        self.page
+    }
+
+    fn set_page(&mut self, page: u32) {
+        self.page = page;
     }
 }
 
@@ -276,15 +337,11 @@ impl<'a> UploadCommentRequest<'a> {
 
     const_setter!(percent: u8);
 
-    pub fn to_url(&self) -> String {
-        format!("{}{}", REQUEST_BASE_URL, UPLOAD_COMMENT_ENDPOINT)
-    }
-
     pub fn new(authenticated_user: AuthenticatedUser<'a>, level_id: u64) -> Self {
         Self::with_base(GD_21, authenticated_user, level_id)
     }
 
-    const fn with_base(base: BaseRequest<'a>, authenticated_user: AuthenticatedUser<'a>, level_id: u64) -> Self {
+    pub const fn with_base(base: BaseRequest<'a>, authenticated_user: AuthenticatedUser<'a>, level_id: u64) -> Self {
         UploadCommentRequest{
             base,
             authenticated_user,
@@ -306,9 +363,27 @@ impl<'a> UploadCommentRequest<'a> {
         let xor_chk = util::xor(util::sha_encrypt(&chk).as_bytes().to_vec(), COMMENT_XOR_CHK_KEY.as_bytes());
         base64::encode_config(xor_chk.as_slice(), base64::URL_SAFE).into()
     }
+}
+
+impl<'a> Endpoint for UploadCommentRequest<'a> {
+    const ENDPOINT: &'static str = UPLOAD_COMMENT_ENDPOINT;
+
+    type Response<'r> = u64;
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        UploadCommentRequest {
+            base: server.base_request(),
+            ..self.clone()
+        }
+        .serialize(serializer)
+    }
+
+    fn extra_fields(&self) -> Vec<(&'static str, String)> {
+        vec![("chk", self.generate_chk().into_owned())]
+    }
 
-    pub fn to_string(&self) -> String {
-        format!("{}&chk={}", super::to_string(&self), self.generate_chk())
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_upload_gj_comment_response(response)
     }
 }
 
@@ -342,7 +417,7 @@ impl<'a> DeleteCommentRequest<'a> {
         Self::with_base(GD_21, authenticated_user, level_id, comment_id)
     }
 
-    const fn with_base(base: BaseRequest<'a>, authenticated_user: AuthenticatedUser<'a>, level_id: u64, comment_id: u64) -> Self {
+    pub const fn with_base(base: BaseRequest<'a>, authenticated_user: AuthenticatedUser<'a>, level_id: u64, comment_id: u64) -> Self {
         DeleteCommentRequest {
             base,
             authenticated_user,
@@ -350,13 +425,23 @@ impl<'a> DeleteCommentRequest<'a> {
             level_id
         }
     }
+}
+
+impl<'a> Endpoint for DeleteCommentRequest<'a> {
+    const ENDPOINT: &'static str = DELETE_COMMENT_ENDPOINT;
 
-    pub fn to_url(&self) -> String {
-        format!("{}{}", REQUEST_BASE_URL, DELETE_COMMENT_ENDPOINT)
+    type Response<'r> = ();
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        DeleteCommentRequest {
+            base: server.base_request(),
+            ..self.clone()
+        }
+        .serialize(serializer)
     }
 
-    pub fn to_string(&self) -> String {
-        super::to_string(&self)
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_delete_gj_comment_response(response)
     }
 }
 
@@ -364,13 +449,13 @@ impl<'a> DeleteCommentRequest<'a> {
 mod tests {
     use std::borrow::Cow;
     use crate::request::comment::{LevelCommentsRequest, ProfileCommentsRequest, CommentHistoryRequest, UploadCommentRequest, DeleteCommentRequest, SortMode};
-    use crate::request::{AuthenticatedUser};
     use crate::request::account::AuthenticatedUser;
+    use crate::request::{Endpoint, ServerConfig, BOOMLINGS};
 
     const TEST_AUTHENTICATED_USER: AuthenticatedUser = AuthenticatedUser::new(
-        "Ryder",
-        57903,
-        Cow::Borrowed("UmVkaXNuZU1FQXJFREdlTnRJQw==")
+        "TestUser",
+        472634,
+        Cow::Borrowed("VGhpc0lzQUZha2VQYXNzd29yZA==")
     );
 
     #[test]
@@ -416,6 +501,20 @@ mod tests {
             request.to_string(),
             "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&userName=TestUser&accountID=472634&gjp=VGhpc0lzQUZha2VQYXNzd29yZA==&comment=VGhpcyBpcyBhIHRlc3QgY29tbWVudA==&levelID=85179632&percent=56&chk=UQsGAAEACgQBVQBaAwoGVwtSDQIEWAYOUFEAVQoIBVtWDwEHDQEJVA=="
         );
+        assert_eq!(request.to_string_on(&BOOMLINGS), request.to_string());
+    }
+
+    #[test]
+    fn serialize_upload_comment_on_server() {
+        let server = ServerConfig::new("http://localhost:8000/").secret("Custom");
+        let request = UploadCommentRequest::new(TEST_AUTHENTICATED_USER, 85179632)
+            .comment("This is a test comment")
+            .percent(56);
+
+        assert_eq!(
+            request.to_string_on(&server),
+            "gameVersion=21&binaryVersion=33&secret=Custom&userName=TestUser&accountID=472634&gjp=VGhpc0lzQUZha2VQYXNzd29yZA==&comment=VGhpcyBpcyBhIHRlc3QgY29tbWVudA==&levelID=85179632&percent=56&chk=UQsGAAEACgQBVQBaAwoGVwtSDQIEWAYOUFEAVQoIBVtWDwEHDQEJVA=="
+        );
     }
 
     #[test]
@@ -1,21 +1,29 @@
-use serde::{Deserialize, Serialize, Serializer};
+use serde::{ser::SerializeStruct, Deserialize, Serialize, Serializer};
 use crate::{
-    request::{BaseRequest, GD_21, REQUEST_BASE_URL},
+    request::{account::AuthenticatedUser, BaseRequest, GD_21, Endpoint, PaginatedEndpoint, ServerConfig},
     model::{
-        level::{DemonRating, LevelLength, LevelRating},
+        level::{
+            online_level::{Level, ListedLevel},
+            score::LevelScore,
+            DemonRating, LevelLength, LevelRating,
+        },
         song::MainSong,
     },
+    response::{self, Page, ResponseError},
 };
 
 pub const DOWNLOAD_LEVEL_ENDPOINT: &str = "downloadGJLevel22.php";
 pub const SEARCH_LEVEL_ENDPOINT: &str = "getGJLevels21.php";
+pub const DAILY_LEVEL_ENDPOINT: &str = "getGJDailyLevel.php";
+pub const LEVEL_SCORES_ENDPOINT: &str = "getGJLevelScores211.php";
+pub const PLATFORMER_LEVEL_SCORES_ENDPOINT: &str = "getGJLevelScoresPlat.php";
 
 /// Struct modelled after a request to `downloadGJLevel22.php`.
 ///
 /// In the Geometry Dash API, this endpoint is used to download a level from
 /// the servers and retrieve some additional information that isn't provided
 /// with the response to a [`LevelsRequest`]
-#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, Hash)]
+#[derive(Debug, Default, Clone, Copy, Deserialize, Hash)]
 pub struct LevelRequest<'a> {
     /// The base request data
     #[serde(borrow)]
@@ -23,11 +31,22 @@ pub struct LevelRequest<'a> {
 
     /// The ID of the level to download
     ///
+    /// Ignored if [`LevelRequest::daily`] is set.
+    ///
     /// ## GD Internals:
     /// This field is called `levelID` in the Boomlings API
     #[serde(rename = "levelID")]
     pub level_id: u64,
 
+    /// The kind of featured level to download instead of the level with
+    /// [`LevelRequest::level_id`], if any
+    ///
+    /// ## GD Internals:
+    /// Featured levels are requested using special, negative level IDs (see
+    /// [`DailyLevelKind::level_id`])
+    #[serde(skip)]
+    pub daily: Option<DailyLevelKind>,
+
     /// Some weird field the Geometry Dash Client sends along
     ///
     /// ## GD Internals:
@@ -62,17 +81,331 @@ impl<'a> LevelRequest<'a> {
         LevelRequest {
             base: GD_21,
             level_id,
+            daily: None,
             inc: true,
             extra: false,
         }
     }
 
-    pub fn to_url(&self) -> String {
-        format!("{}{}", REQUEST_BASE_URL, DOWNLOAD_LEVEL_ENDPOINT)
+    /// Constructs a request downloading the current daily, weekly or event level
+    ///
+    /// The number of the downloaded level is available as
+    /// [`Level::daily_number`](crate::model::level::online_level::Level::daily_number).
+    pub const fn daily(kind: DailyLevelKind) -> LevelRequest<'a> {
+        LevelRequest {
+            daily: Some(kind),
+            ..LevelRequest::new(0)
+        }
+    }
+}
+
+impl Serialize for LevelRequest<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("LevelRequest", 4)?;
+
+        state.serialize_field("base", &self.base)?;
+
+        match self.daily {
+            Some(kind) => state.serialize_field("levelID", &kind.level_id())?,
+            None => state.serialize_field("levelID", &self.level_id)?,
+        }
+
+        state.serialize_field("inc", &self.inc)?;
+        state.serialize_field("extra", &self.extra)?;
+        state.end()
+    }
+}
+
+impl<'a> Endpoint for LevelRequest<'a> {
+    const ENDPOINT: &'static str = DOWNLOAD_LEVEL_ENDPOINT;
+
+    type Response<'r> = Level<'r>;
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        LevelRequest {
+            base: server.base_request(),
+            ..*self
+        }
+        .serialize(serializer)
+    }
+
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_download_gj_level_response(response)
+    }
+}
+
+/// The different kinds of levels that are featured for a limited time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize)]
+pub enum DailyLevelKind {
+    /// The daily level, which changes every day
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `0` in requests
+    #[default]
+    Daily,
+
+    /// The weekly demon, which changes every week
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `1` in requests
+    Weekly,
+
+    /// The event level
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `2` in requests
+    Event,
+}
+
+impl DailyLevelKind {
+    /// The special level ID that can be used in a [`LevelRequest`] to download the current level of
+    /// this kind
+    pub const fn level_id(self) -> i64 {
+        match self {
+            DailyLevelKind::Daily => -1,
+            DailyLevelKind::Weekly => -2,
+            DailyLevelKind::Event => -3,
+        }
+    }
+}
+
+impl From<DailyLevelKind> for i32 {
+    fn from(kind: DailyLevelKind) -> Self {
+        match kind {
+            DailyLevelKind::Daily => 0,
+            DailyLevelKind::Weekly => 1,
+            DailyLevelKind::Event => 2,
+        }
+    }
+}
+
+/// Struct modelled after a request to `getGJDailyLevel.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to find out which daily, weekly or event level
+/// is currently featured, and when it will be replaced. The level itself is then downloaded via a
+/// [`LevelRequest`] constructed using [`LevelRequest::daily`].
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct DailyLevelRequest<'a> {
+    /// The base request data
+    pub base: BaseRequest<'a>,
+
+    /// The kind of level to retrieve information about
+    ///
+    /// ## GD Internals:
+    /// Version 2.1 of the game sends a `weekly` flag, which cannot express event levels. Newer
+    /// versions send a `type` field instead. Both are included in requests.
+    pub kind: DailyLevelKind,
+}
+
+impl<'a> DailyLevelRequest<'a> {
+    const_setter! { base[with_base]: BaseRequest<'a> }
+
+    const_setter! { kind: DailyLevelKind }
+
+    pub const fn new(kind: DailyLevelKind) -> DailyLevelRequest<'a> {
+        DailyLevelRequest { base: GD_21, kind }
+    }
+}
+
+impl Serialize for DailyLevelRequest<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("DailyLevelRequest", 3)?;
+
+        state.serialize_field("base", &self.base)?;
+        state.serialize_field("weekly", &(self.kind == DailyLevelKind::Weekly))?;
+        state.serialize_field("type", &i32::from(self.kind))?;
+        state.end()
+    }
+}
+
+impl<'a> Endpoint for DailyLevelRequest<'a> {
+    const ENDPOINT: &'static str = DAILY_LEVEL_ENDPOINT;
+
+    type Response<'r> = DailyLevelInfo;
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        DailyLevelRequest {
+            base: server.base_request(),
+            ..*self
+        }
+        .serialize(serializer)
+    }
+
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_get_gj_daily_level_response(response)
+    }
+}
+
+/// Information about the currently featured daily, weekly or event level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DailyLevelInfo {
+    /// The number of the current level, the same value as
+    /// [`Level::daily_number`](crate::model::level::online_level::Level::daily_number) of the
+    /// level itself
+    ///
+    /// ## GD Internals:
+    /// Numbers of weekly demons are offset by `100000`.
+    pub index: u32,
+
+    /// The number of seconds until the level is replaced
+    pub seconds_left: u64,
+}
+
+/// The different leaderboards a level has
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(into = "u8")]
+pub enum LevelLeaderboardType {
+    /// The scores of the authenticated user's friends
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `0` in requests
+    #[default]
+    Friends,
+
+    /// The best scores of all players
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `1` in requests
+    Top,
+
+    /// The best scores set during the current week
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `2` in requests
+    Weekly,
+}
+
+impl From<LevelLeaderboardType> for u8 {
+    fn from(leaderboard_type: LevelLeaderboardType) -> Self {
+        match leaderboard_type {
+            LevelLeaderboardType::Friends => 0,
+            LevelLeaderboardType::Top => 1,
+            LevelLeaderboardType::Weekly => 2,
+        }
+    }
+}
+
+/// What the scores on a level's leaderboard are ranked by
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(into = "u8")]
+pub enum LevelLeaderboardMode {
+    /// The best percentage reached, for classic levels
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `0` in requests to `getGJLevelScores211.php`
+    #[default]
+    Percentage,
+
+    /// The fastest completion time, for platformer levels
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `0` in requests to `getGJLevelScoresPlat.php`
+    Time,
+
+    /// The most points collected, for platformer levels
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the value `1` in requests to `getGJLevelScoresPlat.php`
+    Points,
+}
+
+impl LevelLeaderboardMode {
+    /// The endpoint leaderboards ranked by this mode are retrieved from
+    pub const fn endpoint(self) -> &'static str {
+        match self {
+            LevelLeaderboardMode::Percentage => LEVEL_SCORES_ENDPOINT,
+            LevelLeaderboardMode::Time | LevelLeaderboardMode::Points => PLATFORMER_LEVEL_SCORES_ENDPOINT,
+        }
+    }
+}
+
+impl From<LevelLeaderboardMode> for u8 {
+    fn from(mode: LevelLeaderboardMode) -> Self {
+        match mode {
+            LevelLeaderboardMode::Percentage | LevelLeaderboardMode::Time => 0,
+            LevelLeaderboardMode::Points => 1,
+        }
     }
+}
+
+/// Struct modelled after a request to `getGJLevelScores211.php`, or to `getGJLevelScoresPlat.php`
+/// for the leaderboards of platformer levels.
+///
+/// In the Geometry Dash API, these endpoints are used to retrieve the leaderboards of a level. It is
+/// only available to authenticated users, even for the [`LevelLeaderboardType::Top`] leaderboard.
+///
+/// The game also uses this endpoint to submit the authenticated user's own progress on the level.
+/// This is not supported by dash-rs.
+#[derive(Debug, Clone, Serialize, Hash)]
+pub struct LevelLeaderboardRequest<'a> {
+    /// The base request data
+    pub base: BaseRequest<'a>,
+
+    /// The authenticated user data
+    #[serde(flatten)]
+    authenticated_user: AuthenticatedUser<'a>,
+
+    /// The ID of the level whose leaderboard to retrieve
+    ///
+    /// ## GD Internals:
+    /// This field is called `levelID` in the Boomlings API
+    #[serde(rename = "levelID")]
+    pub level_id: u64,
+
+    /// The leaderboard to retrieve
+    ///
+    /// ## GD Internals:
+    /// This field is called `type` in the Boomlings API
+    #[serde(rename = "type")]
+    pub leaderboard_type: LevelLeaderboardType,
+
+    /// What the retrieved scores should be ranked by
+    ///
+    /// ## GD Internals:
+    /// This field is called `mode` in the Boomlings API. It also determines which endpoint the
+    /// request is sent to (see [`LevelLeaderboardMode::endpoint`])
+    pub mode: LevelLeaderboardMode,
+}
+
+impl<'a> LevelLeaderboardRequest<'a> {
+    const_setter! { base[with_base]: BaseRequest<'a> }
+
+    const_setter! { level_id: u64 }
+
+    const_setter! { leaderboard_type: LevelLeaderboardType }
 
-    pub fn to_string(&self) -> String {
-        super::to_string(&self)
+    const_setter! { mode: LevelLeaderboardMode }
+
+    pub const fn new(authenticated_user: AuthenticatedUser<'a>, level_id: u64) -> LevelLeaderboardRequest<'a> {
+        LevelLeaderboardRequest {
+            base: GD_21,
+            authenticated_user,
+            level_id,
+            leaderboard_type: LevelLeaderboardType::Friends,
+            mode: LevelLeaderboardMode::Percentage,
+        }
+    }
+}
+
+impl<'a> Endpoint for LevelLeaderboardRequest<'a> {
+    const ENDPOINT: &'static str = LEVEL_SCORES_ENDPOINT;
+
+    type Response<'r> = Vec<LevelScore<'r>>;
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        LevelLeaderboardRequest {
+            base: server.base_request(),
+            ..self.clone()
+        }
+        .serialize(serializer)
+    }
+
+    fn to_url_on(&self, server: &ServerConfig) -> String {
+        server.url(self.mode.endpoint())
+    }
+
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_get_gj_level_scores_response(response)
     }
 }
 
@@ -568,15 +901,34 @@ impl<'a> LevelsRequest<'a> {
         self.search_filters = filters;
         self
     }
+}
+
+impl<'a> Endpoint for LevelsRequest<'a> {
+    const ENDPOINT: &'static str = SEARCH_LEVEL_ENDPOINT;
+
+    type Response<'r> = Page<ListedLevel<'r>>;
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        LevelsRequest {
+            base: server.base_request(),
+            ..self.clone()
+        }
+        .serialize(serializer)
+    }
 
-    pub fn to_url(&self) -> String {
-        format!("{}{}", REQUEST_BASE_URL, SEARCH_LEVEL_ENDPOINT)
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_get_gj_levels_response(response)
     }
+}
 
-    pub fn to_string(&self) -> String {
-        super::to_string(&self)
+impl<'a> PaginatedEndpoint for LevelsRequest<'a> {
+    fn page_number(&self) -> u32 {
+        self.page
     }
 
+    fn set_page(&mut self, page: u32) {
+        self.page = page;
+    }
 }
 
 /// Newtype struct for [`DemonRating`] to implement robtop's serialization for requests on
@@ -656,7 +1008,12 @@ mod tests {
         model::level::LevelLength,
         request::level::{CompletionFilter, LevelRequestType, LevelsRequest, SearchFilters},
     };
-    use crate::request::level::LevelRequest;
+    use crate::request::level::{
+        DailyLevelInfo, DailyLevelKind, DailyLevelRequest, LevelLeaderboardMode, LevelLeaderboardRequest, LevelLeaderboardType, LevelRequest,
+    };
+    use crate::request::account::AuthenticatedUser;
+    use std::borrow::Cow;
+    use crate::request::Endpoint;
 
     #[tokio::test]
     async fn serialize_levels_request() {
@@ -689,4 +1046,73 @@ mod tests {
             "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&levelID=17448979&inc=0&extra=0"
         );
     }
+
+    #[test]
+    fn serialize_daily_level_request() {
+        assert_eq!(
+            DailyLevelRequest::new(DailyLevelKind::Weekly).to_string(),
+            "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&weekly=1&type=1"
+        );
+        assert_eq!(
+            DailyLevelRequest::new(DailyLevelKind::Event).to_string(),
+            "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&weekly=0&type=2"
+        );
+    }
+
+    #[test]
+    fn serialize_daily_level_download_request() {
+        assert_eq!(
+            LevelRequest::daily(DailyLevelKind::Weekly).to_string(),
+            "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&levelID=-2&inc=1&extra=0"
+        );
+        assert_eq!(
+            LevelRequest::daily(DailyLevelKind::Event).level_id(17448979).to_string(),
+            "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&levelID=-3&inc=1&extra=0"
+        );
+    }
+
+    #[test]
+    fn parse_daily_level_response() {
+        assert_eq!(
+            DailyLevelRequest::parse_response("100213|402135").unwrap(),
+            DailyLevelInfo {
+                index: 100213,
+                seconds_left: 402135
+            }
+        );
+        assert!(DailyLevelRequest::parse_response("-1").is_err());
+        assert!(DailyLevelRequest::parse_response("1234").is_err());
+    }
+
+    #[test]
+    fn serialize_level_leaderboard_request() {
+        let user = AuthenticatedUser::new("TestUser", 472634, Cow::Borrowed("VGhpc0lzQUZha2VQYXNzd29yZA=="));
+        let request = LevelLeaderboardRequest::new(user, 76298358).leaderboard_type(LevelLeaderboardType::Weekly);
+
+        assert_eq!(request.to_url(), "http://www.boomlings.com/database/getGJLevelScores211.php");
+        assert_eq!(
+            request.to_string(),
+            "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&userName=TestUser&accountID=472634&gjp=VGhpc0lzQUZha2VQYXNzd29yZA==&\
+             levelID=76298358&type=2&mode=0"
+        );
+    }
+
+    #[test]
+    fn serialize_platformer_level_leaderboard_request() {
+        let user = AuthenticatedUser::new("TestUser", 472634, Cow::Borrowed("VGhpc0lzQUZha2VQYXNzd29yZA=="));
+        let request = LevelLeaderboardRequest::new(user, 76298358).leaderboard_type(LevelLeaderboardType::Top);
+
+        assert_eq!(request.clone().mode(LevelLeaderboardMode::Time).to_url(), "http://www.boomlings.com/database/getGJLevelScoresPlat.php");
+        assert_eq!(
+            request.clone().mode(LevelLeaderboardMode::Time).to_string(),
+            "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&userName=TestUser&accountID=472634&gjp=VGhpc0lzQUZha2VQYXNzd29yZA==&\
+             levelID=76298358&type=1&mode=0"
+        );
+        assert_eq!(request.clone().mode(LevelLeaderboardMode::Points).to_url(), "http://www.boomlings.com/database/getGJLevelScoresPlat.php");
+        assert_eq!(
+            request.mode(LevelLeaderboardMode::Points).to_string(),
+            "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&userName=TestUser&accountID=472634&gjp=VGhpc0lzQUZha2VQYXNzd29yZA==&\
+             levelID=76298358&type=1&mode=1"
+        );
+    }
 }
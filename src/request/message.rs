@@ -0,0 +1,354 @@
+//! Module containing request definitions for retrieving, sending and deleting private messages
+
+use crate::{
+    model::message::{robtop_encode_message_body, Message, MessageFolder},
+    request::{account::AuthenticatedUser, BaseRequest, Endpoint, PaginatedEndpoint, ServerConfig, GD_21},
+    response::{self, Page, ResponseError},
+};
+use serde::{Serialize, Serializer};
+use std::borrow::Cow;
+
+pub const MESSAGES_ENDPOINT: &str = "getGJMessages20.php";
+pub const DOWNLOAD_MESSAGE_ENDPOINT: &str = "downloadGJMessage20.php";
+pub const UPLOAD_MESSAGE_ENDPOINT: &str = "uploadGJMessage20.php";
+pub const DELETE_MESSAGE_ENDPOINT: &str = "deleteGJMessages20.php";
+
+/// Struct modelled after a request to `getGJMessages20.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to list the messages in the authenticated
+/// user's inbox or sent folder. The listed messages do not contain their body, which needs to be
+/// retrieved via a [`DownloadMessageRequest`]
+#[derive(Debug, Clone, Serialize, Hash)]
+pub struct MessagesRequest<'a> {
+    /// The base request data
+    pub base: BaseRequest<'a>,
+
+    /// The authenticated user data
+    #[serde(flatten)]
+    authenticated_user: AuthenticatedUser<'a>,
+
+    /// The page of messages to retrieve. The first page is page `0`
+    ///
+    /// ## GD Internals:
+    /// This field is called `page` in the Boomlings API
+    pub page: u32,
+
+    /// Unknown, probably related to pagination
+    ///
+    /// ## GD Internals:
+    /// This field is called `total` in the Boomlings API
+    pub total: u32,
+
+    /// The folder whose messages to retrieve
+    ///
+    /// ## GD Internals:
+    /// This field is called `getSent` in the Boomlings API, and is `1` for the sent folder
+    #[serde(rename = "getSent", serialize_with = "serialize_folder")]
+    pub folder: MessageFolder,
+}
+
+impl<'a> MessagesRequest<'a> {
+    const_setter! { base[with_base]: BaseRequest<'a> }
+
+    const_setter! { page: u32 }
+
+    const_setter! { folder: MessageFolder }
+
+    pub const fn new(authenticated_user: AuthenticatedUser<'a>) -> MessagesRequest<'a> {
+        MessagesRequest {
+            base: GD_21,
+            authenticated_user,
+            page: 0,
+            total: 0,
+            folder: MessageFolder::Inbox,
+        }
+    }
+}
+
+impl<'a> Endpoint for MessagesRequest<'a> {
+    const ENDPOINT: &'static str = MESSAGES_ENDPOINT;
+
+    type Response<'r> = Page<Message<'r>>;
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        MessagesRequest {
+            base: server.base_request(),
+            ..self.clone()
+        }
+        .serialize(serializer)
+    }
+
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_get_gj_messages_response(response)
+    }
+}
+
+impl<'a> PaginatedEndpoint for MessagesRequest<'a> {
+    fn page_number(&self) -> u32 {
+        self.page
+    }
+
+    fn set_page(&mut self, page: u32) {
+        self.page = page;
+    }
+}
+
+/// Struct modelled after a request to `downloadGJMessage20.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to retrieve a single message including its body.
+/// Downloading a message from the inbox marks it as read.
+#[derive(Debug, Clone, Serialize, Hash)]
+pub struct DownloadMessageRequest<'a> {
+    /// The base request data
+    pub base: BaseRequest<'a>,
+
+    /// The authenticated user data
+    #[serde(flatten)]
+    authenticated_user: AuthenticatedUser<'a>,
+
+    /// The ID of the message to download
+    ///
+    /// ## GD Internals:
+    /// This field is called `messageID` in the Boomlings API
+    #[serde(rename = "messageID")]
+    pub message_id: u64,
+
+    /// The folder the message to download is in
+    ///
+    /// ## GD Internals:
+    /// This field is called `isSender` in the Boomlings API, and is `1` for the sent folder
+    #[serde(rename = "isSender", serialize_with = "serialize_folder")]
+    pub folder: MessageFolder,
+}
+
+impl<'a> DownloadMessageRequest<'a> {
+    const_setter! { base[with_base]: BaseRequest<'a> }
+
+    const_setter! { message_id: u64 }
+
+    const_setter! { folder: MessageFolder }
+
+    pub const fn new(authenticated_user: AuthenticatedUser<'a>, message_id: u64) -> DownloadMessageRequest<'a> {
+        DownloadMessageRequest {
+            base: GD_21,
+            authenticated_user,
+            message_id,
+            folder: MessageFolder::Inbox,
+        }
+    }
+}
+
+impl<'a> Endpoint for DownloadMessageRequest<'a> {
+    const ENDPOINT: &'static str = DOWNLOAD_MESSAGE_ENDPOINT;
+
+    type Response<'r> = Message<'r>;
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        DownloadMessageRequest {
+            base: server.base_request(),
+            ..self.clone()
+        }
+        .serialize(serializer)
+    }
+
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_download_gj_message_response(response)
+    }
+}
+
+/// Struct modelled after a request to `uploadGJMessage20.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to send a message to another account
+#[derive(Debug, Clone, Serialize, Hash)]
+pub struct UploadMessageRequest<'a> {
+    /// The base request data
+    pub base: BaseRequest<'a>,
+
+    /// The authenticated user data
+    #[serde(flatten)]
+    authenticated_user: AuthenticatedUser<'a>,
+
+    /// The account ID of the recipient
+    ///
+    /// ## GD Internals:
+    /// This field is called `toAccountID` in the Boomlings API
+    #[serde(rename = "toAccountID")]
+    pub recipient: u64,
+
+    /// The subject of the message, this value will be base64 url encoded
+    pub subject: Cow<'a, str>,
+
+    /// The body of the message, this value will be XOR-encoded with
+    /// [`MESSAGE_XOR_KEY`](crate::model::message::MESSAGE_XOR_KEY) and then base64 url encoded
+    pub body: Cow<'a, str>,
+}
+
+impl<'a> UploadMessageRequest<'a> {
+    const_setter! { base[with_base]: BaseRequest<'a> }
+
+    const_setter! { recipient: u64 }
+
+    pub const fn new(authenticated_user: AuthenticatedUser<'a>, recipient: u64) -> UploadMessageRequest<'a> {
+        UploadMessageRequest {
+            base: GD_21,
+            authenticated_user,
+            recipient,
+            subject: Cow::Borrowed(""),
+            body: Cow::Borrowed(""),
+        }
+    }
+
+    pub fn subject(mut self, subject: &str) -> Self {
+        self.subject = base64::encode_config(subject.as_bytes(), base64::URL_SAFE).into();
+        self
+    }
+
+    pub fn body(mut self, body: &str) -> Self {
+        self.body = robtop_encode_message_body(body.as_bytes()).into();
+        self
+    }
+}
+
+impl<'a> Endpoint for UploadMessageRequest<'a> {
+    const ENDPOINT: &'static str = UPLOAD_MESSAGE_ENDPOINT;
+
+    type Response<'r> = ();
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        UploadMessageRequest {
+            base: server.base_request(),
+            ..self.clone()
+        }
+        .serialize(serializer)
+    }
+
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_upload_gj_message_response(response)
+    }
+}
+
+/// Struct modelled after a request to `deleteGJMessages20.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to delete a message from the authenticated
+/// user's inbox or sent folder
+#[derive(Debug, Clone, Serialize, Hash)]
+pub struct DeleteMessageRequest<'a> {
+    /// The base request data
+    pub base: BaseRequest<'a>,
+
+    /// The authenticated user data
+    #[serde(flatten)]
+    authenticated_user: AuthenticatedUser<'a>,
+
+    /// The ID of the message to delete
+    ///
+    /// ## GD Internals:
+    /// This field is called `messageID` in the Boomlings API
+    #[serde(rename = "messageID")]
+    pub message_id: u64,
+
+    /// The folder the message to delete is in
+    ///
+    /// ## GD Internals:
+    /// This field is called `isSender` in the Boomlings API, and is `1` for the sent folder
+    #[serde(rename = "isSender", serialize_with = "serialize_folder")]
+    pub folder: MessageFolder,
+}
+
+impl<'a> DeleteMessageRequest<'a> {
+    const_setter! { base[with_base]: BaseRequest<'a> }
+
+    const_setter! { message_id: u64 }
+
+    const_setter! { folder: MessageFolder }
+
+    pub const fn new(authenticated_user: AuthenticatedUser<'a>, message_id: u64) -> DeleteMessageRequest<'a> {
+        DeleteMessageRequest {
+            base: GD_21,
+            authenticated_user,
+            message_id,
+            folder: MessageFolder::Inbox,
+        }
+    }
+}
+
+impl<'a> Endpoint for DeleteMessageRequest<'a> {
+    const ENDPOINT: &'static str = DELETE_MESSAGE_ENDPOINT;
+
+    type Response<'r> = ();
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        DeleteMessageRequest {
+            base: server.base_request(),
+            ..self.clone()
+        }
+        .serialize(serializer)
+    }
+
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_delete_gj_messages_response(response)
+    }
+}
+
+fn serialize_folder<S: Serializer>(folder: &MessageFolder, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bool((*folder).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::message::MessageFolder,
+        request::{
+            account::AuthenticatedUser,
+            message::{DeleteMessageRequest, DownloadMessageRequest, MessagesRequest, UploadMessageRequest},
+            Endpoint,
+        },
+    };
+    use std::borrow::Cow;
+
+    const TEST_AUTHENTICATED_USER: AuthenticatedUser = AuthenticatedUser::new("TestUser", 472634, Cow::Borrowed("VGhpc0lzQUZha2VQYXNzd29yZA=="));
+
+    #[test]
+    fn serialize_messages_request() {
+        let request = MessagesRequest::new(TEST_AUTHENTICATED_USER).folder(MessageFolder::Sent).page(1);
+
+        assert_eq!(
+            request.to_string(),
+            "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&userName=TestUser&accountID=472634&gjp=VGhpc0lzQUZha2VQYXNzd29yZA==&\
+             page=1&total=0&getSent=1"
+        );
+    }
+
+    #[test]
+    fn serialize_download_message_request() {
+        let request = DownloadMessageRequest::new(TEST_AUTHENTICATED_USER, 64297155);
+
+        assert_eq!(
+            request.to_string(),
+            "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&userName=TestUser&accountID=472634&gjp=VGhpc0lzQUZha2VQYXNzd29yZA==&\
+             messageID=64297155&isSender=0"
+        );
+    }
+
+    #[test]
+    fn serialize_upload_message_request() {
+        let request = UploadMessageRequest::new(TEST_AUTHENTICATED_USER, 34499).subject("Hello").body("Hi there!");
+
+        assert_eq!(
+            request.to_string(),
+            "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&userName=TestUser&accountID=472634&gjp=VGhpc0lzQUZha2VQYXNzd29yZA==&\
+             toAccountID=34499&subject=SGVsbG8=&body=eV0SQVlURlcU"
+        );
+    }
+
+    #[test]
+    fn serialize_delete_message_request() {
+        let request = DeleteMessageRequest::new(TEST_AUTHENTICATED_USER, 64297155).folder(MessageFolder::Sent);
+
+        assert_eq!(
+            request.to_string(),
+            "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&userName=TestUser&accountID=472634&gjp=VGhpc0lzQUZha2VQYXNzd29yZA==&\
+             messageID=64297155&isSender=1"
+        );
+    }
+}
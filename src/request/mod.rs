@@ -9,9 +9,10 @@ use crate::{
     model::{
         GameVersion
     },
+    response::ResponseError,
     serde::RequestSerializer,
 };
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 
 macro_rules! const_setter {
     ($name: ident, $field: ident, $t: ty) => {
@@ -47,9 +48,11 @@ macro_rules! const_setter {
 
 pub mod comment;
 pub mod level;
+pub mod message;
 pub mod user;
 pub mod account;
 pub mod moderator;
+pub mod pack;
 
 pub const REQUEST_BASE_URL: &str = "http://www.boomlings.com/database/";
 
@@ -61,6 +64,9 @@ pub const MODERATOR_SECRET: &str = "Wmfp3879gc3";
 pub const CONTENT_TYPE: &str = "Content-Type";
 pub const URL_FORM_ENCODED: &str = "application/x-www-form-urlencoded";
 
+/// A [`ServerConfig`] describing the official Geometry Dash servers
+pub const BOOMLINGS: ServerConfig<'static> = ServerConfig::new(REQUEST_BASE_URL);
+
 /// A `BaseRequest` instance that has all its fields set to the
 /// same values a Geometry Dash 2.1 client would use
 pub const GD_21: BaseRequest = BaseRequest::new(
@@ -75,6 +81,12 @@ pub const MODERATOR_GD_21: BaseRequest = BaseRequest::new(
     MODERATOR_SECRET,
 );
 
+pub const ACCOUNT_GD_21: BaseRequest = BaseRequest::new(
+    GameVersion::Version { major: 2, minor: 1 },
+    GameVersion::Version { major: 3, minor: 3 },
+    ACCOUNT_SECRET,
+);
+
 /// Base data included in every request made
 ///
 /// The fields in this struct are only relevant when making a request to the
@@ -127,6 +139,158 @@ impl Default for BaseRequest<'static> {
     }
 }
 
+/// Trait implemented by all request structs, tying each of them to the endpoint it is sent to and
+/// to the way the server's response to it is parsed
+///
+/// This allows writing code generic over all endpoints, such as clients, caches or proxies.
+pub trait Endpoint: Serialize {
+    /// The path of this endpoint, relative to the base URL of a server
+    const ENDPOINT: &'static str;
+
+    /// The type the server's response is parsed into, borrowing from the response body
+    type Response<'r>;
+
+    /// The URL of this endpoint on the Boomlings servers
+    fn to_url(&self) -> String {
+        self.to_url_on(&BOOMLINGS)
+    }
+
+    /// The URL of this endpoint on the given server
+    fn to_url_on(&self, server: &ServerConfig) -> String {
+        server.url(Self::ENDPOINT)
+    }
+
+    /// The form-encoded body of this request
+    fn to_string(&self) -> String {
+        with_extra_fields(to_string(self), self.extra_fields())
+    }
+
+    /// The form-encoded body of this request when sent to the given server, authenticated with the
+    /// secret the server uses for this kind of request instead of the one set on the request
+    fn to_string_on(&self, server: &ServerConfig) -> String {
+        with_extra_fields(to_string(OnServer(self, server)), self.extra_fields())
+    }
+
+    /// Serializes this request with its [`BaseRequest`] (or secret) replaced by the one the given
+    /// server uses for this kind of request, see [`ServerConfig::base_request`] and friends
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error>;
+
+    /// Additional fields appended to the form-encoded body after the serialized request, such as
+    /// checksums computed from the other fields
+    fn extra_fields(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    /// Parses the server's response to a request to this endpoint
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>>;
+}
+
+/// Trait implemented by requests to endpoints whose responses are split into
+/// [`Page`](crate::response::Page)s
+pub trait PaginatedEndpoint: Endpoint {
+    /// The page this request retrieves. The first page is page `0`
+    fn page_number(&self) -> u32;
+
+    /// Changes the page this request retrieves
+    fn set_page(&mut self, page: u32);
+}
+
+/// Configuration of the server requests are sent to
+///
+/// This allows pointing requests at private servers (GDPS) or local mocks instead of
+/// `boomlings.com`. [`BOOMLINGS`] is the configuration of the official Geometry Dash servers and
+/// used whenever no other configuration is specified.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ServerConfig<'a> {
+    /// The URL all endpoint paths are appended to. Needs to end with a `/`
+    pub base_url: &'a str,
+
+    /// Replacement paths for specific endpoints, as pairs of the default endpoint path (for
+    /// instance [`DOWNLOAD_LEVEL_ENDPOINT`](level::DOWNLOAD_LEVEL_ENDPOINT)) and the path to use
+    /// instead. Endpoints not listed here keep their default path.
+    pub endpoint_overrides: &'a [(&'a str, &'a str)],
+
+    /// The secret included in regular requests, replacing [`SECRET`]
+    pub secret: &'a str,
+
+    /// The secret included in requests to `accounts/` endpoints, replacing [`ACCOUNT_SECRET`]
+    pub account_secret: &'a str,
+
+    /// The secret included in requests to moderator endpoints, replacing [`MODERATOR_SECRET`]
+    pub moderator_secret: &'a str,
+}
+
+impl<'a> ServerConfig<'a> {
+    const_setter!(base_url: &'a str);
+
+    const_setter!(endpoint_overrides: &'a [(&'a str, &'a str)]);
+
+    const_setter!(secret: &'a str);
+
+    const_setter!(account_secret: &'a str);
+
+    const_setter!(moderator_secret: &'a str);
+
+    /// Constructs a new [`ServerConfig`] for the server at the given base URL, using the same
+    /// secrets as the Boomlings servers and the default endpoint paths
+    pub const fn new(base_url: &'a str) -> Self {
+        ServerConfig {
+            base_url,
+            endpoint_overrides: &[],
+            secret: SECRET,
+            account_secret: ACCOUNT_SECRET,
+            moderator_secret: MODERATOR_SECRET,
+        }
+    }
+
+    /// Gets the path of the given endpoint on this server, taking [`ServerConfig::endpoint_overrides`]
+    /// into account
+    pub fn endpoint_path(&self, endpoint: &'a str) -> &'a str {
+        self.endpoint_overrides
+            .iter()
+            .find(|(default, _)| *default == endpoint)
+            .map(|(_, path)| *path)
+            .unwrap_or(endpoint)
+    }
+
+    /// Constructs the full URL of the given endpoint on this server
+    pub fn url(&self, endpoint: &'a str) -> String {
+        format!("{}{}", self.base_url, self.endpoint_path(endpoint))
+    }
+
+    /// A [`BaseRequest`] with the same versions as [`GD_21`], but using this server's secret
+    pub const fn base_request(&self) -> BaseRequest<'a> {
+        BaseRequest {
+            secret: self.secret,
+            ..GD_21
+        }
+    }
+
+    /// A [`BaseRequest`] with the same versions as [`ACCOUNT_GD_21`], but using this server's
+    /// account secret
+    pub const fn account_base_request(&self) -> BaseRequest<'a> {
+        BaseRequest {
+            secret: self.account_secret,
+            ..ACCOUNT_GD_21
+        }
+    }
+
+    /// A [`BaseRequest`] with the same versions as [`MODERATOR_GD_21`], but using this server's
+    /// moderator secret
+    pub const fn moderator_base_request(&self) -> BaseRequest<'a> {
+        BaseRequest {
+            secret: self.moderator_secret,
+            ..MODERATOR_GD_21
+        }
+    }
+}
+
+impl Default for ServerConfig<'static> {
+    fn default() -> Self {
+        BOOMLINGS
+    }
+}
+
 pub(crate) fn to_string<S: Serialize>(request: S) -> String {
     let mut output = Vec::new();
     let mut serializer = RequestSerializer::new(&mut output);
@@ -134,4 +298,85 @@ pub(crate) fn to_string<S: Serialize>(request: S) -> String {
     request.serialize(&mut serializer).unwrap();
 
     String::from_utf8(output).unwrap()
-}
\ No newline at end of file
+}
+
+/// Serializes a request as it is sent to the given server, see [`Endpoint::serialize_on`]
+struct OnServer<'r, E: ?Sized>(&'r E, &'r ServerConfig<'r>);
+
+impl<E: Endpoint + ?Sized> Serialize for OnServer<'_, E> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_on(self.1, serializer)
+    }
+}
+
+fn with_extra_fields(mut body: String, extra_fields: Vec<(&'static str, String)>) -> String {
+    for (key, value) in extra_fields {
+        body.push('&');
+        body.push_str(key);
+        body.push('=');
+        body.push_str(&value);
+    }
+
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::request::{
+        account::{AuthenticatedUser, LoginRequest},
+        level::{LevelRequest, DOWNLOAD_LEVEL_ENDPOINT},
+        moderator::{SuggestStarsRequest, SuggestedStars},
+        Endpoint, ServerConfig, BOOMLINGS,
+    };
+    use std::borrow::Cow;
+
+    #[test]
+    fn default_server_url() {
+        assert_eq!(LevelRequest::new(1).to_url(), "http://www.boomlings.com/database/downloadGJLevel22.php");
+    }
+
+    #[test]
+    fn endpoint_override_url() {
+        let server = ServerConfig::new("http://localhost:8000/").endpoint_overrides(&[(DOWNLOAD_LEVEL_ENDPOINT, "levels/download.php")]);
+
+        assert_eq!(LevelRequest::new(1).to_url_on(&server), "http://localhost:8000/levels/download.php");
+        assert_eq!(server.url("getGJLevels21.php"), "http://localhost:8000/getGJLevels21.php");
+        assert_eq!(BOOMLINGS.endpoint_path(DOWNLOAD_LEVEL_ENDPOINT), DOWNLOAD_LEVEL_ENDPOINT);
+    }
+
+    #[test]
+    fn server_secret() {
+        let server = ServerConfig::new("http://localhost:8000/").secret("Custom");
+
+        assert_eq!(
+            LevelRequest::new(1).with_base(server.base_request()).to_string(),
+            "gameVersion=21&binaryVersion=33&secret=Custom&levelID=1&inc=1&extra=0"
+        );
+        assert_eq!(
+            LevelRequest::new(1).to_string_on(&server),
+            "gameVersion=21&binaryVersion=33&secret=Custom&levelID=1&inc=1&extra=0"
+        );
+    }
+
+    #[test]
+    fn server_account_and_moderator_secrets() {
+        let server = ServerConfig::new("http://localhost:8000/")
+            .account_secret("CustomAccount")
+            .moderator_secret("CustomModerator");
+        let user = AuthenticatedUser::new("TestUser", 472634, Cow::Borrowed("VGhpc0lzQUZha2VQYXNzd29yZA=="));
+
+        assert_eq!(
+            LoginRequest::default().user_name("TestUser").password("PLAIN_TEXT_PASS_HERE").to_string_on(&server),
+            "udid=100000&userName=TestUser&password=PLAIN_TEXT_PASS_HERE&secret=CustomAccount"
+        );
+        assert_eq!(
+            SuggestStarsRequest::new(user, 96457938).stars(SuggestedStars::Ten).to_string_on(&server),
+            "gameVersion=21&binaryVersion=33&secret=CustomModerator&userName=TestUser&accountID=472634&gjp=VGhpc0lzQUZha2VQYXNzd29yZA==&\
+             levelID=96457938&stars=10&feature=0&gdw=0"
+        );
+        assert_eq!(
+            LevelRequest::new(1).to_string_on(&server),
+            "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&levelID=1&inc=1&extra=0"
+        );
+    }
+}
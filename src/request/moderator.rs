@@ -1,5 +1,6 @@
-use serde::Serialize;
-use crate::request::{BaseRequest, MODERATOR_GD_21, REQUEST_BASE_URL};
+use serde::{Serialize, Serializer};
+use crate::request::{BaseRequest, MODERATOR_GD_21, Endpoint, ServerConfig};
+use crate::response::{self, ResponseError};
 use crate::request::account::AuthenticatedUser;
 
 pub const SUGGEST_STARS_ENDPOINT: &str = "suggestGJStars20.php";
@@ -166,7 +167,7 @@ impl<'a> SuggestStarsRequest<'a> {
         Self::with_base(MODERATOR_GD_21, authenticated_user, level_id)
     }
 
-    const fn with_base(base: BaseRequest<'a>,  authenticated_user: AuthenticatedUser<'a>, level_id: u64) -> Self {
+    pub const fn with_base(base: BaseRequest<'a>,  authenticated_user: AuthenticatedUser<'a>, level_id: u64) -> Self {
         SuggestStarsRequest{
             base,
             authenticated_user,
@@ -176,13 +177,23 @@ impl<'a> SuggestStarsRequest<'a> {
             gdw: 0
         }
     }
+}
+
+impl<'a> Endpoint for SuggestStarsRequest<'a> {
+    const ENDPOINT: &'static str = SUGGEST_STARS_ENDPOINT;
 
-    pub fn to_url(&self) -> String {
-        format!("{}{}", REQUEST_BASE_URL, SUGGEST_STARS_ENDPOINT)
+    type Response<'r> = ();
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        SuggestStarsRequest {
+            base: server.moderator_base_request(),
+            ..self.clone()
+        }
+        .serialize(serializer)
     }
 
-    pub fn to_string(&self) -> String {
-        super::to_string(&self)
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_suggest_gj_stars_response(response)
     }
 }
 
@@ -190,13 +201,13 @@ impl<'a> SuggestStarsRequest<'a> {
 mod tests {
     use std::borrow::Cow;
     use crate::request::account::AuthenticatedUser;
-    use crate::request::AuthenticatedUser;
     use crate::request::moderator::{SuggestedFeatureScore, SuggestedStars, SuggestStarsRequest};
+    use crate::request::Endpoint;
 
     const TEST_AUTHENTICATED_USER: AuthenticatedUser = AuthenticatedUser::new(
-        "Ryder",
-        57903,
-        Cow::Borrowed("UmVkaXNuZU1FQXJFREdlTnRJQw==")
+        "TestUser",
+        472634,
+        Cow::Borrowed("VGhpc0lzQUZha2VQYXNzd29yZA==")
     );
 
     #[test]
@@ -207,7 +218,7 @@ mod tests {
 
         assert_eq!(
             request.to_string(),
-            "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&userName=TestUser&accountID=472634&gjp=VGhpc0lzQUZha2VQYXNzd29yZA==&levelID=96457938&stars=10&feature=1&gdw=0"
+            "gameVersion=21&binaryVersion=33&secret=Wmfp3879gc3&userName=TestUser&accountID=472634&gjp=VGhpc0lzQUZha2VQYXNzd29yZA==&levelID=96457938&stars=10&feature=1&gdw=0"
         );
     }
 }
\ No newline at end of file
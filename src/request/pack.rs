@@ -0,0 +1,139 @@
+//! Module containing request definitions for retrieving map packs and gauntlets
+
+use crate::{
+    model::pack::{Gauntlet, MapPack},
+    request::{BaseRequest, Endpoint, PaginatedEndpoint, ServerConfig, GD_21},
+    response::{self, Page, ResponseError},
+};
+use serde::{Serialize, Serializer};
+
+pub const MAP_PACKS_ENDPOINT: &str = "getGJMapPacks21.php";
+pub const GAUNTLETS_ENDPOINT: &str = "getGJGauntlets21.php";
+
+/// Struct modelled after a request to `getGJMapPacks21.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to list the available map packs. The levels of
+/// a specific map pack can then be retrieved using a
+/// [`LevelsRequest`](crate::request::level::LevelsRequest) with request type
+/// [`LevelRequestType::MapPack`](crate::request::level::LevelRequestType::MapPack).
+#[derive(Debug, Default, Clone, Copy, Serialize, Hash, PartialEq, Eq)]
+pub struct MapPacksRequest<'a> {
+    /// The base request data
+    pub base: BaseRequest<'a>,
+
+    /// The page of map packs to retrieve. The first page is page `0`
+    ///
+    /// ## GD Internals:
+    /// This field is called `page` in the Boomlings API
+    pub page: u32,
+}
+
+impl<'a> MapPacksRequest<'a> {
+    const_setter! { base[with_base]: BaseRequest<'a> }
+
+    const_setter! { page: u32 }
+
+    pub const fn new() -> MapPacksRequest<'a> {
+        MapPacksRequest { base: GD_21, page: 0 }
+    }
+}
+
+impl<'a> Endpoint for MapPacksRequest<'a> {
+    const ENDPOINT: &'static str = MAP_PACKS_ENDPOINT;
+
+    type Response<'r> = Page<MapPack<'r>>;
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        MapPacksRequest {
+            base: server.base_request(),
+            ..*self
+        }
+        .serialize(serializer)
+    }
+
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_get_gj_map_packs_response(response)
+    }
+}
+
+impl<'a> PaginatedEndpoint for MapPacksRequest<'a> {
+    fn page_number(&self) -> u32 {
+        self.page
+    }
+
+    fn set_page(&mut self, page: u32) {
+        self.page = page;
+    }
+}
+
+/// Struct modelled after a request to `getGJGauntlets21.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to list all gauntlets. Unlike map packs,
+/// gauntlets are not paginated.
+#[derive(Debug, Clone, Copy, Serialize, Hash, PartialEq, Eq)]
+pub struct GauntletsRequest<'a> {
+    /// The base request data
+    pub base: BaseRequest<'a>,
+
+    /// Whether to retrieve the gauntlets in the format used since Geometry Dash 2.1
+    ///
+    /// ## GD Internals:
+    /// This field is called `special` in the Boomlings API. The game always sets it, and it is
+    /// unclear what the servers do if it is missing.
+    pub special: bool,
+}
+
+impl<'a> GauntletsRequest<'a> {
+    const_setter! { base[with_base]: BaseRequest<'a> }
+
+    const_setter! { special: bool }
+
+    pub const fn new() -> GauntletsRequest<'a> {
+        GauntletsRequest { base: GD_21, special: true }
+    }
+}
+
+impl Default for GauntletsRequest<'_> {
+    fn default() -> Self {
+        GauntletsRequest::new()
+    }
+}
+
+impl<'a> Endpoint for GauntletsRequest<'a> {
+    const ENDPOINT: &'static str = GAUNTLETS_ENDPOINT;
+
+    type Response<'r> = Vec<Gauntlet>;
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        GauntletsRequest {
+            base: server.base_request(),
+            ..*self
+        }
+        .serialize(serializer)
+    }
+
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_get_gj_gauntlets_response(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::request::pack::{GauntletsRequest, MapPacksRequest};
+    use crate::request::Endpoint;
+
+    #[test]
+    fn serialize_map_packs_request() {
+        let request = MapPacksRequest::new().page(2);
+
+        assert_eq!(request.to_string(), "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&page=2");
+    }
+
+    #[test]
+    fn serialize_gauntlets_request() {
+        assert_eq!(
+            GauntletsRequest::default().to_string(),
+            "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&special=1"
+        );
+    }
+}
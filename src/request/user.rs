@@ -1,13 +1,16 @@
 //! Module containing request definitions for retrieving users
 
 use crate::{
-    request::{BaseRequest, GD_21, REQUEST_BASE_URL},
+    model::user::{profile::Profile, ranked::RankedUser, searched::SearchedUser},
+    request::{BaseRequest, GD_21, Endpoint, ServerConfig},
+    response::{self, ResponseError},
 };
-use serde::Serialize;
+use serde::{Serialize, Serializer};
 use crate::request::account::AuthenticatedUser;
 
 pub const GET_USER_ENDPOINT: &str = "getGJUserInfo20.php";
 pub const SEARCH_USER_ENDPOINT: &str = "getGJUsers20.php";
+pub const LEADERBOARD_ENDPOINT: &str = "getGJScores20.php";
 
 /// Struct modelled after a request to `getGJUserInfo20.php`.
 ///
@@ -19,6 +22,10 @@ pub struct UserRequest<'a> {
     pub base: BaseRequest<'a>,
 
     /// The authenticated user data
+    ///
+    /// ## GD Internals:
+    /// If present, the credentials are sent inline as `userName`, `accountID` and `gjp`
+    #[serde(flatten)]
     authenticated_user: Option<AuthenticatedUser<'a>>,
 
     /// The **account ID** (_not_ user ID) of the users whose data to retrieve.
@@ -32,8 +39,12 @@ pub struct UserRequest<'a> {
 impl<'a> UserRequest<'a> {
 
     pub const fn new(user_id: u64) -> UserRequest<'a> {
+        Self::with_base(GD_21, user_id)
+    }
+
+    pub const fn with_base(base: BaseRequest<'a>, user_id: u64) -> UserRequest<'a> {
         UserRequest {
-            base: GD_21,
+            base,
             authenticated_user: None,
             user: user_id,
         }
@@ -46,15 +57,24 @@ impl<'a> UserRequest<'a> {
             user: user_id,
         }
     }
+}
 
-    pub fn to_url(&self) -> String {
-        format!("{}{}", REQUEST_BASE_URL, GET_USER_ENDPOINT)
-    }
+impl<'a> Endpoint for UserRequest<'a> {
+    const ENDPOINT: &'static str = GET_USER_ENDPOINT;
 
-    pub fn to_string(&self) -> String {
-        super::to_string(&self)
+    type Response<'r> = Profile<'r>;
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        UserRequest {
+            base: server.base_request(),
+            ..self.clone()
+        }
+        .serialize(serializer)
     }
 
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_get_gj_user_info_response(response)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Copy, PartialEq, Eq)]
@@ -90,34 +110,187 @@ pub struct UserSearchRequest<'a> {
 impl<'a> UserSearchRequest<'a> {
 
     pub const fn new(search_string: &'a str) -> Self {
+        Self::with_base(GD_21, search_string)
+    }
+
+    pub const fn with_base(base: BaseRequest<'a>, search_string: &'a str) -> Self {
         UserSearchRequest {
-            base: GD_21,
+            base,
             total: 0,
             page: 0,
             search_string,
         }
     }
+}
+
+impl<'a> Endpoint for UserSearchRequest<'a> {
+    const ENDPOINT: &'static str = SEARCH_USER_ENDPOINT;
+
+    type Response<'r> = SearchedUser<'r>;
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        UserSearchRequest {
+            base: server.base_request(),
+            ..*self
+        }
+        .serialize(serializer)
+    }
+
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_get_gj_users_response(response)
+    }
+}
+
+/// The different leaderboards that can be retrieved via a [`LeaderboardRequest`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LeaderboardType {
+    /// The global leaderboard, ranking players by their stars
+    #[default]
+    Top,
+
+    /// The creator leaderboard, ranking players by their creator points
+    Creators,
+
+    /// The section of the global leaderboard around the authenticated user
+    ///
+    /// Can only be requested via [`LeaderboardRequest::with_authenticated_user`]
+    Relative,
+
+    /// The leaderboard of the authenticated user's friends, including the user themselves
+    ///
+    /// Can only be requested via [`LeaderboardRequest::with_authenticated_user`]
+    Friends,
+}
+
+impl LeaderboardType {
+    /// Whether retrieving this leaderboard requires an [`AuthenticatedUser`]
+    pub const fn requires_authentication(self) -> bool {
+        matches!(self, LeaderboardType::Relative | LeaderboardType::Friends)
+    }
+}
+
+impl Serialize for LeaderboardType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(match self {
+            LeaderboardType::Top => "top",
+            LeaderboardType::Creators => "creators",
+            LeaderboardType::Relative => "relative",
+            LeaderboardType::Friends => "friends",
+        })
+    }
+}
+
+/// Struct modelled after a request to `getGJScores20.php`.
+///
+/// In the Geometry Dash API, this endpoint is used to retrieve the global, creator, relative and
+/// friends leaderboards
+#[derive(Debug, Clone, Serialize, Hash)]
+pub struct LeaderboardRequest<'a> {
+    /// The base request data
+    pub base: BaseRequest<'a>,
+
+    /// The authenticated user data
+    ///
+    /// ## GD Internals:
+    /// If present, the credentials are sent inline as `userName`, `accountID` and `gjp`. The
+    /// [`LeaderboardType::Relative`] and [`LeaderboardType::Friends`] leaderboards are computed
+    /// relative to this user
+    #[serde(flatten)]
+    authenticated_user: Option<AuthenticatedUser<'a>>,
+
+    /// The leaderboard to retrieve
+    ///
+    /// ## GD Internals:
+    /// This field is called `type` in the Boomlings API
+    #[serde(rename = "type")]
+    leaderboard_type: LeaderboardType,
+
+    /// The maximum amount of users to retrieve
+    ///
+    /// ## GD Internals:
+    /// This field is called `count` in the Boomlings API. The game requests `100` users for the
+    /// global and creator leaderboards
+    pub count: u32,
+}
+
+impl<'a> LeaderboardRequest<'a> {
+    const_setter! { base[with_base]: BaseRequest<'a> }
+
+    const_setter! { count: u32 }
+
+    /// Constructs a request retrieving the global leaderboard
+    pub const fn top() -> LeaderboardRequest<'a> {
+        LeaderboardRequest {
+            base: GD_21,
+            authenticated_user: None,
+            leaderboard_type: LeaderboardType::Top,
+            count: 100,
+        }
+    }
+
+    /// Constructs a request retrieving the creator leaderboard
+    pub const fn creators() -> LeaderboardRequest<'a> {
+        LeaderboardRequest {
+            base: GD_21,
+            authenticated_user: None,
+            leaderboard_type: LeaderboardType::Creators,
+            count: 100,
+        }
+    }
 
-    pub fn to_url(&self) -> String {
-        format!("{}{}", REQUEST_BASE_URL, SEARCH_USER_ENDPOINT)
+    /// Constructs a request retrieving the given leaderboard as the given user. This is the only
+    /// way to request the leaderboards that [require
+    /// authentication](LeaderboardType::requires_authentication)
+    pub const fn with_authenticated_user(authenticated_user: AuthenticatedUser<'a>, leaderboard_type: LeaderboardType) -> LeaderboardRequest<'a> {
+        LeaderboardRequest {
+            base: GD_21,
+            authenticated_user: Some(authenticated_user),
+            leaderboard_type,
+            count: 100,
+        }
     }
 
-    pub fn to_string(&self) -> String {
-        super::to_string(&self)
+    /// The leaderboard this request retrieves
+    pub const fn leaderboard_type(&self) -> LeaderboardType {
+        self.leaderboard_type
     }
+}
+
+impl Default for LeaderboardRequest<'_> {
+    fn default() -> Self {
+        LeaderboardRequest::top()
+    }
+}
 
+impl<'a> Endpoint for LeaderboardRequest<'a> {
+    const ENDPOINT: &'static str = LEADERBOARD_ENDPOINT;
+
+    type Response<'r> = Vec<RankedUser<'r>>;
+
+    fn serialize_on<S: Serializer>(&self, server: &ServerConfig, serializer: S) -> Result<S::Ok, S::Error> {
+        LeaderboardRequest {
+            base: server.base_request(),
+            ..self.clone()
+        }
+        .serialize(serializer)
+    }
+
+    fn parse_response(response: &str) -> Result<Self::Response<'_>, ResponseError<'_>> {
+        response::parse_get_gj_scores_response(response)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::borrow::Cow;
     use crate::request::account::AuthenticatedUser;
-    use crate::request::user::{UserRequest, UserSearchRequest};
+    use crate::request::user::{LeaderboardRequest, LeaderboardType, UserRequest, UserSearchRequest};
+    use crate::request::Endpoint;
 
     const TEST_AUTHENTICATED_USER: AuthenticatedUser = AuthenticatedUser::new(
-        "Ryder",
-        57903,
-        Cow::Borrowed("UmVkaXNuZU1FQXJFREdlTnRJQw==")
+        "TestUser",
+        472634,
+        Cow::Borrowed("VGhpc0lzQUZha2VQYXNzd29yZA==")
     );
 
     #[test]
@@ -130,6 +303,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn serialize_unauthenticated_user_request() {
+        let request = UserRequest::new(57903);
+
+        assert_eq!(
+            request.to_string(),
+            "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&targetAccountID=57903"
+        );
+    }
+
     #[test]
     fn serialize_user_search_request() {
         let request = UserSearchRequest::new("Ryder");
@@ -139,4 +322,32 @@ mod tests {
             "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&total=0&page=0&str=Ryder"
         );
     }
+
+    #[test]
+    fn serialize_leaderboard_request() {
+        let request = LeaderboardRequest::creators();
+
+        assert_eq!(
+            request.to_string(),
+            "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&type=creators&count=100"
+        );
+    }
+
+    #[test]
+    fn serialize_default_leaderboard_request() {
+        assert_eq!(
+            LeaderboardRequest::default().to_string(),
+            "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&type=top&count=100"
+        );
+    }
+
+    #[test]
+    fn serialize_authenticated_leaderboard_request() {
+        let request = LeaderboardRequest::with_authenticated_user(TEST_AUTHENTICATED_USER, LeaderboardType::Friends).count(50);
+
+        assert_eq!(
+            request.to_string(),
+            "gameVersion=21&binaryVersion=33&secret=Wmfd2893gb7&userName=TestUser&accountID=472634&gjp=VGhpc0lzQUZha2VQYXNzd29yZA==&type=friends&count=50"
+        );
+    }
 }
@@ -9,17 +9,27 @@ use crate::{
         },
         creator::Creator,
         level::{
-            online_level::{Level, ListedLevel}
+            online_level::{Level, ListedLevel},
+            score::LevelScore,
         },
+        message::Message,
+        pack::{Gauntlet, MapPack},
         song::NewgroundsSong,
-        user::{profile::Profile, searched::SearchedUser},
+        user::{profile::Profile, ranked::RankedUser, searched::SearchedUser},
+    },
+    request::{
+        account::{AuthenticationError, LoginResponse, RegistrationError, SyncResponse},
+        level::DailyLevelInfo,
     },
     DeError, HasRobtopFormat,
 };
 
 use serde::__private::Formatter;
-use std::fmt::Display;
-use crate::model::level::local_level::LevelData;
+use std::{borrow::Cow, fmt::Display, ops::Deref};
+use crate::{model::level::local_level::LevelData, util};
+
+/// Salt appended to the data hashed for the integrity check at the end of `getGJLevels` responses
+pub const LEVELS_HASH_SALT: &str = "xI25fpAapCQg";
 
 // Since NoneError is not stabilized, we cannot do `impl From<NoneError> for ResponseError<'_>`, so
 // this is the next best thing
@@ -43,6 +53,15 @@ pub enum ResponseError<'a> {
     /// The response was not worked in the expected way (too few sections, etc.)
     UnexpectedFormat,
 
+    /// The hash included in the response did not match the data in the response
+    HashMismatch,
+
+    /// The servers rejected a login attempt
+    Authentication(AuthenticationError),
+
+    /// The servers rejected an account registration
+    Registration(RegistrationError),
+
     /// There was an error making the request to http://www.boomlings.com
     RequestError(reqwest::Error)
 }
@@ -53,6 +72,9 @@ impl Display for ResponseError<'_> {
             ResponseError::De(err) => err.fmt(f),
             ResponseError::NotFound => write!(f, "not found"),
             ResponseError::UnexpectedFormat => write!(f, "unexpected format"),
+            ResponseError::HashMismatch => write!(f, "hash mismatch"),
+            ResponseError::Authentication(err) => err.fmt(f),
+            ResponseError::Registration(err) => err.fmt(f),
             ResponseError::RequestError(err) => err.fmt(f)
         }
     }
@@ -66,7 +88,128 @@ impl<'a> From<DeError<'a>> for ResponseError<'a> {
     }
 }
 
-pub fn parse_get_gj_levels_response(response: &str) -> Result<Vec<ListedLevel>, ResponseError> {
+/// A single page of a paginated list response
+///
+/// ## GD Internals:
+/// Paginated responses end with a section of the form `total:offset:page_size`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page<T> {
+    /// The items on this page
+    pub items: Vec<T>,
+
+    /// The total amount of items across all pages
+    pub total: u32,
+
+    /// The index of the first item on this page in the list of all items
+    pub offset: u32,
+
+    /// The amount of items the server puts on a single page
+    pub page_size: u32,
+}
+
+impl<T> Page<T> {
+    /// Whether no further items exist after this page
+    pub fn is_last(&self) -> bool {
+        self.items.is_empty() || self.offset as usize + self.items.len() >= self.total as usize
+    }
+
+    /// Applies a conversion to every item of this page, keeping the pagination information
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+            offset: self.offset,
+            page_size: self.page_size,
+        }
+    }
+
+    /// Applies a fallible conversion to every item of this page, keeping the pagination
+    /// information
+    pub fn try_map<U, E>(self, f: impl FnMut(T) -> Result<U, E>) -> Result<Page<U>, E> {
+        Ok(Page {
+            items: self.items.into_iter().map(f).collect::<Result<_, _>>()?,
+            total: self.total,
+            offset: self.offset,
+            page_size: self.page_size,
+        })
+    }
+
+    fn from_trailer(items: Vec<T>, trailer: &str) -> Result<Self, ResponseError<'static>> {
+        let mut parts = trailer.split(':').map(str::parse);
+
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(Ok(total)), Some(Ok(offset)), Some(Ok(page_size)), None) => Ok(Page {
+                items,
+                total,
+                offset,
+                page_size,
+            }),
+            _ => Err(ResponseError::UnexpectedFormat),
+        }
+    }
+}
+
+impl<T> Default for Page<T> {
+    /// An empty page, which is also the last one
+    fn default() -> Self {
+        Page {
+            items: Vec::new(),
+            total: 0,
+            offset: 0,
+            page_size: 0,
+        }
+    }
+}
+
+impl<T> Deref for Page<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.items
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type IntoIter = std::vec::IntoIter<T>;
+    type Item = T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'p, T> IntoIterator for &'p Page<T> {
+    type IntoIter = std::slice::Iter<'p, T>;
+    type Item = &'p T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+/// Computes the hash the Boomlings servers append to `getGJLevels` responses
+///
+/// ## GD Internals:
+/// For each level, the first and last digit of its ID, its star count and whether its coins are
+/// verified are concatenated. The SHA-1 of the result, salted with [`LEVELS_HASH_SALT`], is the hash.
+pub fn levels_hash<D, S, U>(levels: &[Level<'_, D, S, U>]) -> String {
+    let mut input = String::new();
+
+    for level in levels {
+        let level_id = level.level_id.to_string();
+
+        input.push_str(&level_id[..1]);
+        input.push_str(&level_id[level_id.len() - 1..]);
+        input.push_str(&level.stars.to_string());
+        input.push(if level.has_verified_coins { '1' } else { '0' });
+    }
+
+    input.push_str(LEVELS_HASH_SALT);
+
+    util::sha_encrypt(&Cow::Owned(input))
+}
+
+pub fn parse_get_gj_levels_response(response: &str) -> Result<Page<ListedLevel<'_>>, ResponseError<'_>> {
     if response == "-1" {
         return Err(ResponseError::NotFound)
     }
@@ -85,7 +228,9 @@ pub fn parse_get_gj_levels_response(response: &str) -> Result<Vec<ListedLevel>,
         .map(|fragment| NewgroundsSong::from_robtop_str(fragment))
         .collect::<Result<Vec<NewgroundsSong>, _>>()?;
 
-    levels
+    let trailer = section!(sections);
+
+    let levels = levels
         .split('|')
         .map(|fragment| {
             let level: Level<'_, Option<LevelData<'_>>> = Level::from_robtop_str(fragment)?;
@@ -125,7 +270,91 @@ pub fn parse_get_gj_levels_response(response: &str) -> Result<Vec<ListedLevel>,
                 in_gauntlet: level.in_gauntlet
             })
         })
-        .collect::<Result<_, _>>()
+        .collect::<Result<Vec<_>, ResponseError>>()?;
+
+    match sections.next() {
+        Some(hash) if !hash.is_empty() && hash != levels_hash(&levels) => Err(ResponseError::HashMismatch),
+        _ => Page::from_trailer(levels, trailer),
+    }
+}
+
+/// Computes the hash RobTop appends to `getGJMapPacks21` responses
+///
+/// ## GD Internals:
+/// For each map pack, the first and last digit of its ID, its star count and its coin count are
+/// concatenated. The SHA-1 of the result, salted with [`LEVELS_HASH_SALT`], is the hash.
+pub fn map_packs_hash(packs: &[MapPack<'_>]) -> String {
+    let mut input = String::new();
+
+    for pack in packs {
+        let pack_id = pack.pack_id.to_string();
+
+        input.push_str(&pack_id[..1]);
+        input.push_str(&pack_id[pack_id.len() - 1..]);
+        input.push_str(&pack.stars.to_string());
+        input.push_str(&pack.coins.to_string());
+    }
+
+    input.push_str(LEVELS_HASH_SALT);
+
+    util::sha_encrypt(&Cow::Owned(input))
+}
+
+/// Computes the hash RobTop appends to `getGJGauntlets21` responses
+///
+/// ## GD Internals:
+/// For each gauntlet, its ID and the comma separated list of its levels are concatenated. The
+/// SHA-1 of the result, salted with [`LEVELS_HASH_SALT`], is the hash.
+pub fn gauntlets_hash(gauntlets: &[Gauntlet]) -> String {
+    let mut input = String::new();
+
+    for gauntlet in gauntlets {
+        input.push_str(&gauntlet.gauntlet_id.to_string());
+        input.push_str(&gauntlet.levels.iter().map(ToString::to_string).collect::<Vec<_>>().join(","));
+    }
+
+    input.push_str(LEVELS_HASH_SALT);
+
+    util::sha_encrypt(&Cow::Owned(input))
+}
+
+pub fn parse_get_gj_map_packs_response(response: &str) -> Result<Page<MapPack<'_>>, ResponseError<'_>> {
+    if response == "-1" {
+        return Err(ResponseError::NotFound)
+    }
+
+    let mut sections = response.split('#');
+
+    let packs = section!(sections)
+        .split('|')
+        .filter(|s| !s.is_empty())
+        .map(MapPack::from_robtop_str)
+        .collect::<Result<Vec<_>, _>>()?;
+    let trailer = section!(sections);
+
+    match sections.next() {
+        Some(hash) if !hash.is_empty() && hash != map_packs_hash(&packs) => Err(ResponseError::HashMismatch),
+        _ => Page::from_trailer(packs, trailer),
+    }
+}
+
+pub fn parse_get_gj_gauntlets_response(response: &str) -> Result<Vec<Gauntlet>, ResponseError<'_>> {
+    if response == "-1" {
+        return Err(ResponseError::NotFound)
+    }
+
+    let mut sections = response.split('#');
+
+    let gauntlets = section!(sections)
+        .split('|')
+        .filter(|s| !s.is_empty())
+        .map(Gauntlet::from_robtop_str)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match sections.next() {
+        Some(hash) if !hash.is_empty() && hash != gauntlets_hash(&gauntlets) => Err(ResponseError::HashMismatch),
+        _ => Ok(gauntlets),
+    }
 }
 
 pub fn parse_download_gj_level_response(response: &str) -> Result<Level, ResponseError> {
@@ -138,6 +367,32 @@ pub fn parse_download_gj_level_response(response: &str) -> Result<Level, Respons
     Ok(Level::from_robtop_str(section!(sections))?)
 }
 
+pub fn parse_get_gj_daily_level_response(response: &str) -> Result<DailyLevelInfo, ResponseError<'_>> {
+    if response == "-1" {
+        return Err(ResponseError::NotFound)
+    }
+
+    // The response is of the form `index|seconds_left`. A `:` is accepted as separator as well
+    let mut sections = response.split(['|', ':']);
+
+    match (section!(sections).parse(), section!(sections).parse()) {
+        (Ok(index), Ok(seconds_left)) => Ok(DailyLevelInfo { index, seconds_left }),
+        _ => Err(ResponseError::UnexpectedFormat),
+    }
+}
+
+pub fn parse_get_gj_level_scores_response(response: &str) -> Result<Vec<LevelScore<'_>>, ResponseError<'_>> {
+    if response == "-1" {
+        return Err(ResponseError::NotFound)
+    }
+
+    Ok(response
+        .split('|')
+        .filter(|s| !s.is_empty())
+        .map(LevelScore::from_robtop_str)
+        .collect::<Result<_, _>>()?)
+}
+
 pub fn parse_get_gj_user_info_response(response: &str) -> Result<Profile, ResponseError> {
     if response == "-1" {
         return Err(ResponseError::NotFound)
@@ -159,7 +414,19 @@ pub fn parse_get_gj_users_response(response: &str) -> Result<SearchedUser, Respo
     Ok(SearchedUser::from_robtop_str(section!(sections))?)
 }
 
-pub fn  parse_get_gj_comments_response(response: &str) -> Result<Vec<LevelComment>, ResponseError> {
+pub fn parse_get_gj_scores_response(response: &str) -> Result<Vec<RankedUser<'_>>, ResponseError<'_>> {
+    if response == "-1" {
+        return Err(ResponseError::NotFound)
+    }
+
+    Ok(response
+        .split('|')
+        .filter(|s| !s.is_empty())
+        .map(RankedUser::from_robtop_str)
+        .collect::<Result<_, _>>()?)
+}
+
+pub fn parse_get_gj_comments_response(response: &str) -> Result<Page<LevelComment<'_>>, ResponseError<'_>> {
     if response == "-1" {
         return Err(ResponseError::NotFound)
     }
@@ -169,7 +436,7 @@ pub fn  parse_get_gj_comments_response(response: &str) -> Result<Vec<LevelCommen
     // The format here is very weird. We have a '|' separated list of (comment, user) pairs, and said
     // pair is separated by a ':'
 
-    section!(sections)
+    let comments = section!(sections)
         .split('|')
         .map(|fragment| {
             let mut parts = fragment.split(':');
@@ -188,18 +455,153 @@ pub fn  parse_get_gj_comments_response(response: &str) -> Result<Vec<LevelCommen
                 Err(ResponseError::UnexpectedFormat)
             }
         })
-        .collect()
+        .collect::<Result<_, _>>()?;
+
+    Page::from_trailer(comments, section!(sections))
 }
 
-pub fn parse_get_gj_acccount_comments_response(response: &str) -> Result<Vec<ProfileComment>, ResponseError> {
+pub fn parse_get_gj_acccount_comments_response(response: &str) -> Result<Page<ProfileComment<'_>>, ResponseError<'_>> {
     if response == "-1" {
         return Err(ResponseError::NotFound)
     }
 
     let mut sections = response.split('#');
 
-    section!(sections)
+    let comments = section!(sections)
+        .split('|')
+        .map(ProfileComment::from_robtop_str)
+        .collect::<Result<_, _>>()?;
+
+    Page::from_trailer(comments, section!(sections))
+}
+
+pub fn parse_upload_gj_comment_response(response: &str) -> Result<u64, ResponseError<'_>> {
+    if response == "-1" {
+        return Err(ResponseError::NotFound)
+    }
+
+    // On success, the servers respond with the ID of the newly posted comment
+    response.parse().map_err(|_| ResponseError::UnexpectedFormat)
+}
+
+pub fn parse_delete_gj_comment_response(response: &str) -> Result<(), ResponseError<'_>> {
+    parse_success_response(response)
+}
+
+pub fn parse_suggest_gj_stars_response(response: &str) -> Result<(), ResponseError<'_>> {
+    parse_success_response(response)
+}
+
+pub fn parse_get_gj_messages_response(response: &str) -> Result<Page<Message<'_>>, ResponseError<'_>> {
+    if response == "-1" {
+        return Err(ResponseError::NotFound)
+    }
+
+    // The servers respond with `-2` if the requested folder is empty
+    if response == "-2" {
+        return Ok(Page::default())
+    }
+
+    let mut sections = response.split('#');
+
+    let messages = section!(sections)
         .split('|')
-        .map(|fragment| Ok(ProfileComment::from_robtop_str(fragment)?))
-        .collect()
-}
\ No newline at end of file
+        .map(Message::from_robtop_str)
+        .collect::<Result<_, _>>()?;
+
+    Page::from_trailer(messages, section!(sections))
+}
+
+pub fn parse_download_gj_message_response(response: &str) -> Result<Message<'_>, ResponseError<'_>> {
+    if response == "-1" {
+        return Err(ResponseError::NotFound)
+    }
+
+    Ok(Message::from_robtop_str(response)?)
+}
+
+pub fn parse_upload_gj_message_response(response: &str) -> Result<(), ResponseError<'_>> {
+    parse_success_response(response)
+}
+
+pub fn parse_delete_gj_messages_response(response: &str) -> Result<(), ResponseError<'_>> {
+    parse_success_response(response)
+}
+
+/// Parses the response of endpoints which simply respond with `"1"` on success
+fn parse_success_response(response: &str) -> Result<(), ResponseError<'_>> {
+    match response {
+        "1" => Ok(()),
+        "-1" => Err(ResponseError::NotFound),
+        _ => Err(ResponseError::UnexpectedFormat),
+    }
+}
+
+pub fn parse_login_gj_account_response(response: &str) -> Result<LoginResponse, ResponseError<'_>> {
+    // On failure, the servers respond with a negative error code
+    if let Ok(code) = response.parse::<i32>() {
+        if code < 0 {
+            return Err(ResponseError::Authentication(AuthenticationError::from_code(code)))
+        }
+    }
+
+    // On success, the servers respond with `accountID,userID`
+    let mut sections = response.split(',');
+
+    match (section!(sections).parse(), section!(sections).parse()) {
+        (Ok(account_id), Ok(user_id)) => Ok(LoginResponse { account_id, user_id }),
+        _ => Err(ResponseError::UnexpectedFormat),
+    }
+}
+
+pub fn parse_register_gj_account_response(response: &str) -> Result<(), ResponseError<'_>> {
+    match response.parse::<i32>() {
+        Ok(1) => Ok(()),
+        Ok(code) if code < 0 => Err(ResponseError::Registration(RegistrationError::from_code(code))),
+        _ => Err(ResponseError::UnexpectedFormat),
+    }
+}
+
+pub fn parse_update_gj_acc_settings_response(response: &str) -> Result<(), ResponseError<'_>> {
+    parse_success_response(response)
+}
+
+pub fn parse_get_account_url_response(response: &str) -> Result<&str, ResponseError<'_>> {
+    match response {
+        "-1" => Err(ResponseError::NotFound),
+        url if url.starts_with("http") => Ok(url),
+        _ => Err(ResponseError::UnexpectedFormat),
+    }
+}
+
+pub fn parse_backup_gj_account_response(response: &str) -> Result<(), ResponseError<'_>> {
+    match response {
+        "-2" => Err(ResponseError::Authentication(AuthenticationError::InvalidCredentials)),
+        _ => parse_success_response(response),
+    }
+}
+
+pub fn parse_sync_gj_account_response(response: &str) -> Result<SyncResponse<'_>, ResponseError<'_>> {
+    match response {
+        "-1" => return Err(ResponseError::NotFound),
+        "-2" => return Err(ResponseError::Authentication(AuthenticationError::InvalidCredentials)),
+        _ => (),
+    }
+
+    // The response is of the form `gameManager;localLevels;gameVersion;binaryVersion;...`
+    let mut sections = response.split(';');
+
+    let game_manager = section!(sections);
+    let local_levels = section!(sections);
+
+    if game_manager.is_empty() || local_levels.is_empty() {
+        return Err(ResponseError::UnexpectedFormat)
+    }
+
+    Ok(SyncResponse {
+        game_manager: Cow::Borrowed(game_manager),
+        local_levels: Cow::Borrowed(local_levels),
+        game_version: sections.next().and_then(|version| version.parse().ok()),
+        binary_version: sections.next().and_then(|version| version.parse().ok()),
+    })
+}
@@ -0,0 +1,441 @@
+//! Module containing a typed model of the `CCGameManager.dat` save file
+
+use crate::{
+    model::{
+        level::local_level::LocalLevel,
+        user::{Color, IconType},
+    },
+    save::{Dictionary, SaveError, Value},
+};
+use std::borrow::Cow;
+
+/// The contents of the `CCGameManager.dat` save file that dash-rs knows how to interpret
+#[derive(Debug, PartialEq)]
+pub struct GameManager<'a> {
+    /// The name of the player
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `playerName`
+    pub player_name: Cow<'a, str>,
+
+    /// The player's user ID
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `playerUserID`
+    pub user_id: Option<u64>,
+
+    /// The account ID of the account the player is logged into
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `GJA_003`
+    pub account_id: Option<u64>,
+
+    /// The player's statistics
+    pub stats: Stats,
+
+    /// The icons and colors the player has selected
+    pub icons: SelectedIcons,
+
+    /// The levels the player has completed
+    pub completed_levels: Vec<CompletedLevel>,
+
+    /// The icons the player has unlocked, as pairs of icon type and icon index
+    pub unlocked_icons: Vec<(IconType, u16)>,
+
+    /// The primary colors the player has unlocked
+    pub unlocked_primary_colors: Vec<Color>,
+
+    /// The secondary colors the player has unlocked
+    pub unlocked_secondary_colors: Vec<Color>,
+
+    /// The online levels the player has downloaded
+    ///
+    /// ## GD Internals:
+    /// These are stored under key `GLM_03`, as a dictionary mapping level IDs to level dictionaries
+    /// in the same format as in `CCLocalLevels.dat`
+    pub online_levels: Vec<LocalLevel<'a>>,
+}
+
+/// The player statistics as displayed in the game's stats menu
+///
+/// ## GD Internals:
+/// These values are stored in the `GS_value` dictionary, using stringified numbers as keys and
+/// values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Stats {
+    /// Stored under key `1`
+    pub jumps: u64,
+
+    /// Stored under key `2`
+    pub attempts: u64,
+
+    /// Stored under key `3`
+    pub completed_official_levels: u64,
+
+    /// Stored under key `4`
+    pub completed_online_levels: u64,
+
+    /// Stored under key `5`
+    pub demons: u64,
+
+    /// Stored under key `6`
+    pub stars: u64,
+
+    /// Stored under key `7`
+    pub completed_map_packs: u64,
+
+    /// Stored under key `8`
+    pub secret_coins: u64,
+
+    /// Stored under key `9`
+    pub destroyed_players: u64,
+
+    /// Stored under key `10`
+    pub liked_levels: u64,
+
+    /// Stored under key `11`
+    pub rated_levels: u64,
+
+    /// Stored under key `12`
+    pub user_coins: u64,
+
+    /// Stored under key `13`
+    pub diamonds: u64,
+
+    /// The amount of mana orbs the player currently has. Stored under key `14`
+    pub orbs: u64,
+
+    /// Stored under key `15`
+    pub completed_daily_levels: u64,
+
+    /// The amount of mana orbs the player has ever collected. Stored under key `22`
+    pub total_orbs: u64,
+}
+
+/// The icon set the player has selected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectedIcons {
+    /// The gamemode whose icon is shown next to the player's name, for instance on leaderboards
+    ///
+    /// ## GD Internals:
+    /// This value is stored under key `playerIconType`
+    pub icon_type: IconType,
+
+    /// Stored under key `playerFrame`
+    pub cube: u16,
+
+    /// Stored under key `playerShip`
+    pub ship: u8,
+
+    /// Stored under key `playerBall`
+    pub ball: u8,
+
+    /// Stored under key `playerBird`
+    pub ufo: u8,
+
+    /// Stored under key `playerDart`
+    pub wave: u8,
+
+    /// Stored under key `playerRobot`
+    pub robot: u8,
+
+    /// Stored under key `playerSpider`
+    pub spider: u8,
+
+    /// Stored under key `playerStreak`
+    pub streak: u8,
+
+    /// Stored under key `playerDeathEffect`
+    pub death_effect: u8,
+
+    /// Stored under key `playerColor`
+    pub primary_color: Color,
+
+    /// Stored under key `playerColor2`
+    pub secondary_color: Color,
+
+    /// Stored under key `playerGlow`
+    pub has_glow: bool,
+}
+
+/// The different kinds of levels tracked in the player's completed levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LevelKind {
+    /// One of RobTop's official levels
+    ///
+    /// ## GD Internals:
+    /// Represented by the key prefix `c_`
+    Official,
+
+    /// A level downloaded from the servers
+    ///
+    /// ## GD Internals:
+    /// Represented by the key prefix `n_`
+    Online,
+
+    /// A daily or weekly level
+    ///
+    /// ## GD Internals:
+    /// Represented by the key prefix `d_`. Unlike for the other kinds, the number after the prefix
+    /// is not a level ID, but the number of the daily or weekly level (see
+    /// [`DailyLevelInfo::index`](crate::request::level::DailyLevelInfo::index))
+    Daily,
+
+    /// A gauntlet level
+    ///
+    /// ## GD Internals:
+    /// Represented by the key prefix `g_`
+    Gauntlet,
+}
+
+/// A level the player has completed
+///
+/// ## GD Internals:
+/// Completed levels are stored in the `GS_completed` dictionary, with keys of the form
+/// `<prefix>_<id>`, where the prefix depends on the [`LevelKind`]. Additional entries,
+/// such as the ones tracking whether stars were awarded for a completion, are ignored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompletedLevel {
+    /// What kind of level was completed, which determines what the
+    /// [`level_id`](CompletedLevel::level_id) refers to
+    pub kind: LevelKind,
+
+    /// The ID of the completed level. For [`LevelKind::Daily`] this is instead the number of the
+    /// daily or weekly level, which needs to be looked up to find the level it refers to
+    ///
+    /// ## GD Internals:
+    /// This is the part of the key after the [`LevelKind`] prefix
+    pub level_id: u64,
+}
+
+fn dict<'a, 'b>(dict: &'a Dictionary<'b>, key: &'static str) -> Result<Option<&'a Dictionary<'b>>, SaveError> {
+    dict.get(key).map(|value| value.as_dict().ok_or(SaveError::InvalidValue(key))).transpose()
+}
+
+impl<'a> GameManager<'a> {
+    /// Reads the game manager from the root dictionary of the `CCGameManager.dat` save file
+    pub fn from_dictionary(root: &'a Dictionary<'_>) -> Result<Self, SaveError> {
+        let empty = Dictionary::new();
+
+        let (unlocked_icons, unlocked_primary_colors, unlocked_secondary_colors) = unlocks(dict(root, "valueKeeper")?.unwrap_or(&empty));
+
+        Ok(GameManager {
+            player_name: match root.get("playerName") {
+                Some(name) => Cow::Borrowed(name.as_str().ok_or(SaveError::InvalidValue("playerName"))?),
+                None => Cow::Borrowed(""),
+            },
+            user_id: root.get_int("playerUserID")?,
+            account_id: root.get_int("GJA_003")?,
+            stats: Stats::from_dictionary(dict(root, "GS_value")?.unwrap_or(&empty))?,
+            icons: SelectedIcons::from_dictionary(root)?,
+            completed_levels: dict(root, "GS_completed")?.map(completed_levels).unwrap_or_default(),
+            unlocked_icons,
+            unlocked_primary_colors,
+            unlocked_secondary_colors,
+            online_levels: match dict(root, "GLM_03")? {
+                Some(levels) =>
+                    levels
+                        .iter()
+                        .map(|(_, level)| level.as_dict().ok_or(SaveError::InvalidValue("GLM_03")).and_then(LocalLevel::from_dictionary))
+                        .collect::<Result<_, _>>()?,
+                None => Vec::new(),
+            },
+        })
+    }
+}
+
+impl Stats {
+    /// Reads the player statistics from the `GS_value` dictionary
+    pub fn from_dictionary(dict: &Dictionary<'_>) -> Result<Self, SaveError> {
+        let stat = |key| dict.get_int(key).map(Option::unwrap_or_default);
+
+        Ok(Stats {
+            jumps: stat("1")?,
+            attempts: stat("2")?,
+            completed_official_levels: stat("3")?,
+            completed_online_levels: stat("4")?,
+            demons: stat("5")?,
+            stars: stat("6")?,
+            completed_map_packs: stat("7")?,
+            secret_coins: stat("8")?,
+            destroyed_players: stat("9")?,
+            liked_levels: stat("10")?,
+            rated_levels: stat("11")?,
+            user_coins: stat("12")?,
+            diamonds: stat("13")?,
+            orbs: stat("14")?,
+            completed_daily_levels: stat("15")?,
+            total_orbs: stat("22")?,
+        })
+    }
+}
+
+impl SelectedIcons {
+    /// Reads the selected icons from the root dictionary of the `CCGameManager.dat` save file
+    pub fn from_dictionary(root: &Dictionary<'_>) -> Result<Self, SaveError> {
+        Ok(SelectedIcons {
+            icon_type: IconType::from(root.get_int::<u8>("playerIconType")?.unwrap_or(0)),
+            cube: root.get_int("playerFrame")?.unwrap_or(1),
+            ship: root.get_int("playerShip")?.unwrap_or(1),
+            ball: root.get_int("playerBall")?.unwrap_or(1),
+            ufo: root.get_int("playerBird")?.unwrap_or(1),
+            wave: root.get_int("playerDart")?.unwrap_or(1),
+            robot: root.get_int("playerRobot")?.unwrap_or(1),
+            spider: root.get_int("playerSpider")?.unwrap_or(1),
+            streak: root.get_int("playerStreak")?.unwrap_or(1),
+            death_effect: root.get_int("playerDeathEffect")?.unwrap_or(1),
+            primary_color: Color::from(root.get_int::<u8>("playerColor")?.unwrap_or(0)),
+            secondary_color: Color::from(root.get_int::<u8>("playerColor2")?.unwrap_or(3)),
+            has_glow: root.get("playerGlow").and_then(Value::as_bool).unwrap_or(false),
+        })
+    }
+}
+
+fn completed_levels(dict: &Dictionary<'_>) -> Vec<CompletedLevel> {
+    dict.iter()
+        .filter_map(|(key, _)| {
+            let (prefix, level_id) = key.split_at(key.find('_')?);
+            let kind = match prefix {
+                "c" => LevelKind::Official,
+                "n" => LevelKind::Online,
+                "d" => LevelKind::Daily,
+                "g" => LevelKind::Gauntlet,
+                _ => return None,
+            };
+
+            Some(CompletedLevel {
+                kind,
+                level_id: level_id[1..].parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+/// Reads the unlocked icons and colors from the `valueKeeper` dictionary, whose keys are of the form
+/// `<unlock type>_<index>`
+fn unlocks(dict: &Dictionary<'_>) -> (Vec<(IconType, u16)>, Vec<Color>, Vec<Color>) {
+    let mut icons = Vec::new();
+    let mut primary_colors = Vec::new();
+    let mut secondary_colors = Vec::new();
+
+    for (key, value) in dict.iter() {
+        if value.as_i64() != Some(1) {
+            continue
+        }
+
+        let (prefix, index) = match key.find('_') {
+            Some(idx) => (&key[..idx], &key[idx + 1..]),
+            None => continue,
+        };
+
+        let index: u16 = match index.parse() {
+            Ok(index) => index,
+            Err(_) => continue,
+        };
+
+        let icon_type = match prefix {
+            "i" => IconType::Cube,
+            "ship" => IconType::Ship,
+            "ball" => IconType::Ball,
+            "bird" => IconType::Ufo,
+            "dart" => IconType::Wave,
+            "robot" => IconType::Robot,
+            "spider" => IconType::Spider,
+            "swing" => IconType::Swing,
+            "c0" => {
+                primary_colors.push(Color::from(index as u8));
+                continue
+            },
+            "c1" => {
+                secondary_colors.push(Color::from(index as u8));
+                continue
+            },
+            _ => continue,
+        };
+
+        icons.push((icon_type, index));
+    }
+
+    (icons, primary_colors, secondary_colors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::user::{Color, IconType},
+        save::{
+            game_manager::{CompletedLevel, GameManager, LevelKind},
+            Plist,
+        },
+    };
+
+    const GAME_MANAGER_XML: &str = concat!(
+        r#"<?xml version="1.0"?><plist version="1.0" gjver="2.0"><dict>"#,
+        r#"<k>valueKeeper</k><d><k>gv_0001</k><s>1</s><k>i_12</k><s>1</s><k>ship_3</k><s>1</s><k>dart_2</k><s>1</s><k>swing_4</k><s>1</s><k>c0_5</k><s>1</s><k>c1_12</k><s>1</s></d>"#,
+        r#"<k>GS_value</k><d><k>1</k><s>123456</s><k>2</k><s>7890</s><k>6</k><s>1520</s><k>13</k><s>4000</s><k>14</k><s>300</s><k>22</k><s>98000</s></d>"#,
+        r#"<k>GS_completed</k><d><k>c_1</k><s>1</s><k>n_11774780</k><s>1</s><k>star_n_11774780</k><s>1</s><k>g_4001</k><s>1</s></d>"#,
+        r#"<k>GLM_03</k><d><k>11774780</k><d><k>k1</k><i>11774780</i><k>k2</k><s>Dark Realm</s><k>k6</k><i>2795</i></d></d>"#,
+        r#"<k>playerName</k><s>Ryder</s><k>playerUserID</k><i>8451</i><k>GJA_003</k><i>57903</i>"#,
+        r#"<k>playerFrame</k><i>12</i><k>playerShip</k><i>3</i><k>playerIconType</k><i>4</i><k>playerColor</k><i>5</i><k>playerColor2</k><i>12</i><k>playerGlow</k><t />"#,
+        r#"</dict></plist>"#
+    );
+
+    #[test]
+    fn parse_game_manager() {
+        let plist = Plist::parse(GAME_MANAGER_XML).unwrap();
+        let manager = GameManager::from_dictionary(&plist.root).unwrap();
+
+        assert_eq!(manager.player_name, "Ryder");
+        assert_eq!(manager.user_id, Some(8451));
+        assert_eq!(manager.account_id, Some(57903));
+
+        assert_eq!(manager.stats.jumps, 123456);
+        assert_eq!(manager.stats.attempts, 7890);
+        assert_eq!(manager.stats.stars, 1520);
+        assert_eq!(manager.stats.diamonds, 4000);
+        assert_eq!(manager.stats.orbs, 300);
+        assert_eq!(manager.stats.total_orbs, 98000);
+        assert_eq!(manager.stats.demons, 0);
+
+        assert_eq!(manager.icons.icon_type, IconType::Wave);
+        assert_eq!(manager.icons.cube, 12);
+        assert_eq!(manager.icons.ship, 3);
+        assert_eq!(manager.icons.primary_color, Color::from(5));
+        assert_eq!(manager.icons.secondary_color, Color::from(12));
+        assert!(manager.icons.has_glow);
+
+        assert_eq!(manager.completed_levels, vec![
+            CompletedLevel {
+                kind: LevelKind::Official,
+                level_id: 1
+            },
+            CompletedLevel {
+                kind: LevelKind::Online,
+                level_id: 11774780
+            },
+            CompletedLevel {
+                kind: LevelKind::Gauntlet,
+                level_id: 4001
+            },
+        ]);
+
+        assert_eq!(manager.unlocked_icons, vec![(IconType::Cube, 12), (IconType::Ship, 3), (IconType::Wave, 2), (IconType::Swing, 4)]);
+        assert_eq!(manager.unlocked_primary_colors, vec![Color::from(5)]);
+        assert_eq!(manager.unlocked_secondary_colors, vec![Color::from(12)]);
+
+        assert_eq!(manager.online_levels.len(), 1);
+        assert_eq!(manager.online_levels[0].level_id, Some(11774780));
+        assert_eq!(manager.online_levels[0].name, "Dark Realm");
+    }
+
+    #[test]
+    fn parse_empty_game_manager() {
+        let plist = Plist::parse("<plist><dict></dict></plist>").unwrap();
+        let manager = GameManager::from_dictionary(&plist.root).unwrap();
+
+        assert_eq!(manager.player_name, "");
+        assert_eq!(manager.stats.jumps, 0);
+        assert!(manager.completed_levels.is_empty());
+        assert!(manager.online_levels.is_empty());
+    }
+}
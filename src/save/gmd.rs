@@ -0,0 +1,127 @@
+//! Module for reading and writing `.gmd` level files
+//!
+//! A `.gmd` file (as produced by level sharing tools such as GDShare) is an unencrypted plist whose
+//! root dictionary is a single level, in the same format as the level dictionaries stored in
+//! `CCLocalLevels.dat`. This means levels can be moved between `.gmd` files, the local save file and
+//! the servers by going through [`LocalLevel`] (and [`Level`](crate::model::level::online_level::Level)).
+
+use crate::{
+    model::level::{local_level::LocalLevel, LevelProcessError},
+    save::{Plist, SaveError, Value},
+};
+use std::borrow::Cow;
+
+/// The XML declaration and opening `<plist>` tag written at the start of every `.gmd` file
+pub const GMD_HEADER: &str = r#"<?xml version="1.0"?><plist version="1.0" gjver="2.0">"#;
+
+/// The closing tag written at the end of every `.gmd` file
+pub const GMD_FOOTER: &str = "</plist>";
+
+/// The value of the `kCEK` key that identifies a dictionary as a level
+///
+/// ## GD Internals:
+/// `kCEK` is the "class key" the game uses to tell apart the different kinds of objects it stores
+/// in plists.
+pub const LEVEL_CLASS_KEY: i64 = 4;
+
+/// Parses the contents of a `.gmd` file
+///
+/// The level itself can be read from the returned plist's root dictionary using
+/// [`LocalLevel::from_dictionary`], or directly by using [`parse_level`].
+pub fn parse(xml: &str) -> Result<Plist<'_>, SaveError> {
+    let plist = Plist::parse(xml)?;
+
+    match plist.root.get("kCEK").map(Value::as_i64) {
+        None | Some(Some(LEVEL_CLASS_KEY)) => Ok(plist),
+        Some(_) => Err(SaveError::InvalidValue("kCEK")),
+    }
+}
+
+/// Reads the level stored in a plist previously obtained via [`parse`]
+pub fn parse_level<'a>(plist: &'a Plist<'_>) -> Result<LocalLevel<'a>, SaveError> {
+    LocalLevel::from_dictionary(&plist.root)
+}
+
+/// Creates the plist of a `.gmd` file containing the given level
+///
+/// The `kCEK` entry is always written first, as the game does. If the level does not carry one
+/// (for instance because it was converted from a
+/// [`Level`](crate::model::level::online_level::Level) downloaded from the servers), it is added.
+/// All other entries are written in the order [`LocalLevel::to_dictionary`] produces them, so a
+/// level read from a `.gmd` file is written back out unchanged.
+pub fn to_plist<'a>(level: &'a LocalLevel<'_>) -> Result<Plist<'a>, LevelProcessError> {
+    let mut root = level.to_dictionary()?;
+
+    if !root.move_to_front("kCEK") {
+        root.insert("kCEK", Value::from(LEVEL_CLASS_KEY));
+        root.move_to_front("kCEK");
+    }
+
+    Ok(Plist {
+        header: Cow::Borrowed(GMD_HEADER),
+        root,
+        footer: Cow::Borrowed(GMD_FOOTER),
+    })
+}
+
+/// Serializes the given level into the contents of a `.gmd` file
+pub fn to_xml(level: &LocalLevel<'_>) -> Result<String, LevelProcessError> {
+    Ok(to_plist(level)?.to_xml())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::save::{
+        gmd::{parse, parse_level, to_xml, GMD_HEADER},
+        Value,
+    };
+
+    const GMD: &str = r#"<?xml version="1.0"?><plist version="1.0" gjver="2.0"><dict><k>kCEK</k><i>4</i><k>k2</k><s>Test Level</s><k>k5</k><s>Ryder</s><k>k16</k><i>2</i></dict></plist>"#;
+
+    #[test]
+    fn gmd_roundtrip() {
+        let plist = parse(GMD).unwrap();
+        let level = parse_level(&plist).unwrap();
+
+        assert_eq!(level.name, "Test Level");
+        assert_eq!(level.creator_name, "Ryder");
+        assert_eq!(level.version, 2);
+
+        let xml = to_xml(&level).unwrap();
+        let written = parse(&xml).unwrap();
+
+        assert!(xml.starts_with(GMD_HEADER));
+        assert_eq!(written.root.get("kCEK").and_then(Value::as_i64), Some(4));
+        assert_eq!(parse_level(&written).unwrap(), level);
+    }
+
+    #[test]
+    fn gmd_roundtrip_is_byte_identical() {
+        let plist = parse(GMD).unwrap();
+
+        assert_eq!(to_xml(&parse_level(&plist).unwrap()).unwrap(), GMD);
+    }
+
+    #[test]
+    fn gmd_moves_class_key_first() {
+        let plist = parse(r#"<plist><dict><k>k2</k><s>Test Level</s><k>kCEK</k><i>4</i></dict></plist>"#).unwrap();
+        let level = parse_level(&plist).unwrap();
+        let xml = to_xml(&level).unwrap();
+
+        assert_eq!(xml, format!("{}<dict><k>kCEK</k><i>4</i><k>k2</k><s>Test Level</s></dict></plist>", GMD_HEADER));
+    }
+
+    #[test]
+    fn gmd_adds_class_key() {
+        let plist = parse(r#"<plist><dict><k>k2</k><s>Test Level</s></dict></plist>"#).unwrap();
+        let level = parse_level(&plist).unwrap();
+        let xml = to_xml(&level).unwrap();
+
+        assert!(xml.starts_with(&format!("{}<dict><k>kCEK</k><i>4</i><k>k2</k>", GMD_HEADER)), "{}", xml);
+    }
+
+    #[test]
+    fn gmd_wrong_class_key() {
+        assert!(parse(r#"<plist><dict><k>kCEK</k><i>2</i></dict></plist>"#).is_err());
+    }
+}
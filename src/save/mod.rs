@@ -0,0 +1,184 @@
+//! Module containing functionality for reading and writing Geometry Dash's local save files
+//!
+//! Geometry Dash stores its save data in two files, `CCGameManager.dat` (player stats, settings,
+//! unlocks, ...) and `CCLocalLevels.dat` (the levels created by the player). Both are a
+//! [plist-style dictionary](plist) that has been encrypted in the following way:
+//!
+//! 1. The XML is gzip compressed
+//! 2. The compressed data is URL-safe base64 encoded
+//! 3. Every byte of the base64 string is XOR-ed with [`SAVE_XOR_KEY`]
+//!
+//! [`decrypt`] undoes these steps and [`encrypt`] re-applies them. Compression uses the same
+//! settings and gzip header as the game, so the game reads re-encrypted files, and the XML inside
+//! them is unchanged. The encrypted bytes themselves can still differ from the game's: the game
+//! pads some files with null bytes, which [`decrypt`] strips and [`encrypt`] does not add back,
+//! and different zlib versions may compress the same XML differently.
+//!
+//! Note that this only applies to the Windows and Android versions of the game. The macOS version
+//! encrypts its save files using AES, which is not supported.
+
+use crate::util;
+use flate2::{
+    read::{GzDecoder, GzEncoder},
+    Compression,
+};
+use std::{
+    fmt::{Display, Formatter},
+    io::Read,
+};
+
+pub mod game_manager;
+pub mod gmd;
+pub mod plist;
+
+pub use plist::{Dictionary, Plist, Value};
+
+/// The key every byte of an encrypted save file is XOR-ed with
+pub const SAVE_XOR_KEY: u8 = 11;
+
+/// The value of the "operating system" field in the gzip header of save files written by the game
+const GZIP_OS: u8 = 11;
+
+/// Enum modelling the different errors that can occur while processing a save file
+#[derive(Debug)]
+pub enum SaveError {
+    /// The save data was not valid base64 after removing the XOR layer
+    Base64(base64::DecodeError),
+
+    /// Error during (de)compression
+    Compression(std::io::Error),
+
+    /// The decompressed save data was not valid UTF-8
+    Utf8(std::string::FromUtf8Error),
+
+    /// The decrypted save data was not a valid plist. `position` is the byte offset into the XML at
+    /// which `expected` was expected
+    Plist { position: usize, expected: &'static str },
+
+    /// A required key was missing from a dictionary
+    MissingKey(&'static str),
+
+    /// The value stored under the given key was not of the expected type
+    InvalidValue(&'static str),
+}
+
+impl Display for SaveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveError::Base64(inner) => inner.fmt(f),
+            SaveError::Compression(inner) => inner.fmt(f),
+            SaveError::Utf8(inner) => inner.fmt(f),
+            SaveError::Plist { position, expected } => write!(f, "invalid plist: expected {} at position {}", expected, position),
+            SaveError::MissingKey(key) => write!(f, "missing key {}", key),
+            SaveError::InvalidValue(key) => write!(f, "invalid value for key {}", key),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+/// Decrypts the contents of a `CCGameManager.dat` or `CCLocalLevels.dat` file into the XML of the
+/// plist stored within
+pub fn decrypt(data: &[u8]) -> Result<String, SaveError> {
+    let mut data = data.to_vec();
+
+    util::cyclic_xor(&mut data, &[SAVE_XOR_KEY]);
+
+    // The game pads some files with null bytes, which have turned into XOR-key bytes by now
+    while let Some(&SAVE_XOR_KEY) = data.last() {
+        data.pop();
+    }
+
+    let compressed = base64::decode_config(&data, base64::URL_SAFE).map_err(SaveError::Base64)?;
+
+    let mut decompressed = Vec::new();
+
+    GzDecoder::new(&compressed[..])
+        .read_to_end(&mut decompressed)
+        .map_err(SaveError::Compression)?;
+
+    String::from_utf8(decompressed).map_err(SaveError::Utf8)
+}
+
+/// Encrypts the XML of a plist into the format of `CCGameManager.dat` and `CCLocalLevels.dat`
+pub fn encrypt(xml: &str) -> Result<Vec<u8>, SaveError> {
+    let mut compressed = Vec::new();
+
+    GzEncoder::new(xml.as_bytes(), Compression::default())
+        .read_to_end(&mut compressed)
+        .map_err(SaveError::Compression)?;
+
+    // The operating system byte of the gzip header. flate2 writes "unknown" here, while the game's
+    // zlib writes a different value
+    if let Some(os) = compressed.get_mut(9) {
+        *os = GZIP_OS;
+    }
+
+    let mut encoded = base64::encode_config(&compressed, base64::URL_SAFE).into_bytes();
+
+    util::cyclic_xor(&mut encoded, &[SAVE_XOR_KEY]);
+
+    Ok(encoded)
+}
+
+/// Decrypts a save file and parses the plist stored within.
+///
+/// Since the returned [`Plist`] borrows from the decrypted XML, the XML is written into `buffer`
+pub fn load<'a>(data: &[u8], buffer: &'a mut String) -> Result<Plist<'a>, SaveError> {
+    *buffer = decrypt(data)?;
+
+    Plist::parse(buffer)
+}
+
+/// Serializes and encrypts a plist into the format of a save file
+pub fn store(plist: &Plist<'_>) -> Result<Vec<u8>, SaveError> {
+    encrypt(&plist.to_xml())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::save::{decrypt, encrypt, load, store, Value, SAVE_XOR_KEY};
+
+    const SAVE_XML: &str = r#"<?xml version="1.0"?><plist version="1.0" gjver="2.0"><dict><k>valueKeeper</k><d><k>gv_0001</k><s>1</s></d><k>playerName</k><s>Tom &amp; Jerry</s><k>playerUserID</k><i>8451</i><k>bgVolume</k><r>0.5</r><k>hasRP</k><t /><k>emptyDict</k><d /></dict></plist>"#;
+
+    #[test]
+    fn encrypted_header() {
+        let encrypted = encrypt(SAVE_XML).unwrap();
+        let mut base64 = encrypted.clone();
+
+        crate::util::cyclic_xor(&mut base64, &[SAVE_XOR_KEY]);
+
+        // All save files written by the game start like this
+        assert!(base64.starts_with(b"H4sIAAAAAAAAC"), "{}", String::from_utf8_lossy(&base64));
+    }
+
+    #[test]
+    fn decrypt_encrypt_roundtrip() {
+        let encrypted = encrypt(SAVE_XML).unwrap();
+
+        assert_eq!(decrypt(&encrypted).unwrap(), SAVE_XML);
+        assert_eq!(encrypt(&decrypt(&encrypted).unwrap()).unwrap(), encrypted);
+    }
+
+    #[test]
+    fn decrypt_null_padded() {
+        let mut encrypted = encrypt(SAVE_XML).unwrap();
+
+        encrypted.extend_from_slice(&[0; 3]);
+
+        assert_eq!(decrypt(&encrypted).unwrap(), SAVE_XML);
+    }
+
+    #[test]
+    fn load_store_roundtrip() {
+        let encrypted = encrypt(SAVE_XML).unwrap();
+        let mut buffer = String::new();
+        let plist = load(&encrypted, &mut buffer).unwrap();
+
+        assert_eq!(plist.root.get("playerName").and_then(Value::as_str), Some("Tom & Jerry"));
+        assert_eq!(plist.root.get("playerUserID").and_then(Value::as_i64), Some(8451));
+        assert_eq!(plist.root.get("bgVolume").and_then(Value::as_f64), Some(0.5));
+        assert_eq!(plist.root.get("hasRP").and_then(Value::as_bool), Some(true));
+        assert_eq!(store(&plist).unwrap(), encrypted);
+    }
+}
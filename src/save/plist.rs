@@ -0,0 +1,629 @@
+//! Module containing a parser and writer for the plist dialect used in Geometry Dash's save files
+//!
+//! RobTop uses a compressed variation of Apple's XML property list format, where all tags are
+//! abbreviated to a single letter:
+//!
+//! * `<k>` - the key of a dictionary entry
+//! * `<s>` - a string value
+//! * `<i>` - an integer value
+//! * `<r>` - a real (floating point) value
+//! * `<t />` (or `<t/>`) - the boolean `true`. `false` values are represented by omitting the entry
+//! * `<d>` - a nested dictionary
+//!
+//! Only the root dictionary uses the regular `<dict>` tag. Arrays are stored as dictionaries with
+//! keys of the form `k_<index>`.
+//!
+//! Entries are kept in the order they appear in the XML, integers and reals keep their textual
+//! representation and entries whose XML differs from what the writer would produce (for instance
+//! because of `&quot;` escapes or `<t/>` tags) remember their original XML, so that parsing and
+//! re-serializing an unmodified plist reproduces the XML byte by byte.
+
+use crate::save::SaveError;
+use std::{borrow::Cow, convert::TryFrom, fmt::Write, iter::FromIterator};
+
+/// A parsed save file plist
+#[derive(Debug, Clone, PartialEq)]
+pub struct Plist<'a> {
+    /// Everything before the root dictionary, namely the XML declaration and the opening `<plist>`
+    /// tag
+    pub header: Cow<'a, str>,
+
+    /// The root dictionary
+    pub root: Dictionary<'a>,
+
+    /// Everything after the root dictionary, usually just the closing `</plist>` tag
+    pub footer: Cow<'a, str>,
+}
+
+/// A plist dictionary, whose entries are kept in insertion order
+#[derive(Debug, Clone, Default)]
+pub struct Dictionary<'a> {
+    entries: Vec<Entry<'a>>,
+}
+
+/// An entry of a [`Dictionary`]
+#[derive(Debug, Clone)]
+struct Entry<'a> {
+    key: Cow<'a, str>,
+    value: Value<'a>,
+
+    /// The XML this entry was parsed from, if it differs from the XML [`Entry::write`] would
+    /// produce. It covers the key and the value, except for non-empty dictionaries, where it only
+    /// covers the key and the opening `<d>` tag.
+    ///
+    /// It is only written back as long as it still describes this entry
+    raw: Option<Cow<'a, str>>,
+}
+
+/// A value stored in a plist [`Dictionary`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value<'a> {
+    /// A string value, stored unescaped
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the `<s>` tag
+    String(Cow<'a, str>),
+
+    /// An integer value, stored in its textual representation
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the `<i>` tag
+    Integer(Cow<'a, str>),
+
+    /// A floating point value, stored in its textual representation
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the `<r>` tag
+    Real(Cow<'a, str>),
+
+    /// The boolean `true`
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the `<t />` tag, or sometimes the `<t/>` tag
+    True,
+
+    /// A nested dictionary
+    ///
+    /// ## GD Internals:
+    /// This variant is represented by the `<d>` tag
+    Dict(Dictionary<'a>),
+}
+
+impl<'a> Plist<'a> {
+    /// Parses the XML of a (decrypted) save file
+    pub fn parse(xml: &'a str) -> Result<Self, SaveError> {
+        let start = xml.find("<dict>").ok_or(SaveError::Plist {
+            position: 0,
+            expected: "<dict>",
+        })?;
+
+        let mut parser = Parser {
+            input: xml,
+            position: start + "<dict>".len(),
+        };
+
+        let root = parser.dictionary("</dict>")?;
+
+        Ok(Plist {
+            header: Cow::Borrowed(&xml[..start]),
+            root,
+            footer: Cow::Borrowed(&xml[parser.position..]),
+        })
+    }
+
+    /// Serializes this plist back into XML
+    pub fn to_xml(&self) -> String {
+        let mut xml = String::with_capacity(self.header.len() + self.footer.len());
+
+        xml.push_str(&self.header);
+        xml.push_str("<dict>");
+        self.root.write_entries(&mut xml);
+        xml.push_str("</dict>");
+        xml.push_str(&self.footer);
+
+        xml
+    }
+
+    pub fn into_owned(self) -> Plist<'static> {
+        Plist {
+            header: Cow::Owned(self.header.into_owned()),
+            root: self.root.into_owned(),
+            footer: Cow::Owned(self.footer.into_owned()),
+        }
+    }
+}
+
+impl<'a> Dictionary<'a> {
+    pub fn new() -> Self {
+        Dictionary::default()
+    }
+
+    /// Gets the value stored under the given key
+    pub fn get(&self, key: &str) -> Option<&Value<'a>> {
+        self.entries.iter().find(|entry| entry.key == key).map(|entry| &entry.value)
+    }
+
+    /// Gets a mutable reference to the value stored under the given key
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut Value<'a>> {
+        self.entries.iter_mut().find(|entry| entry.key == key).map(|entry| &mut entry.value)
+    }
+
+    /// Gets the integer stored under the given key, converted to `T`. Fails if the stored value is
+    /// not an integer, or does not fit into `T`
+    pub(crate) fn get_int<T: TryFrom<i64>>(&self, key: &'static str) -> Result<Option<T>, SaveError> {
+        match self.get(key) {
+            None => Ok(None),
+            Some(value) =>
+                value
+                    .as_i64()
+                    .and_then(|int| T::try_from(int).ok())
+                    .map(Some)
+                    .ok_or(SaveError::InvalidValue(key)),
+        }
+    }
+
+    /// Stores a value under the given key, returning the previous value.
+    ///
+    /// Existing entries keep their position, new entries are appended
+    pub fn insert(&mut self, key: impl Into<Cow<'a, str>>, value: Value<'a>) -> Option<Value<'a>> {
+        let key = key.into();
+
+        match self.get_mut(&key) {
+            Some(old) => Some(std::mem::replace(old, value)),
+            None => {
+                self.entries.push(Entry { key, value, raw: None });
+
+                None
+            },
+        }
+    }
+
+    /// Removes the entry with the given key, returning its value
+    pub fn remove(&mut self, key: &str) -> Option<Value<'a>> {
+        let index = self.entries.iter().position(|entry| entry.key == key)?;
+
+        Some(self.entries.remove(index).value)
+    }
+
+    /// Moves the entry with the given key to the front of this dictionary, returning whether such
+    /// an entry exists. The entry keeps its original XML
+    pub(crate) fn move_to_front(&mut self, key: &str) -> bool {
+        match self.entries.iter().position(|entry| entry.key == key) {
+            Some(index) => {
+                self.entries[..=index].rotate_right(1);
+
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Iterates over the entries of this dictionary in order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value<'a>)> {
+        self.entries.iter().map(|entry| (entry.key.as_ref(), &entry.value))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn into_owned(self) -> Dictionary<'static> {
+        Dictionary {
+            entries: self.entries.into_iter().map(Entry::into_owned).collect(),
+        }
+    }
+
+    fn write_entries(&self, xml: &mut String) {
+        for entry in &self.entries {
+            entry.write(xml);
+        }
+    }
+}
+
+impl PartialEq for Dictionary<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<'a> IntoIterator for Dictionary<'a> {
+    type IntoIter = std::vec::IntoIter<(Cow<'a, str>, Value<'a>)>;
+    type Item = (Cow<'a, str>, Value<'a>);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries
+            .into_iter()
+            .map(|entry| (entry.key, entry.value))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+impl<'a, K: Into<Cow<'a, str>>> FromIterator<(K, Value<'a>)> for Dictionary<'a> {
+    fn from_iter<I: IntoIterator<Item = (K, Value<'a>)>>(iter: I) -> Self {
+        let mut dict = Dictionary::new();
+
+        for (key, value) in iter {
+            dict.insert(key, value);
+        }
+
+        dict
+    }
+}
+
+impl<'a> Entry<'a> {
+    /// Constructs the entry parsed from the given XML, remembering the XML if writing the entry
+    /// would not reproduce it
+    fn parsed(key: Cow<'a, str>, value: Value<'a>, xml: &'a str) -> Self {
+        let mut entry = Entry { key, value, raw: None };
+
+        // Only escapes, self-closing tags without a space and empty dictionaries that are not
+        // self-closing can be written differently
+        if xml.contains('&') || xml.contains("/>") || xml.ends_with("<d></d>") {
+            let mut written = String::with_capacity(xml.len());
+
+            entry.write_head(&mut written);
+
+            if written != xml {
+                entry.raw = Some(Cow::Borrowed(xml));
+            }
+        }
+
+        entry
+    }
+
+    /// Whether the given XML, which this entry was parsed from, still describes this entry
+    fn is_described_by(&self, xml: &str) -> bool {
+        let mut parser = Parser { input: xml, position: 0 };
+
+        if parser.expect("<k>").is_err() || parser.text("</k>").ok().as_deref() != Some(&*self.key) {
+            return false
+        }
+
+        match &self.value {
+            Value::Dict(dict) if !dict.is_empty() => parser.rest() == "<d>",
+            value => parser.value().ok().as_ref() == Some(value) && parser.rest().is_empty(),
+        }
+    }
+
+    /// Writes the part of this entry covered by [`Entry::raw`]
+    fn write_head(&self, xml: &mut String) {
+        xml.push_str("<k>");
+        escape_into(&self.key, xml);
+        xml.push_str("</k>");
+
+        match &self.value {
+            Value::Dict(dict) if !dict.is_empty() => xml.push_str("<d>"),
+            value => value.write(xml),
+        }
+    }
+
+    fn write(&self, xml: &mut String) {
+        match &self.raw {
+            Some(raw) if self.is_described_by(raw) => xml.push_str(raw),
+            _ => self.write_head(xml),
+        }
+
+        if let Value::Dict(dict) = &self.value {
+            if !dict.is_empty() {
+                dict.write_entries(xml);
+                xml.push_str("</d>");
+            }
+        }
+    }
+
+    fn into_owned(self) -> Entry<'static> {
+        Entry {
+            key: Cow::Owned(self.key.into_owned()),
+            value: self.value.into_owned(),
+            raw: self.raw.map(|raw| Cow::Owned(raw.into_owned())),
+        }
+    }
+}
+
+impl<'a> Value<'a> {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    /// Gets this value as an integer. Also succeeds for [`Value::String`]s containing an integer,
+    /// since RobTop is not always consistent with his types
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::Integer(int) | Value::String(int) => int.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Gets this value as a floating point number. Also succeeds for integers and strings
+    /// containing a number
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Real(float) | Value::Integer(float) | Value::String(float) => float.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::True => Some(true),
+            _ => None,
+        }
+    }
+
+    pub fn as_dict(&self) -> Option<&Dictionary<'a>> {
+        match self {
+            Value::Dict(dict) => Some(dict),
+            _ => None,
+        }
+    }
+
+    pub fn as_dict_mut(&mut self) -> Option<&mut Dictionary<'a>> {
+        match self {
+            Value::Dict(dict) => Some(dict),
+            _ => None,
+        }
+    }
+
+    pub fn into_owned(self) -> Value<'static> {
+        match self {
+            Value::String(string) => Value::String(Cow::Owned(string.into_owned())),
+            Value::Integer(int) => Value::Integer(Cow::Owned(int.into_owned())),
+            Value::Real(float) => Value::Real(Cow::Owned(float.into_owned())),
+            Value::True => Value::True,
+            Value::Dict(dict) => Value::Dict(dict.into_owned()),
+        }
+    }
+
+    /// Writes this value. For non-empty dictionaries, [`Entry::write`] writes the content
+    fn write(&self, xml: &mut String) {
+        match self {
+            Value::String(string) => {
+                xml.push_str("<s>");
+                escape_into(string, xml);
+                xml.push_str("</s>");
+            },
+            Value::Integer(int) => {
+                let _ = write!(xml, "<i>{}</i>", int);
+            },
+            Value::Real(float) => {
+                let _ = write!(xml, "<r>{}</r>", float);
+            },
+            Value::True => xml.push_str("<t />"),
+            Value::Dict(dict) if dict.is_empty() => xml.push_str("<d />"),
+            Value::Dict(dict) => {
+                xml.push_str("<d>");
+                dict.write_entries(xml);
+                xml.push_str("</d>");
+            },
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Value<'a> {
+    fn from(string: &'a str) -> Self {
+        Value::String(Cow::Borrowed(string))
+    }
+}
+
+impl From<String> for Value<'_> {
+    fn from(string: String) -> Self {
+        Value::String(Cow::Owned(string))
+    }
+}
+
+impl From<i64> for Value<'_> {
+    fn from(int: i64) -> Self {
+        Value::Integer(Cow::Owned(int.to_string()))
+    }
+}
+
+impl From<f64> for Value<'_> {
+    fn from(float: f64) -> Self {
+        Value::Real(Cow::Owned(float.to_string()))
+    }
+}
+
+impl<'a> From<Dictionary<'a>> for Value<'a> {
+    fn from(dict: Dictionary<'a>) -> Self {
+        Value::Dict(dict)
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn error(&self, expected: &'static str) -> SaveError {
+        SaveError::Plist {
+            position: self.position,
+            expected,
+        }
+    }
+
+    fn consume(&mut self, tag: &'static str) -> bool {
+        if self.rest().starts_with(tag) {
+            self.position += tag.len();
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, tag: &'static str) -> Result<(), SaveError> {
+        if self.consume(tag) {
+            Ok(())
+        } else {
+            Err(self.error(tag))
+        }
+    }
+
+    /// Reads the text up to the given closing tag and consumes the tag
+    fn text(&mut self, closing: &'static str) -> Result<Cow<'a, str>, SaveError> {
+        let length = self.rest().find('<').ok_or_else(|| self.error(closing))?;
+        let text = &self.rest()[..length];
+
+        self.position += length;
+        self.expect(closing)?;
+
+        Ok(unescape(text))
+    }
+
+    /// Parses the entries of a dictionary whose opening tag has already been consumed
+    fn dictionary(&mut self, closing: &'static str) -> Result<Dictionary<'a>, SaveError> {
+        let mut entries = Vec::new();
+
+        while !self.consume(closing) {
+            let start = self.position;
+
+            self.expect("<k>")?;
+
+            let key = self.text("</k>")?;
+
+            // The raw XML of non-empty dictionaries only extends to their opening tag
+            let (value, end) = if self.rest().starts_with("<d>") && !self.rest().starts_with("<d></d>") {
+                self.position += "<d>".len();
+
+                let end = self.position;
+
+                (Value::Dict(self.dictionary("</d>")?), end)
+            } else {
+                let value = self.value()?;
+
+                (value, self.position)
+            };
+
+            entries.push(Entry::parsed(key, value, &self.input[start..end]));
+        }
+
+        Ok(Dictionary { entries })
+    }
+
+    fn value(&mut self) -> Result<Value<'a>, SaveError> {
+        if self.consume("<s>") {
+            Ok(Value::String(self.text("</s>")?))
+        } else if self.consume("<i>") {
+            Ok(Value::Integer(self.text("</i>")?))
+        } else if self.consume("<r>") {
+            Ok(Value::Real(self.text("</r>")?))
+        } else if self.consume("<t />") || self.consume("<t/>") {
+            Ok(Value::True)
+        } else if self.consume("<d />") || self.consume("<d/>") {
+            Ok(Value::Dict(Dictionary::new()))
+        } else if self.consume("<d>") {
+            Ok(Value::Dict(self.dictionary("</d>")?))
+        } else {
+            Err(self.error("value"))
+        }
+    }
+}
+
+fn unescape(text: &str) -> Cow<'_, str> {
+    if !text.contains('&') {
+        return Cow::Borrowed(text)
+    }
+
+    Cow::Owned(
+        text.replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&apos;", "'")
+            .replace("&amp;", "&"),
+    )
+}
+
+fn escape_into(text: &str, xml: &mut String) {
+    for c in text.chars() {
+        match c {
+            '&' => xml.push_str("&amp;"),
+            '<' => xml.push_str("&lt;"),
+            '>' => xml.push_str("&gt;"),
+            c => xml.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::save::plist::{Dictionary, Plist, Value};
+
+    const XML: &str = r#"<?xml version="1.0"?><plist version="1.0" gjver="2.0"><dict><k>LLM_01</k><d><k>_isArr</k><t /><k>k_0</k><d><k>kCEK</k><i>4</i><k>k2</k><s>My &lt;Level&gt;</s></d></d><k>LLM_02</k><i>37</i></dict></plist>"#;
+
+    const UNUSUAL_XML: &str = r#"<plist><dict><k>a</k><s>&quot;Quoted&quot; &apos;level&apos;</s><k>b</k><t/><k>c</k><d/><k>d</k><d></d><k>e</k><d><k>f</k><t/></d></dict></plist>"#;
+
+    #[test]
+    fn parse_write_roundtrip() {
+        let plist = Plist::parse(XML).unwrap();
+
+        assert_eq!(plist.to_xml(), XML);
+    }
+
+    #[test]
+    fn parse_write_roundtrip_keeps_original_xml() {
+        let plist = Plist::parse(UNUSUAL_XML).unwrap();
+
+        assert_eq!(plist.root.get("a").and_then(Value::as_str), Some("\"Quoted\" 'level'"));
+        assert_eq!(plist.to_xml(), UNUSUAL_XML);
+    }
+
+    #[test]
+    fn modified_entries_are_written_anew() {
+        let mut plist = Plist::parse(UNUSUAL_XML).unwrap();
+
+        plist.root.insert("a", Value::from("\"Changed\""));
+        plist.root.get_mut("e").and_then(Value::as_dict_mut).unwrap().insert("g", Value::from(1));
+
+        assert_eq!(
+            plist.to_xml(),
+            r#"<plist><dict><k>a</k><s>"Changed"</s><k>b</k><t/><k>c</k><d/><k>d</k><d></d><k>e</k><d><k>f</k><t/><k>g</k><i>1</i></d></dict></plist>"#
+        );
+    }
+
+    #[test]
+    fn parse_nested() {
+        let plist = Plist::parse(XML).unwrap();
+        let level = plist
+            .root
+            .get("LLM_01")
+            .and_then(Value::as_dict)
+            .and_then(|levels| levels.get("k_0"))
+            .and_then(Value::as_dict)
+            .unwrap();
+
+        assert_eq!(level.get("k2").and_then(Value::as_str), Some("My <Level>"));
+        assert_eq!(level.get("kCEK").and_then(Value::as_i64), Some(4));
+        assert_eq!(plist.root.get("LLM_02").and_then(Value::as_i64), Some(37));
+    }
+
+    #[test]
+    fn insert_keeps_order() {
+        let mut dict = Dictionary::new();
+
+        dict.insert("a", Value::from(1));
+        dict.insert("b", Value::from("x"));
+        assert_eq!(dict.insert("a", Value::from(2)), Some(Value::from(1)));
+
+        assert_eq!(dict.iter().map(|(key, _)| key).collect::<Vec<_>>(), vec!["a", "b"]);
+        assert_eq!(dict.get("a").and_then(Value::as_i64), Some(2));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(Plist::parse("<plist><dict><k>a</k><x>1</x></dict></plist>").is_err());
+        assert!(Plist::parse("<plist></plist>").is_err());
+    }
+}
@@ -16,7 +16,7 @@ use crate::serde::SerError as Error;
 use dtoa::Floating;
 use itoa::Integer;
 use serde::{
-    ser::{Error as _, Impossible, SerializeStruct},
+    ser::{Error as _, Impossible, SerializeMap, SerializeStruct},
     Serialize, Serializer,
 };
 use std::{fmt::Display, io::Write};
@@ -28,11 +28,19 @@ pub struct RequestSerializer<W> {
     /// Value indicating whether this serializer has already serialized something. This is used to
     /// check if we need to prepend the delimiter to the next field.
     is_start: bool,
+
+    /// The key of the map entry currently being serialized. Map entries are produced by fields
+    /// marked with `#[serde(flatten)]`, whose keys are only known at runtime.
+    map_key: String,
 }
 
 impl<W> RequestSerializer<W> {
     pub fn new(writer: W) -> Self {
-        RequestSerializer { writer, is_start: true }
+        RequestSerializer {
+            writer,
+            is_start: true,
+            map_key: String::new(),
+        }
     }
 }
 
@@ -49,7 +57,7 @@ macro_rules! unsupported {
 impl<'a, W: Write> Serializer for &'a mut RequestSerializer<W> {
     type Error = Error;
     type Ok = ();
-    type SerializeMap = Impossible<(), Error>;
+    type SerializeMap = Self;
     type SerializeSeq = Impossible<(), Error>;
     type SerializeStruct = Self;
     type SerializeStructVariant = Impossible<(), Error>;
@@ -132,7 +140,8 @@ impl<'a, W: Write> Serializer for &'a mut RequestSerializer<W> {
     }
 
     fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
-        Err(Error::Unsupported("serialize_map"))
+        // serde serializes structs containing `#[serde(flatten)]` fields as maps
+        Ok(self)
     }
 
     fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct, Self::Error> {
@@ -161,14 +170,10 @@ impl<'a, W: Write> SerializeStruct for &'a mut RequestSerializer<W> {
     where
         T: Serialize,
     {
-        if !self.is_start {
-            self.writer.write(b"&").map_err(Error::custom)?;
-        }
-
-        // we cannot do self.is_start = false here because the first field might be a struct that was
-        // inlined, meaning no key/value pair is directly constructed! It has to happen when we actually
-        // write the first key, which occurs inside some nested ValueSerializer call.
-
+        // We cannot write the delimiter here because the field might be a struct that gets inlined
+        // (meaning no key/value pair is directly constructed), or an optional block of fields that is
+        // entirely absent. It is written when we actually write the next key, which occurs inside some
+        // nested ValueSerializer call.
         value.serialize(&mut ValueSerializer {
             key: Some(key),
             serializer: self,
@@ -181,14 +186,49 @@ impl<'a, W: Write> SerializeStruct for &'a mut RequestSerializer<W> {
     }
 }
 
-struct ValueSerializer<'ser, W: Write> {
-    key: Option<&'static str>,
+impl<W: Write> SerializeMap for &mut RequestSerializer<W> {
+    type Error = Error;
+    type Ok = ();
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        let mut buffer = Vec::new();
+
+        key.serialize(&mut ValueSerializer {
+            key: None,
+            serializer: &mut RequestSerializer::new(&mut buffer),
+        })?;
+
+        self.map_key = String::from_utf8(buffer).map_err(Error::custom)?;
+
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = std::mem::take(&mut self.map_key);
+
+        value.serialize(&mut ValueSerializer {
+            key: Some(&key),
+            serializer: self,
+        })
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+struct ValueSerializer<'ser, 'k, W: Write> {
+    key: Option<&'k str>,
     serializer: &'ser mut RequestSerializer<W>,
 }
 
-impl<'ser, W: Write> ValueSerializer<'ser, W> {
+impl<'ser, 'k, W: Write> ValueSerializer<'ser, 'k, W> {
     fn write_key(&mut self) -> Result<(), Error> {
         if let Some(key) = self.key {
+            if !self.serializer.is_start {
+                self.serializer.writer.write(b"&").map_err(Error::custom)?;
+            }
+
             self.serializer.writer.write_all(key.as_bytes()).map_err(Error::custom)?;
             self.serializer.writer.write(b"=").map_err(Error::custom)?;
 
@@ -214,10 +254,10 @@ impl<'ser, W: Write> ValueSerializer<'ser, W> {
     }
 }
 
-impl<'ser, 'a, W: Write> Serializer for &'a mut ValueSerializer<'ser, W> {
+impl<'ser, 'k, 'a, W: Write> Serializer for &'a mut ValueSerializer<'ser, 'k, W> {
     type Error = Error;
     type Ok = ();
-    type SerializeMap = Impossible<(), Error>;
+    type SerializeMap = &'a mut RequestSerializer<W>;
     type SerializeSeq = SerializeSeq<'a, W>;
     type SerializeStruct = &'a mut RequestSerializer<W>;
     type SerializeStructVariant = Impossible<(), Error>;
@@ -377,7 +417,12 @@ impl<'ser, 'a, W: Write> Serializer for &'a mut ValueSerializer<'ser, W> {
     }
 
     fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
-        Err(Error::Unsupported("serialize_map"))
+        if self.key.is_none() {
+            return Err(Error::Unsupported("map inside sequence"))
+        }
+
+        // Like structs, nested maps are inlined
+        Ok(self.serializer)
     }
 
     fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct, Self::Error> {
@@ -385,12 +430,9 @@ impl<'ser, 'a, W: Write> Serializer for &'a mut ValueSerializer<'ser, W> {
             return Err(Error::Unsupported("struct inside sequence"))
         }
 
-        // If we inline a struct, that struct might not be the first field we serialize. However, we do not
-        // know whether the value we serialize is a struct or not until we reach this method, so the value
-        // serializer will already have appended an '&' for us. This means that we do not want to add
-        // another one for the first field of this nested struct.
-        self.serializer.is_start = true;
-
+        // Nested structs are inlined, their key is simply dropped. Since the delimiter is only written
+        // together with the next key, the fields of the nested struct are joined correctly regardless
+        // of where in the outer struct it appears.
         Ok(self.serializer)
     }
 
@@ -456,10 +498,7 @@ mod tests {
         let level_request = LevelRequest::default();
         let mut buffer = Vec::new();
 
-        let mut ser = RequestSerializer {
-            writer: &mut buffer,
-            is_start: true,
-        };
+        let mut ser = RequestSerializer::new(&mut buffer);
         let result = level_request.serialize(&mut ser);
 
         assert!(result.is_ok(), "{:?}", result);
@@ -56,9 +56,10 @@ impl std::error::Error for ProcessError {}
 #[derive(Debug, Eq, PartialEq, Clone, Deserialize)]
 #[serde(untagged)]
 pub enum Thunk<'a, C: ThunkContent<'a>> {
-    /// Unprocessed value
+    /// Unprocessed value. This is usually borrowed from the input it was parsed from, and only
+    /// owned after calling [`Thunk::into_owned`]
     #[serde(skip)]
-    Unprocessed(&'a str),
+    Unprocessed(Cow<'a, str>),
 
     /// Processed value
     Processed(C),
@@ -73,6 +74,9 @@ pub(crate) enum RefThunk<'input, 'content, C: ThunkContent<'input>> {
     /// Unprocessed value
     Unprocessed(&'input str),
 
+    /// Unprocessed value owned by the [`Thunk`] this was created from
+    Owned(&'content str),
+
     /// Processed value
     Processed(&'content C),
 }
@@ -83,7 +87,9 @@ impl<'a, C: ThunkContent<'a> + Serialize> Serialize for Thunk<'a, C> {
         S: Serializer,
     {
         match self {
-            Thunk::Unprocessed(unprocessed) => C::from_unprocessed(unprocessed).map_err(S::Error::custom)?.serialize(serializer),
+            Thunk::Unprocessed(Cow::Borrowed(unprocessed)) => C::from_unprocessed(unprocessed).map_err(S::Error::custom)?.serialize(serializer),
+            Thunk::Unprocessed(Cow::Owned(unprocessed)) =>
+                C::from_owned_unprocessed(unprocessed).map_err(S::Error::custom)?.serialize(serializer),
             Thunk::Processed(processed) => processed.serialize(serializer),
         }
     }
@@ -96,6 +102,7 @@ impl<'input, 'content, C: ThunkContent<'input>> Serialize for RefThunk<'input, '
     {
         match self {
             RefThunk::Unprocessed(unprocessed) => serializer.serialize_str(unprocessed),
+            RefThunk::Owned(unprocessed) => serializer.serialize_str(unprocessed),
             RefThunk::Processed(ref processed) =>
                 match processed.as_unprocessed().map_err(serde::ser::Error::custom)? {
                     Cow::Borrowed(s) => serializer.serialize_str(s),
@@ -127,6 +134,11 @@ pub trait ThunkContent<'a>: Sized {
     /// format.
     fn from_unprocessed(unprocessed: &'a str) -> Result<Self, Self::Error>;
 
+    /// Processes data that does not live for `'a`, as is the case for [`Thunk`]s converted using
+    /// [`Thunk::into_owned`]. Unlike [`ThunkContent::from_unprocessed`], the result cannot borrow
+    /// from the unprocessed data.
+    fn from_owned_unprocessed(unprocessed: &str) -> Result<Self, Self::Error>;
+
     /// Takes some processed thunk value and converts it into RobTop-representation
     fn as_unprocessed(&self) -> Result<Cow<str>, Self::Error>;
 }
@@ -144,8 +156,10 @@ impl<'a, C: ThunkContent<'a>> Thunk<'a, C> {
     /// returns [`Thunk::Processed`]. Simply returns `self` if this is a [`Thunk::Processed`]
     /// variant
     pub fn process(&mut self) -> Result<&C, C::Error> {
-        if let Thunk::Unprocessed(raw_data) = self {
-            *self = Thunk::Processed(C::from_unprocessed(raw_data)?)
+        match self {
+            Thunk::Unprocessed(Cow::Borrowed(raw_data)) => *self = Thunk::Processed(C::from_unprocessed(raw_data)?),
+            Thunk::Unprocessed(Cow::Owned(raw_data)) => *self = Thunk::Processed(C::from_owned_unprocessed(raw_data)?),
+            Thunk::Processed(_) => (),
         }
 
         match self {
@@ -157,15 +171,28 @@ impl<'a, C: ThunkContent<'a>> Thunk<'a, C> {
     /// Returns the result of processing this [`Thunk`]
     pub fn into_processed(self) -> Result<C, C::Error> {
         match self {
-            Thunk::Unprocessed(unprocessed) => C::from_unprocessed(unprocessed),
+            Thunk::Unprocessed(Cow::Borrowed(unprocessed)) => C::from_unprocessed(unprocessed),
+            Thunk::Unprocessed(Cow::Owned(unprocessed)) => C::from_owned_unprocessed(&unprocessed),
             Thunk::Processed(p) => Ok(p),
         }
     }
 
+    /// Converts this [`Thunk`] into one that doesn't borrow from the input it was parsed from.
+    ///
+    /// Unprocessed values are copied without processing them. Processed values are converted using
+    /// the given function.
+    pub fn into_owned<D: ThunkContent<'static>>(self, owned: impl FnOnce(C) -> D) -> Thunk<'static, D> {
+        match self {
+            Thunk::Unprocessed(unprocessed) => Thunk::Unprocessed(Cow::Owned(unprocessed.into_owned())),
+            Thunk::Processed(processed) => Thunk::Processed(owned(processed)),
+        }
+    }
+
     // TODO: uhh maybe AsRef or Borrow or something would be the appropriate trait here?
     pub(crate) fn as_ref_thunk<'content>(&'content self) -> RefThunk<'a, 'content, C> {
         match self {
-            Thunk::Unprocessed(raw) => RefThunk::Unprocessed(raw),
+            Thunk::Unprocessed(Cow::Borrowed(raw)) => RefThunk::Unprocessed(raw),
+            Thunk::Unprocessed(Cow::Owned(raw)) => RefThunk::Owned(raw),
             Thunk::Processed(processed) => RefThunk::Processed(processed),
         }
     }
@@ -185,6 +212,12 @@ pub const ROBTOP_SET: &AsciiSet = &CONTROLS
 #[serde(transparent)]
 pub struct PercentDecoded<'a>(#[serde(borrow)] pub Cow<'a, str>);
 
+impl PercentDecoded<'_> {
+    pub fn into_owned(self) -> PercentDecoded<'static> {
+        PercentDecoded(Cow::Owned(self.0.into_owned()))
+    }
+}
+
 impl<'a> ThunkContent<'a> for PercentDecoded<'a> {
     type Error = ProcessError;
 
@@ -195,6 +228,13 @@ impl<'a> ThunkContent<'a> for PercentDecoded<'a> {
             .map_err(ProcessError::Utf8)
     }
 
+    fn from_owned_unprocessed(unprocessed: &str) -> Result<Self, ProcessError> {
+        percent_decode_str(unprocessed)
+            .decode_utf8()
+            .map(|decoded| PercentDecoded(Cow::Owned(decoded.into_owned())))
+            .map_err(ProcessError::Utf8)
+    }
+
     fn as_unprocessed(&self) -> Result<Cow<str>, ProcessError> {
         Ok(utf8_percent_encode(self.0.as_ref(), ROBTOP_SET).into())
     }
@@ -204,10 +244,21 @@ impl<'a> ThunkContent<'a> for PercentDecoded<'a> {
 #[serde(transparent)]
 pub struct Base64Decoded<'a>(pub Cow<'a, str>);
 
+impl Base64Decoded<'_> {
+    pub fn into_owned(self) -> Base64Decoded<'static> {
+        Base64Decoded(Cow::Owned(self.0.into_owned()))
+    }
+}
+
 impl<'a> ThunkContent<'a> for Base64Decoded<'a> {
     type Error = ProcessError;
 
     fn from_unprocessed(unprocessed: &'a str) -> Result<Self, ProcessError> {
+        // Decoding allocates anyway, so the result never borrows from the input
+        Base64Decoded::from_owned_unprocessed(unprocessed)
+    }
+
+    fn from_owned_unprocessed(unprocessed: &str) -> Result<Self, ProcessError> {
         let vec = base64::decode_config(unprocessed, URL_SAFE).map_err(ProcessError::Base64)?;
         let string = String::from_utf8(vec).map_err(ProcessError::FromUtf8)?;
 
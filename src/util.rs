@@ -80,3 +80,15 @@ pub(crate) fn true_to_ten<S: Serializer>(b: &bool, serializer: S) -> Result<S::O
         false => serializer.serialize_str("0"),
     }
 }
+
+/// Serializes enums with a `From<Enum> for u8` implementation as their numeric value
+pub(crate) fn as_u8<S: Serializer, T: Copy + Into<u8>>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8((*value).into())
+}
+
+/// Serializes floats using their [`Display`](std::fmt::Display) implementation, which (unlike
+/// the indexed serializer) writes integral values without a trailing `.0`, the same way the game
+/// does
+pub(crate) fn float_as_display<S: Serializer, F: std::fmt::Display>(value: &F, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&value.to_string())
+}
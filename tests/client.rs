@@ -0,0 +1,158 @@
+use dash_rs::{
+    client::{ClientError, GdClient, PaginationOptions},
+    model::level::Password::PasswordCopy,
+    request::{
+        account::{AuthenticatedUser, SyncAccountRequest},
+        comment::ProfileCommentsRequest,
+        level::{LevelRequest, LevelsRequest},
+        user::UserSearchRequest,
+        ServerConfig,
+    },
+};
+use futures::StreamExt;
+use std::{borrow::Cow, sync::OnceLock, time::Duration};
+
+#[tokio::test]
+async fn download_level_test() {
+    let client = GdClient::new();
+
+    let level = client.download_level(&LevelRequest::new(76298358)).await.unwrap();
+
+    assert_eq!(level.name, "Edooox Collab");
+    assert_eq!(level.level_data.as_ref().unwrap().password, PasswordCopy(7678));
+}
+
+#[tokio::test]
+async fn search_levels_test() {
+    let client = GdClient::new();
+
+    let levels = client.search_levels(&LevelsRequest::default().search("Spectrum Rave")).await.unwrap();
+
+    assert_eq!(levels[0].level_id, 72308725);
+    assert_eq!(levels[0].creator.as_ref().unwrap().name, "Ryder");
+}
+
+#[tokio::test]
+async fn search_user_test() {
+    let client = GdClient::new();
+
+    let user = client.search_user(&UserSearchRequest::new("Ryder")).await.unwrap();
+
+    assert_eq!(user.account_id, 57903);
+}
+
+#[tokio::test]
+async fn download_missing_level_test() {
+    let client = GdClient::new();
+
+    let result = client.download_level(&LevelRequest::new(0)).await;
+
+    assert!(matches!(result, Err(ClientError::NotFound)));
+}
+
+/// Serves a fixed number of HTTP requests on a local port, answering each with the body produced by
+/// `respond` from the request's form body
+fn serve(requests: usize, respond: fn(&str) -> String) -> String {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+    };
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming().take(requests) {
+            let mut stream = stream.unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 4096];
+
+            // Read until the whole body (as announced by Content-Length) has arrived
+            loop {
+                let read = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..read]);
+
+                let text = String::from_utf8_lossy(&request).to_string();
+
+                if let Some(header_end) = text.find("\r\n\r\n") {
+                    let content_length = text
+                        .lines()
+                        .find_map(|line| line.to_lowercase().strip_prefix("content-length: ").map(|len| len.parse::<usize>().unwrap()))
+                        .unwrap_or(0);
+
+                    if request.len() >= header_end + 4 + content_length {
+                        let body = respond(&text[header_end + 4..]);
+                        write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body).unwrap();
+                        break
+                    }
+                }
+            }
+        }
+    });
+
+    url
+}
+
+fn profile_comments_page(body: &str) -> String {
+    let page: u32 = body.split('&').find_map(|pair| pair.strip_prefix("page=")).unwrap().parse().unwrap();
+    let comments = (0..2)
+        .map(|idx| format!("2~SGVsbG8=~4~0~9~1 day~6~{}", page * 2 + idx))
+        .collect::<Vec<_>>()
+        .join("|");
+
+    format!("{}#5:{}:2", comments, page * 2)
+}
+
+#[tokio::test]
+async fn paginate_profile_comments_until_last_page() {
+    let url = serve(3, profile_comments_page);
+    let client = GdClient::with_server(ServerConfig::new(&url));
+
+    let comments = client
+        .profile_comments_paginated(ProfileCommentsRequest::new(57903), PaginationOptions::default())
+        .collect::<Vec<_>>()
+        .await;
+
+    let ids = comments.into_iter().map(|comment| comment.unwrap().comment_id).collect::<Vec<_>>();
+
+    assert_eq!(ids, vec![0, 1, 2, 3, 4, 5]);
+}
+
+#[tokio::test]
+async fn paginate_profile_comments_with_limit() {
+    let url = serve(2, profile_comments_page);
+    let client = GdClient::with_server(ServerConfig::new(&url));
+    let options = PaginationOptions::default().limit(3).delay(Duration::from_millis(10));
+
+    let comments = client
+        .profile_comments_paginated(ProfileCommentsRequest::new(57903).page(1), options)
+        .collect::<Vec<_>>()
+        .await;
+
+    let ids = comments.into_iter().map(|comment| comment.unwrap().comment_id).collect::<Vec<_>>();
+
+    assert_eq!(ids, vec![2, 3, 4]);
+}
+
+static DATA_SERVER: OnceLock<String> = OnceLock::new();
+
+#[tokio::test]
+async fn sync_account_via_data_server() {
+    DATA_SERVER.get_or_init(|| serve(1, |_| "H4sIAAAAAAAAC_manager;H4sIAAAAAAAAC_levels;21;35".to_string()));
+
+    // Only the `getAccountURL.php` request is sent to the main server, which responds with the bare
+    // host of the data server
+    let url = serve(1, |body| {
+        assert!(body.contains("type=2"));
+
+        DATA_SERVER.get().unwrap().trim_end_matches('/').to_string()
+    });
+    let client = GdClient::with_server(ServerConfig::new(&url));
+    let user = AuthenticatedUser::new("TestUser", 472634, Cow::Borrowed("VGhpc0lzQUZha2VQYXNzd29yZA=="));
+
+    let response = client.sync_account(&SyncAccountRequest::new(user)).await.unwrap();
+
+    assert_eq!(response.game_manager, "H4sIAAAAAAAAC_manager");
+    assert_eq!(response.local_levels, "H4sIAAAAAAAAC_levels");
+    assert_eq!((response.game_version, response.binary_version), (Some(21), Some(35)));
+}
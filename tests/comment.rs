@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use dash_rs::{
     request::{
         account::LoginRequest,
+        Endpoint,
         comment::{UploadCommentRequest, DeleteCommentRequest, CommentHistoryRequest, LevelCommentsRequest, ProfileCommentsRequest, SortMode},
     },
 };
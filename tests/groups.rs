@@ -0,0 +1,147 @@
+use dash_rs::{
+    model::level::{
+        groups::TriggerGraph,
+        local_level::Objects,
+        object::{
+            ids,
+            trigger::{Easing, Trigger, TriggerActivation},
+            LevelObject, ObjectData,
+        },
+    },
+    ThunkContent,
+};
+
+const DARK_REALM_DATA: &str = include_str!("data/11774780_dark_realm_raw_level_data");
+
+fn block(groups: Vec<u16>) -> LevelObject {
+    LevelObject {
+        id: 1,
+        groups,
+        ..LevelObject::default()
+    }
+}
+
+fn trigger(id: u16, groups: Vec<u16>, spawn_triggered: bool, trigger: Trigger) -> LevelObject {
+    LevelObject {
+        id,
+        groups,
+        metadata: ObjectData::Trigger {
+            activation: TriggerActivation {
+                spawn_triggered,
+                ..TriggerActivation::default()
+            },
+            trigger,
+        },
+        ..LevelObject::default()
+    }
+}
+
+fn spawn(groups: Vec<u16>, spawn_triggered: bool, target_group: u16) -> LevelObject {
+    trigger(ids::SPAWN_TRIGGER, groups, spawn_triggered, Trigger::Spawn { target_group, delay: 0.0 })
+}
+
+fn move_trigger(groups: Vec<u16>, target_group: u16) -> LevelObject {
+    trigger(ids::MOVE_TRIGGER, groups, true, Trigger::Move {
+        target_group,
+        duration: 1.0,
+        easing: Easing::None,
+        easing_rate: 0.0,
+        move_x: 30.0,
+        move_y: 0.0,
+        lock_to_player_x: false,
+        lock_to_player_y: false,
+    })
+}
+
+/// Objects:
+/// 0: spawn trigger, targeting group 1
+/// 1: spawn triggered spawn trigger in group 1, targeting group 2
+/// 2: spawn triggered spawn trigger in group 2, targeting group 1 (cycle with 1)
+/// 3: spawn triggered move trigger in group 2, targeting group 3
+/// 4, 5: blocks in group 3
+/// 6: block in groups 3 and 4
+/// 7: toggle trigger, targeting group 7 (which doesn't exist)
+fn objects() -> Vec<LevelObject> {
+    vec![
+        spawn(vec![], false, 1),
+        spawn(vec![1], true, 2),
+        spawn(vec![2], true, 1),
+        move_trigger(vec![2], 3),
+        block(vec![3]),
+        block(vec![3]),
+        block(vec![3, 4]),
+        trigger(ids::TOGGLE_TRIGGER, vec![], false, Trigger::Toggle {
+            target_group: 7,
+            activate_group: false,
+        }),
+    ]
+}
+
+#[test]
+fn group_index() {
+    let objects = objects();
+    let graph = TriggerGraph::new(&objects);
+    let index = graph.group_index();
+
+    assert_eq!(index.objects_in(3), &[4, 5, 6]);
+    assert_eq!(index.objects_in(2), &[2, 3]);
+    assert!(index.objects_in(5).is_empty());
+    assert_eq!(index.groups().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn trigger_activations() {
+    let objects = objects();
+    let graph = TriggerGraph::new(&objects);
+
+    assert_eq!(graph.triggers().collect::<Vec<_>>(), vec![0, 1, 2, 3, 7]);
+    assert_eq!(graph.activated_by(0), &[1]);
+    assert_eq!(graph.activated_by(1), &[2, 3]);
+    assert!(graph.activated_by(3).is_empty());
+    assert!(graph.activated_by(4).is_empty());
+}
+
+#[test]
+fn trigger_cycles() {
+    let objects = objects();
+
+    assert_eq!(TriggerGraph::new(&objects).cycles(), vec![vec![1, 2]]);
+
+    let looping = vec![spawn(vec![1], true, 1)];
+
+    assert_eq!(TriggerGraph::new(&looping).cycles(), vec![vec![0]]);
+}
+
+#[test]
+fn unused_and_missing_groups() {
+    let objects = objects();
+    let graph = TriggerGraph::new(&objects);
+
+    assert_eq!(graph.unused_groups(), vec![4]);
+    assert_eq!(graph.missing_groups(), vec![7]);
+}
+
+#[test]
+fn transitive_touches() {
+    let objects = objects();
+    let graph = TriggerGraph::new(&objects);
+
+    assert_eq!(graph.touched_by(0).into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6]);
+    assert_eq!(graph.touched_by(3).into_iter().collect::<Vec<_>>(), vec![4, 5, 6]);
+    assert!(graph.touched_by(4).is_empty());
+}
+
+#[test]
+fn dark_realm_trigger_graph() {
+    let objects = Objects::from_unprocessed(DARK_REALM_DATA.trim()).unwrap();
+    let graph = objects.trigger_graph();
+
+    assert!(graph.triggers().count() > 0);
+    assert!(graph.group_index().groups().count() > 0);
+
+    for trigger in graph.triggers() {
+        for touched in graph.touched_by(trigger) {
+            assert!(touched < objects.objects.len());
+        }
+    }
+}
@@ -6,6 +6,7 @@ use dash_rs::{
 use dash_rs::model::level::DemonRating::Extreme;
 use dash_rs::model::level::LevelRating::Demon;
 use dash_rs::model::level::Password::PasswordCopy;
+use dash_rs::request::Endpoint;
 use dash_rs::request::level::{LevelRequest, LevelsRequest};
 use dash_rs::response::{parse_download_gj_level_response, parse_get_gj_levels_response};
 
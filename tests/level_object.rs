@@ -0,0 +1,316 @@
+use dash_rs::{
+    model::level::{
+        color::Hsv,
+        local_level::Objects,
+        object::{
+            game_mode::GameMode,
+            gameplay::{Orb, StartPosition},
+            speed::Speed,
+            trigger::{Easing, PulseTarget, Trigger, TriggerActivation},
+            LevelObject, ObjectData, ZLayer,
+        },
+    },
+    HasRobtopFormat, ThunkContent,
+};
+use flate2::read::GzDecoder;
+use std::io::Read;
+
+const DARK_REALM_DATA: &str = include_str!("data/11774780_dark_realm_raw_level_data");
+
+/// Decodes and decompresses the given gzip compressed level string
+fn decompress(level_string: &str) -> String {
+    let decoded = base64::decode_config(level_string, base64::URL_SAFE).unwrap();
+    let mut decompressed = String::new();
+
+    GzDecoder::new(&decoded[..]).read_to_string(&mut decompressed).unwrap();
+    decompressed
+}
+
+const MOVE_TRIGGER: &str = "1,901,2,345,3,75,10,0.5,28,30,29,-60,30,2,51,7,62,1,85,2";
+const PULSE_TRIGGER: &str = "1,1006,2,15,3,15,7,255,46,0.25,48,1,49,180a1a1a0a0,51,3,52,1,87,1";
+const LEGACY_COLOR_TRIGGER: &str = "1,29,2,15,3,15,7,255,8,0,9,0,10,0.5";
+
+const COLOR_TRIGGER_WITH_DEFAULTS: &str = "1,899,2,15,3,15,7,0,8,0,9,0,10,0,23,1,35,1";
+const MOVE_TRIGGER_WITH_DEFAULTS: &str = "1,901,2,15,3,15,7,0,10,0,28,0,29,30,30,0,35,1,51,4";
+const ALPHA_TRIGGER_WITH_DEFAULTS: &str = "1,1007,2,15,3,15,10,0,35,1,51,5";
+const PULSE_TRIGGER_UNORDERED: &str = "1,1006,2,15,3,15,36,1,51,1000,47,0.5";
+const MOVE_TRIGGER_WITH_UNUSED_KEYS: &str = "1,901,2,15,3,15,7,255,35,0.5,51,4";
+
+const OBJECTS_WITH_TRIGGER_KEYS: [(&str, &str, &str); 3] = [
+    ("1,1,2,15,3,15,30,300", "30", "300"),
+    ("1,1,2,15,3,15,10,abc", "10", "abc"),
+    ("1,1,2,15,3,15,49,garbage", "49", "garbage"),
+];
+
+const TELEPORT_PORTAL: &str = "1,747,2,14475,3,165,54,242";
+const START_POSITION: &str = "1,31,2,465,3,15,kA2,1,kA3,0,kA8,1,kA4,3,kA11,0";
+
+const OBJECT_WITH_DEFAULTS: &str = "1,1,2,15,3,45,36,1,21,0,4,0,25,0,32,1,6,0";
+
+const OBJECT_WITH_UNNORMALIZED_VALUES: &str = "1,1,2,15.0,3,15.50,21,03,57,3.012";
+
+const OBJECT: &str = "1,1,2,15,3,45,4,1,6,90,20,2,21,5,22,6,24,7,25,-2,32,1.5,41,1,43,60a0.5a1a1a0,57,3.12.999,64,1,108,4,155,1,36,1";
+
+#[test]
+fn deserialize_level_object() {
+    let object = LevelObject::from_robtop_str(OBJECT).unwrap();
+
+    assert_eq!(object.id, 1);
+    assert_eq!((object.x, object.y), (15.0, 45.0));
+    assert!(object.flipped_x);
+    assert!(!object.flipped_y);
+    assert_eq!(object.rotation, 90.0);
+    assert_eq!(object.editor_layer, 2);
+    assert_eq!(object.main_color, 5);
+    assert_eq!(object.detail_color, 6);
+    assert_eq!(object.z_layer, Some(ZLayer::T2));
+    assert_eq!(object.z_order, -2);
+    assert_eq!(object.scale, 1.5);
+    assert!(object.main_hsv_enabled);
+    assert_eq!(
+        object.main_hsv,
+        Some(Hsv {
+            hue: 60,
+            saturation: 0.5,
+            value: 1.0,
+            saturation_additive: true,
+            value_additive: false
+        })
+    );
+    assert_eq!(object.detail_hsv, None);
+    assert_eq!(object.groups, vec![3, 12, 999]);
+    assert!(object.dont_fade);
+    assert!(!object.dont_enter);
+    assert_eq!(object.linked_group, Some(4));
+    assert_eq!(object.metadata, ObjectData::Unknown);
+    assert_eq!(object.others, vec![("155".to_owned(), "1".to_owned()), ("36".to_owned(), "1".to_owned())]);
+}
+
+#[test]
+fn serialize_level_object() {
+    let object = LevelObject::from_robtop_str(OBJECT).unwrap();
+
+    assert_eq!(
+        object.to_robtop_string().unwrap(),
+        "1,1,2,15,3,45,4,1,6,90,20,2,21,5,22,6,24,7,25,-2,32,1.5,41,1,43,60a0.5a1a1a0,57,3.12.999,64,1,108,4,155,1,36,1"
+    );
+}
+
+#[test]
+fn level_object_keeps_defaults_and_key_order() {
+    let mut object = LevelObject::from_robtop_str(OBJECT_WITH_DEFAULTS).unwrap();
+
+    assert_eq!(object.to_robtop_string().unwrap(), OBJECT_WITH_DEFAULTS);
+
+    object.main_color = 3;
+    object.flipped_y = true;
+
+    assert_eq!(object.to_robtop_string().unwrap(), "1,1,2,15,3,45,36,1,21,3,4,0,25,0,32,1,6,0,5,1");
+}
+
+#[test]
+fn level_object_keeps_unnormalized_values() {
+    let mut object = LevelObject::from_robtop_str(OBJECT_WITH_UNNORMALIZED_VALUES).unwrap();
+
+    assert_eq!(object.to_robtop_string().unwrap(), OBJECT_WITH_UNNORMALIZED_VALUES);
+
+    object.x = 30.0;
+    object.groups.push(4);
+
+    assert_eq!(object.to_robtop_string().unwrap(), "1,1,2,30,3,15.50,21,03,57,3.12.4");
+}
+
+#[test]
+fn serialize_default_level_object() {
+    let object = LevelObject {
+        id: 8,
+        x: 105.0,
+        y: 15.5,
+        ..LevelObject::default()
+    };
+
+    assert_eq!(object.to_robtop_string().unwrap(), "1,8,2,105,3,15.5");
+}
+
+#[test]
+fn dark_realm_objects_roundtrip() {
+    let objects = Objects::from_unprocessed(DARK_REALM_DATA.trim()).unwrap();
+    let reencoded = objects.as_unprocessed().unwrap();
+
+    assert!(
+        decompress(&reencoded) == decompress(DARK_REALM_DATA.trim()),
+        "re-encoded level string differs from the original"
+    );
+    assert_eq!(Objects::from_unprocessed(&reencoded).unwrap(), objects);
+    assert!(objects.objects.iter().any(|object| !object.groups.is_empty()));
+}
+
+#[test]
+fn deserialize_move_trigger() {
+    let object = LevelObject::from_robtop_str(MOVE_TRIGGER).unwrap();
+
+    assert_eq!(
+        object.metadata,
+        ObjectData::Trigger {
+            activation: TriggerActivation {
+                touch_triggered: false,
+                spawn_triggered: true,
+                multi_trigger: false
+            },
+            trigger: Trigger::Move {
+                target_group: 7,
+                duration: 0.5,
+                easing: Easing::EaseIn,
+                easing_rate: 2.0,
+                move_x: 30.0,
+                move_y: -60.0,
+                lock_to_player_x: false,
+                lock_to_player_y: false
+            }
+        }
+    );
+    assert!(object.others.is_empty());
+}
+
+#[test]
+fn deserialize_pulse_trigger() {
+    let object = LevelObject::from_robtop_str(PULSE_TRIGGER).unwrap();
+
+    match object.metadata {
+        ObjectData::Trigger { activation, trigger } => {
+            assert!(activation.multi_trigger);
+
+            match trigger {
+                Trigger::Pulse {
+                    target, hold, use_hsv, hsv, ..
+                } => {
+                    assert_eq!(target, PulseTarget::Group(3));
+                    assert_eq!(hold, 0.25);
+                    assert!(use_hsv);
+                    assert_eq!(hsv.map(|hsv| hsv.hue), Some(180));
+                },
+                trigger => panic!("expected pulse trigger, got {:?}", trigger),
+            }
+        },
+        metadata => panic!("expected trigger, got {:?}", metadata),
+    }
+}
+
+#[test]
+fn trigger_roundtrip() {
+    for input in &[MOVE_TRIGGER, PULSE_TRIGGER, LEGACY_COLOR_TRIGGER] {
+        assert_eq!(LevelObject::from_robtop_str(input).unwrap().to_robtop_string().unwrap(), *input);
+    }
+}
+
+#[test]
+fn trigger_keeps_defaults_and_key_order() {
+    for input in &[
+        COLOR_TRIGGER_WITH_DEFAULTS,
+        MOVE_TRIGGER_WITH_DEFAULTS,
+        ALPHA_TRIGGER_WITH_DEFAULTS,
+        PULSE_TRIGGER_UNORDERED,
+    ] {
+        assert_eq!(LevelObject::from_robtop_str(input).unwrap().to_robtop_string().unwrap(), *input);
+    }
+}
+
+#[test]
+fn trigger_keeps_unused_keys() {
+    let object = LevelObject::from_robtop_str(MOVE_TRIGGER_WITH_UNUSED_KEYS).unwrap();
+
+    assert_eq!(object.others, vec![("7".to_owned(), "255".to_owned()), ("35".to_owned(), "0.5".to_owned())]);
+    assert_eq!(object.to_robtop_string().unwrap(), MOVE_TRIGGER_WITH_UNUSED_KEYS);
+}
+
+#[test]
+fn modified_trigger_keeps_key_order() {
+    let mut object = LevelObject::from_robtop_str(MOVE_TRIGGER_WITH_DEFAULTS).unwrap();
+
+    match object.metadata {
+        ObjectData::Trigger {
+            trigger: Trigger::Move {
+                ref mut easing,
+                ref mut move_y,
+                ..
+            },
+            ..
+        } => {
+            *easing = Easing::EaseIn;
+            *move_y = 0.0;
+        },
+        ref metadata => panic!("expected move trigger, got {:?}", metadata),
+    }
+
+    assert_eq!(object.to_robtop_string().unwrap(), "1,901,2,15,3,15,7,0,10,0,28,0,30,2,35,1,51,4");
+}
+
+#[test]
+fn legacy_color_trigger_keeps_keys() {
+    let object = LevelObject::from_robtop_str(LEGACY_COLOR_TRIGGER).unwrap();
+
+    assert_eq!(object.metadata, ObjectData::Unknown);
+    assert_eq!(object.others.len(), 4);
+    assert_eq!(object.others[3], ("10".to_owned(), "0.5".to_owned()));
+}
+
+#[test]
+fn non_trigger_keeps_trigger_keys() {
+    for &(input, key, value) in &OBJECTS_WITH_TRIGGER_KEYS {
+        let object = LevelObject::from_robtop_str(input).unwrap();
+
+        assert_eq!(object.metadata, ObjectData::Unknown);
+        assert_eq!(object.others, vec![(key.to_owned(), value.to_owned())]);
+        assert_eq!(object.to_robtop_string().unwrap(), input);
+    }
+}
+
+#[test]
+fn deserialize_teleport_portal() {
+    let object = LevelObject::from_robtop_str(TELEPORT_PORTAL).unwrap();
+
+    assert_eq!(
+        object.metadata,
+        ObjectData::TeleportPortal {
+            checked: false,
+            y_offset: 242.0
+        }
+    );
+    assert!(object.others.is_empty());
+}
+
+#[test]
+fn deserialize_start_position() {
+    let object = LevelObject::from_robtop_str(START_POSITION).unwrap();
+
+    assert_eq!(
+        object.metadata,
+        ObjectData::StartPosition(StartPosition {
+            speed: Speed::Fast,
+            game_mode: GameMode::Ship,
+            mini: false,
+            dual: true,
+            gravity_inverted: false
+        })
+    );
+    assert!(object.others.is_empty());
+}
+
+#[test]
+fn gameplay_object_roundtrip() {
+    for input in &[TELEPORT_PORTAL, START_POSITION] {
+        assert_eq!(LevelObject::from_robtop_str(input).unwrap().to_robtop_string().unwrap(), *input);
+    }
+}
+
+#[test]
+fn dark_realm_gameplay_objects() {
+    let objects = Objects::from_unprocessed(DARK_REALM_DATA.trim()).unwrap();
+    let count = |predicate: fn(&ObjectData) -> bool| objects.objects.iter().filter(|object| predicate(&object.metadata)).count();
+
+    assert_eq!(count(|data| *data == ObjectData::Orb(Orb::Yellow)), 12);
+    assert_eq!(count(|data| matches!(data, ObjectData::GravityPortal { inverted: true, .. })), 22);
+    assert_eq!(count(|data| matches!(data, ObjectData::TeleportPortal { .. })), 3);
+    assert_eq!(count(|data| matches!(data, ObjectData::Coin(_))), 3);
+}
+
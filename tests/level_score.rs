@@ -0,0 +1,31 @@
+use dash_rs::model::{
+    level::score::LevelScore,
+    user::{Color, IconType},
+};
+use std::borrow::Cow;
+
+#[macro_use]
+mod helper;
+
+const LEVEL_SCORE_DATA: &str = "1:Michigun:2:703929:9:22:10:15:11:12:14:0:15:2:16:34499:3:100:6:1:42:3 years:13:3";
+const LEVEL_SCORE: LevelScore = LevelScore {
+    name: Cow::Borrowed("Michigun"),
+    user_id: 703929,
+    score: 100,
+    rank: 1,
+    icon_index: 22,
+    primary_color: Color::Known(0, 0, 0),
+    secondary_color: Color::Known(255, 255, 255),
+    coins: 3,
+    icon_type: IconType::Cube,
+    has_glow: true,
+    account_id: 34499,
+    age: Cow::Borrowed("3 years"),
+};
+
+impl helper::ThunkProcessor for LevelScore<'_> {
+    fn process_all_thunks(&mut self) {}
+}
+
+save_load_roundtrip!(LevelScore, LEVEL_SCORE);
+load_save_roundtrip!(LevelScore, LEVEL_SCORE_DATA, LEVEL_SCORE, ":", true);
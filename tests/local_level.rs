@@ -0,0 +1,299 @@
+use std::{borrow::Cow, convert::TryFrom};
+
+use dash_rs::{
+    model::level::{
+        color::{PlayerColor, RgbColor},
+        local_level::{Guideline, GuidelineColor, LevelMetadata, LocalLevel, Objects},
+        object::{game_mode::GameMode, speed::Speed},
+        online_level::Level,
+        LevelLength, Password,
+    },
+    save::{gmd, Plist, Value},
+    HasRobtopFormat, Thunk, ThunkContent,
+};
+
+const DARK_REALM_DATA: &str = include_str!("data/11774780_dark_realm_raw_level_data");
+
+const METADATA: &str = "kS38,1_32_2_0_3_14_4_-1_6_1000_7_1_15_0_8_1|1_17_2_0_3_7_4_-1_6_1001_7_1_15_0_8_1|,kA13,1.5,kA15,0,kA16,1,kA14,0.5~0.8~1.25~0.9~3~1~4~0.5~,\
+                        kA6,12,kA7,8,kA17,1,kA18,3,kS39,3,kA2,4,kA3,1,kA8,0,kA4,1,kA9,0,kA10,1,kA11,0,kA22,1,kA99,unknown";
+
+const LEGACY_METADATA: &str = "kS1,0,kS2,102,kS3,255,kA2,0,kA4,0";
+
+const UNNORMALIZED_METADATA: &str = "kA13,0.50,kA15,2,kA16,10,kA2,0";
+
+/// The entries of the "Dark Realm" level dictionary, as they appear in the save file
+fn dark_realm_entries() -> String {
+    format!(
+        "<k>kCEK</k><i>4</i><k>k2</k><s>Dark Realm</s><k>k3</k><s>VGhlIGRhcmtlc3Qgb2YgcmVhbG1z</s><k>k4</k><s>{}</s><k>k5</k><s>Ryder</\
+         s><k>k6</k><i>8451</i><k>k8</k><i>3</i><k>k14</k><t /><k>k16</k><i>4</i><k>k18</k><i>1337</i><k>k23</k><i>3</i><k>k41</k><i>1</\
+         i><k>k64</k><i>2</i><k>k80</k><i>3600</i><k>k999</k><s>preserved</s>",
+        DARK_REALM_DATA.trim()
+    )
+}
+
+fn local_levels_xml() -> String {
+    format!(
+        "<?xml version=\"1.0\"?><plist version=\"1.0\" gjver=\"2.0\"><dict><k>LLM_01</k><d><k>_isArr</k><t /><k>k_0</k><d>{}</d></d></dict></plist>",
+        dark_realm_entries()
+    )
+}
+
+#[test]
+fn parse_local_level() {
+    let xml = local_levels_xml();
+    let plist = Plist::parse(&xml).unwrap();
+    let dict = plist.root.get("LLM_01").and_then(Value::as_dict).and_then(|levels| levels.get("k_0")).and_then(Value::as_dict).unwrap();
+
+    let level = LocalLevel::from_dictionary(dict).unwrap();
+
+    assert_eq!(level.level_id, None);
+    assert_eq!(level.name, "Dark Realm");
+    assert_eq!(level.creator_name, "Ryder");
+    assert_eq!(level.creator_id, 8451);
+    assert_eq!(level.main_song.map(|song| song.main_song_id), Some(3));
+    assert_eq!(level.custom_song, None);
+    assert!(level.verified);
+    assert!(!level.uploaded);
+    assert_eq!(level.version, 4);
+    assert_eq!(level.attempts, 1337);
+    assert_eq!(level.length, LevelLength::Long);
+    assert_eq!(level.password, Password::FreeCopy);
+    assert_eq!(level.coin_amount, 2);
+    assert_eq!(level.editor_time, Some(3600));
+    assert_eq!(level.others.get("kCEK").and_then(Value::as_i64), Some(4));
+    assert_eq!(level.others.get("k999").and_then(Value::as_str), Some("preserved"));
+
+    let mut description = level.description.unwrap();
+    assert_eq!(description.process().unwrap().0, "The darkest of realms");
+
+    let mut level_data = level.level_data.unwrap();
+    assert!(!level_data.process().unwrap().objects.is_empty());
+}
+
+#[test]
+fn local_level_dictionary_roundtrip() {
+    let xml = local_levels_xml();
+    let plist = Plist::parse(&xml).unwrap();
+    let dict = plist.root.get("LLM_01").and_then(Value::as_dict).and_then(|levels| levels.get("k_0")).and_then(Value::as_dict).unwrap();
+
+    let level = LocalLevel::from_dictionary(dict).unwrap();
+    let written = level.to_dictionary().unwrap();
+
+    assert_eq!(&written, dict);
+}
+
+#[test]
+fn local_level_dictionary_keeps_present_keys() {
+    let plist = Plist::parse("<plist><dict><k>kCEK</k><i>4</i><k>k2</k><s>Song Test</s><k>k8</k><i>5</i><k>k45</k><i>12345</i><k>k50</k><i>35</i></dict></plist>").unwrap();
+    let level = LocalLevel::from_dictionary(&plist.root).unwrap();
+
+    assert_eq!(level.main_song, None);
+    assert_eq!(level.custom_song, Some(12345));
+    assert_eq!(level.others.get("k8").and_then(Value::as_i64), Some(5));
+
+    let written = level.to_dictionary().unwrap();
+
+    assert_eq!(written, plist.root);
+    assert_eq!(written.iter().map(|(key, _)| key).collect::<Vec<_>>(), vec!["kCEK", "k2", "k8", "k45", "k50"]);
+}
+
+#[test]
+fn local_level_dictionary_modified() {
+    let plist = Plist::parse("<plist><dict><k>kCEK</k><i>4</i><k>k1</k><i>0</i><k>k2</k><s>Test</s><k>k18</k><i>3</i><k>k50</k><i>35</i></dict></plist>").unwrap();
+    let mut level = LocalLevel::from_dictionary(&plist.root).unwrap();
+
+    level.name = "Renamed".into();
+    level.level_id = Some(128);
+    level.attempts = 0;
+    level.verified = true;
+
+    let written = level.to_dictionary().unwrap();
+
+    assert_eq!(
+        written.iter().map(|(key, value)| (key, value.clone())).collect::<Vec<_>>(),
+        vec![
+            ("kCEK", Value::from(4)),
+            ("k1", Value::from(128)),
+            ("k2", Value::from("Renamed")),
+            ("k18", Value::from(0)),
+            ("k50", Value::from(35)),
+            ("k14", Value::True),
+        ]
+    );
+}
+
+#[test]
+fn local_level_missing_name() {
+    let plist = Plist::parse("<plist><dict><k>k4</k><s></s></dict></plist>").unwrap();
+
+    assert!(LocalLevel::from_dictionary(&plist.root).is_err());
+}
+
+#[test]
+fn local_level_to_level_and_back() {
+    let xml = local_levels_xml();
+    let plist = Plist::parse(&xml).unwrap();
+    let dict = plist.root.get("LLM_01").and_then(Value::as_dict).and_then(|levels| levels.get("k_0")).and_then(Value::as_dict).unwrap();
+
+    let level = Level::try_from(LocalLevel::from_dictionary(dict).unwrap()).unwrap();
+
+    assert_eq!(level.name, "Dark Realm");
+    assert_eq!(level.creator, 8451);
+    assert_eq!(level.coin_amount, 2);
+
+    let level_data = level.level_data.as_ref().unwrap();
+    assert_eq!(level_data.password, Password::FreeCopy);
+    assert!(matches!(level_data.level_data, Thunk::Unprocessed(_)));
+
+    let local = LocalLevel::from(level);
+
+    assert_eq!(local.name, "Dark Realm");
+    assert_eq!(local.password, Password::FreeCopy);
+    assert_eq!(local.editor_time, Some(3600));
+    assert!(local.level_data.is_some());
+}
+
+#[test]
+fn level_into_owned_keeps_objects_unprocessed() {
+    let xml = local_levels_xml();
+    let plist = Plist::parse(&xml).unwrap();
+    let dict = plist.root.get("LLM_01").and_then(Value::as_dict).and_then(|levels| levels.get("k_0")).and_then(Value::as_dict).unwrap();
+
+    let mut level = Level::try_from(LocalLevel::from_dictionary(dict).unwrap()).unwrap().into_owned();
+    let level_data = level.level_data.as_mut().unwrap();
+
+    assert!(matches!(level_data.level_data, Thunk::Unprocessed(Cow::Owned(_))));
+    assert!(!level_data.level_data.process().unwrap().objects.is_empty());
+}
+
+#[test]
+fn local_level_version_out_of_range() {
+    let plist = Plist::parse("<plist><dict><k>k2</k><s>Test</s><k>k16</k><i>300</i></dict></plist>").unwrap();
+
+    assert!(Level::try_from(LocalLevel::from_dictionary(&plist.root).unwrap()).is_err());
+}
+
+#[test]
+fn local_level_gmd_roundtrip() {
+    let xml = local_levels_xml();
+    let plist = Plist::parse(&xml).unwrap();
+    let dict = plist.root.get("LLM_01").and_then(Value::as_dict).and_then(|levels| levels.get("k_0")).and_then(Value::as_dict).unwrap();
+
+    let level = LocalLevel::from_dictionary(dict).unwrap();
+    let gmd_xml = gmd::to_xml(&level).unwrap();
+
+    assert_eq!(gmd_xml, format!("{}<dict>{}</dict>{}", gmd::GMD_HEADER, dark_realm_entries(), gmd::GMD_FOOTER));
+
+    let gmd_plist = gmd::parse(&gmd_xml).unwrap();
+
+    assert_eq!(gmd::parse_level(&gmd_plist).unwrap(), level);
+}
+
+#[test]
+fn parse_level_metadata() {
+    let meta = LevelMetadata::from_robtop_str(METADATA).unwrap();
+
+    assert_eq!(meta.color_channels.len(), 2);
+    assert_eq!(meta.color_channels[0].index, 1000);
+    assert_eq!(meta.color_channels[0].color, RgbColor { red: 32, green: 0, blue: 14 });
+    assert_eq!(meta.color_channels[1].index, 1001);
+    assert_eq!(meta.color_channels[1].player_color, PlayerColor::None);
+    assert_eq!(meta.song_offset, 1.5);
+    assert!(!meta.song_fade_in);
+    assert!(meta.song_fade_out);
+    assert_eq!(meta.guidelines, vec![
+        Guideline {
+            time: 0.5,
+            color: GuidelineColor::Orange
+        },
+        Guideline {
+            time: 1.25,
+            color: GuidelineColor::Yellow
+        },
+        Guideline {
+            time: 3.0,
+            color: GuidelineColor::Green
+        },
+        Guideline {
+            time: 4.0,
+            color: GuidelineColor::Unknown(0.5)
+        },
+    ]);
+    assert_eq!(meta.background_texture, 12);
+    assert_eq!(meta.ground_texture, 8);
+    assert_eq!(meta.ground_line, 1);
+    assert_eq!(meta.font, 3);
+    assert_eq!(meta.color_page, 3);
+    assert_eq!(meta.starting_game_mode, GameMode::Wave);
+    assert!(meta.start_mini);
+    assert_eq!(meta.starting_speed, Speed::Normal);
+    assert!(meta.two_player_controls);
+    assert_eq!(meta.others, vec![("kA22".to_owned(), "1".to_owned()), ("kA99".to_owned(), "unknown".to_owned())]);
+}
+
+#[test]
+fn level_metadata_roundtrip() {
+    let meta = LevelMetadata::from_robtop_str(METADATA).unwrap();
+
+    assert_eq!(meta.to_robtop_string().unwrap(), METADATA);
+}
+
+#[test]
+fn level_metadata_keeps_present_keys_only() {
+    let mut meta = LevelMetadata::from_robtop_str(LEGACY_METADATA).unwrap();
+
+    assert_eq!(meta.to_robtop_string().unwrap(), LEGACY_METADATA);
+
+    meta.starting_speed = Speed::Fast;
+    meta.font = 2;
+
+    assert_eq!(meta.to_robtop_string().unwrap(), "kS1,0,kS2,102,kS3,255,kA2,0,kA4,3,kA18,2");
+}
+
+#[test]
+fn level_metadata_keeps_unnormalized_values() {
+    let mut meta = LevelMetadata::from_robtop_str(UNNORMALIZED_METADATA).unwrap();
+
+    assert_eq!(meta.song_offset, 0.5);
+    assert!(meta.song_fade_in);
+    assert!(meta.song_fade_out);
+    assert_eq!(meta.to_robtop_string().unwrap(), UNNORMALIZED_METADATA);
+
+    meta.song_offset = 1.25;
+    meta.song_fade_out = false;
+
+    assert_eq!(meta.to_robtop_string().unwrap(), "kA13,1.25,kA15,2,kA16,0,kA2,0");
+}
+
+#[test]
+fn serialize_default_level_metadata() {
+    assert_eq!(
+        LevelMetadata::default().to_robtop_string().unwrap(),
+        "kA13,0,kA15,0,kA16,0,kA14,,kA6,0,kA7,0,kA17,0,kA18,0,kS39,0,kA2,0,kA3,0,kA8,0,kA4,1,kA9,0,kA10,0,kA11,0"
+    );
+}
+
+#[test]
+fn empty_level_metadata() {
+    let meta = LevelMetadata::from_robtop_str("").unwrap();
+
+    assert_eq!(meta, LevelMetadata {
+        source: Some(String::new()),
+        ..LevelMetadata::default()
+    });
+    assert_eq!(meta.to_robtop_string().unwrap(), "");
+    assert!(LevelMetadata::from_robtop_str("kA14,1~").is_err());
+}
+
+#[test]
+fn dark_realm_metadata() {
+    let objects = Objects::from_unprocessed(DARK_REALM_DATA.trim()).unwrap();
+
+    assert_eq!(objects.meta.color_channels.len(), 31);
+    assert!(objects.meta.color_channels.iter().any(|channel| channel.index == 1 && channel.hsv.is_some()));
+    assert_eq!(objects.meta.background_texture, 12);
+    assert_eq!(objects.meta.ground_texture, 8);
+    assert_eq!(objects.meta.font, 3);
+    assert!(objects.meta.guidelines.is_empty());
+    assert!(objects.meta.others.is_empty());
+}
@@ -0,0 +1,78 @@
+use dash_rs::{
+    model::message::{Message, MessageBody, MessageFolder},
+    Base64Decoded, HasRobtopFormat, Thunk,
+};
+use std::borrow::Cow;
+
+#[macro_use]
+mod helper;
+
+const MESSAGE_DATA: &str = "6:Michigun:3:703929:2:34499:1:64297155:4:WW91ciBsZXZlbA==:5:dltdURFdQVFeEUZdRl0RRVxXFV1UQldZEA==:8:1:9:0:7:2 days";
+const MESSAGE: Message = Message {
+    message_id: 64297155,
+    account_id: 34499,
+    user_id: 703929,
+    subject: Thunk::Processed(Base64Decoded(Cow::Borrowed("Your level"))),
+    body: Some(Thunk::Processed(MessageBody(Cow::Borrowed("Good luck with the level!")))),
+    user_name: Cow::Borrowed("Michigun"),
+    age: Cow::Borrowed("2 days"),
+    is_read: true,
+    folder: MessageFolder::Inbox,
+};
+
+impl helper::ThunkProcessor for Message<'_> {
+    fn process_all_thunks(&mut self) {
+        self.subject.process().unwrap();
+
+        if let Some(ref mut body) = self.body {
+            body.process().unwrap();
+        }
+    }
+}
+
+save_load_roundtrip!(Message, MESSAGE);
+load_save_roundtrip!(Message, MESSAGE_DATA, MESSAGE, ":", true);
+
+#[test]
+fn listed_message_without_body() {
+    let message = Message::from_robtop_str("6:Michigun:3:703929:2:34499:1:64297155:4:WW91ciBsZXZlbA==:8:0:9:1:7:2 days").unwrap();
+
+    assert_eq!(message.body, None);
+    assert!(!message.is_read);
+    assert_eq!(message.folder, MessageFolder::Sent);
+}
+
+#[test]
+fn body_is_processed_lazily() {
+    let mut message = Message::from_robtop_str(MESSAGE_DATA).unwrap();
+
+    assert_eq!(message.body, Some(Thunk::Unprocessed(Cow::Borrowed("dltdURFdQVFeEUZdRl0RRVxXFV1UQldZEA=="))));
+    assert_eq!(
+        message.body.as_mut().unwrap().process().unwrap(),
+        &MessageBody(Cow::Borrowed("Good luck with the level!"))
+    );
+}
+
+#[test]
+fn into_owned_keeps_body_unprocessed() {
+    let mut message = Message::from_robtop_str(MESSAGE_DATA).unwrap().into_owned();
+
+    assert_eq!(message.body, Some(Thunk::Unprocessed(Cow::Owned("dltdURFdQVFeEUZdRl0RRVxXFV1UQldZEA==".to_string()))));
+    assert_eq!(
+        message.body.as_mut().unwrap().process().unwrap(),
+        &MessageBody(Cow::Borrowed("Good luck with the level!"))
+    );
+    assert_eq!(message.to_robtop_string().unwrap(), Message::from_robtop_str(MESSAGE_DATA).unwrap().to_robtop_string().unwrap());
+}
+
+#[test]
+fn write_unprocessed_body() {
+    let message = Message {
+        body: Some(Thunk::Unprocessed(Cow::Borrowed("dltdURFdQVFeEUZdRl0RRVxXFV1UQldZEA=="))),
+        ..MESSAGE
+    };
+
+    let written = message.to_robtop_string().unwrap();
+
+    assert!(written.contains(":5:dltdURFdQVFeEUZdRl0RRVxXFV1UQldZEA==:"), "{}", written);
+}
@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 use dash_rs::request::account::{AuthenticatedUser, LoginRequest};
-use dash_rs::request::{CONTENT_TYPE, URL_FORM_ENCODED};
+use dash_rs::request::{Endpoint, CONTENT_TYPE, URL_FORM_ENCODED};
 use dash_rs::request::moderator::{SuggestedFeatureScore, SuggestedStars, SuggestStarsRequest};
 
 #[tokio::test]
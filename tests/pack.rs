@@ -0,0 +1,66 @@
+use dash_rs::{
+    model::{
+        level::{color::RgbColor, DemonRating, LevelRating},
+        pack::{Gauntlet, MapPack},
+    },
+    HasRobtopFormat,
+};
+use std::borrow::Cow;
+
+const DEMON_PACK_DATA: &str = "1:6:2:Demon Map Pack:3:5001,5002,5003:4:10:5:2:6:6:7:255,0,0:8:255,0,0";
+
+fn demon_pack() -> MapPack<'static> {
+    MapPack {
+        pack_id: 6,
+        name: Cow::Borrowed("Demon Map Pack"),
+        levels: vec![5001, 5002, 5003],
+        stars: 10,
+        coins: 2,
+        difficulty: LevelRating::Demon(DemonRating::Hard),
+        text_color: RgbColor { red: 255, green: 0, blue: 0 },
+        bar_color: RgbColor { red: 255, green: 0, blue: 0 },
+    }
+}
+
+const FIRE_GAUNTLET_DATA: &str = "1:1:3:27732941,28200611,27483789,28225110,27448202";
+
+fn fire_gauntlet() -> Gauntlet {
+    Gauntlet {
+        gauntlet_id: 1,
+        levels: vec![27732941, 28200611, 27483789, 28225110, 27448202],
+    }
+}
+
+#[test]
+fn load_save_roundtrip_map_pack() {
+    let loaded = MapPack::from_robtop_str(DEMON_PACK_DATA).unwrap();
+
+    assert_eq!(loaded, demon_pack());
+    assert_eq!(loaded.to_robtop_string().unwrap(), DEMON_PACK_DATA);
+}
+
+#[test]
+fn load_save_roundtrip_gauntlet() {
+    let loaded = Gauntlet::from_robtop_str(FIRE_GAUNTLET_DATA).unwrap();
+
+    assert_eq!(loaded, fire_gauntlet());
+    assert_eq!(loaded.name(), Some("Fire"));
+    assert_eq!(loaded.to_robtop_string().unwrap(), FIRE_GAUNTLET_DATA);
+}
+
+#[test]
+fn map_pack_difficulties() {
+    let pack = MapPack::from_robtop_str("1:1:2:Easy Map Pack:3:1,2,3:4:4:5:2:6:1:7:255,255,0:8:255,255,0").unwrap();
+
+    assert_eq!(pack.difficulty, LevelRating::Easy);
+    assert_eq!(pack.text_color, RgbColor { red: 255, green: 255, blue: 0 });
+
+    let pack = MapPack::from_robtop_str("1:2:2:Extreme Pack:3:1:4:20:5:2:6:10:7:0,0,0:8:0,0,0").unwrap();
+
+    assert_eq!(pack.difficulty, LevelRating::Demon(DemonRating::Extreme));
+}
+
+#[test]
+fn map_pack_malformed_color() {
+    assert!(MapPack::from_robtop_str("1:6:2:Pack:3:1:4:1:5:0:6:1:7:255,0:8:0,0,0").is_err());
+}
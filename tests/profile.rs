@@ -57,6 +57,7 @@
 use std::borrow::Cow;
 use dash_rs::model::user::ModLevel;
 use dash_rs::request::account::{AuthenticatedUser, LoginRequest};
+use dash_rs::request::Endpoint;
 use dash_rs::request::user::UserRequest;
 use dash_rs::response::parse_get_gj_user_info_response;
 
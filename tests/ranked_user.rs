@@ -0,0 +1,31 @@
+use dash_rs::model::user::{ranked::RankedUser, Color, IconType};
+use std::borrow::Cow;
+
+#[macro_use]
+mod helper;
+
+const RANKED_MICHIGUN_DATA: &str = "1:Michigun:2:703929:13:149:17:12312:6:52:9:22:10:15:11:12:14:0:15:2:16:34499:3:61161:8:16:46:11522:4:997";
+const RANKED_MICHIGUN: RankedUser = RankedUser {
+    name: Cow::Borrowed("Michigun"),
+    user_id: 703929,
+    stars: 61161,
+    demons: 997,
+    rank: 52,
+    creator_points: 16,
+    icon_index: 22,
+    primary_color: Color::Known(0, 0, 0),
+    secondary_color: Color::Known(255, 255, 255),
+    secret_coins: 149,
+    icon_type: IconType::Cube,
+    has_glow: true,
+    account_id: 34499,
+    user_coins: 12312,
+    diamonds: 11522,
+};
+
+impl helper::ThunkProcessor for RankedUser<'_> {
+    fn process_all_thunks(&mut self) {}
+}
+
+save_load_roundtrip!(RankedUser, RANKED_MICHIGUN);
+load_save_roundtrip!(RankedUser, RANKED_MICHIGUN_DATA, RANKED_MICHIGUN, ":", true);
@@ -1,3 +1,9 @@
+use dash_rs::{
+    model::{level::color::RgbColor, message::MessageFolder, user::IconType},
+    request::account::{AuthenticationError, LoginResponse, RegistrationError},
+    response::ResponseError,
+};
+
 // This string has been manually edited to ensure that the cases of duplicate and missing
 // songs/creators are also covered by the unit test
 const GET_GJ_LEVELS_RESPONSE: &str = "1:62953227:2:Noice:5:1:6:14098234:8:10:9:30:10:329795:12:0:13:21:14:16024:17::43:0:25::18:5:19:24981:42:1:45:30320:3:Tm9pY2UgbGV2ZWwsIGhvcGUgeW91IGxpa2UgaXQ=:15:3:30:0:31:0:37:0:38:0:39:5:46:1:47:2:35:778510|1:63362544:2:Happy Day Gd:5:4:6:14098234:8:10:9:30:10:16162:12:0:13:21:14:2772:17::43:0:25::18:4:19:24979:42:0:45:38476:3:NyB5ZWFycyBvZiBqb3lzIGFuZCBzb3Jyb3dzLCB0aGUgYmVzdCBnYW1lIEkga25ldyBhbmQgSSB3aWxsIHN0YXksIGNvbW1lbnQgaG93IG1hbnkgeWVhcnMgeW91IGhhdmUgYmVlbiBhbmQgd2hhdCBtb3RpdmF0ZWQgeW91IHRvIHN0YXk=:15:3:30:0:31:0:37:3:38:1:39:4:46:1:47:2:35:936243|1:63336521:2:Sound Visualization:5:1:6:4123296:8:10:9:50:10:47521:12:0:13:21:14:2951:17::43:6:25::18:8:19:24979:42:1:45:26229:3:dmlzdWFsIGVmZmVjdHM=:15:3:30:0:31:0:37:3:38:1:39:8:46:1:47:2:35:778510|1:63335504:2:Mind Control:5:2:6:10130943:8:10:9:30:10:51434:12:0:13:21:14:4120:17::43:0:25::18:5:19:24979:42:1:45:49951:3:SXQncyBkb25lIHlhYWF5:15:3:30:0:31:0:37:2:38:1:39:5:46:1:47:2:35:763439|1:63333766:2:An Ode to Time:5:8:6:7226087:8:10:9:10:10:10795:12:0:13:21:14:716:17:1:43:3:25::18:10:19:24979:42:0:45:65535:3:QW5kIGl0IGFsbCBjb21lcyBjcmFzaGluZyBkb3duLiAwOC8xMC8yMC4=:15:4:30:0:31:0:37:3:38:1:39:10:46:1:47:2:35:896364|1:63292359:2:AnnoZone:5:2:6:5897998:8:10:9:50:10:7890:12:0:13:21:14:636:17::43:6:25::18:8:19:24979:42:1:45:51592:3:VGhlIEFubm8gU2VyaWVzIGhhcyByZXR1cm5lZCBhZnRlciAyIHllYXJzIHdpdGggYSAzcmQgbGV2ZWwhIERlZGljYXRlZCB0byB0aGUgQnJveXMuIE1vcmUgQW5ubyBTZXJpZXMgbGV2ZWxzIHRvIGNvbWUuLi4_:15:3:30:0:31:0:37:0:38:1:39:7:46:1:47:2:35:638150|1:63260507:2:Trouble:5:3:6:14221993:8:10:9:50:10:4512:12:0:13:21:14:323:17::43:6:25::18:8:19:24979:42:1:45:58854:3:SSBzaG91bGQgb2Yga25vd24geW91IHdlcmUgdHJvdWJsZS4uLi4uLiAgICAgICAgICAgICBoaQ==:15:3:30:0:31:0:37:1:38:1:39:8:46:1:47:2:35:939885|1:63254272:2:AdrenaLines:5:2:6:116033399:8:10:9:40:10:34848:12:0:13:21:14:2419:17::43:5:25::18:6:19:24979:42:0:45:32956:3:ZGVjbyBsdmw_ISBlbmpveSB1d3U=:15:3:30:0:31:0:37:2:38:1:39:6:46:1:47:2:35:887253|1:63232525:2:Metropolis:5:3:6:1647052:8:10:9:10:10:99385:12:0:13:21:14:5506:17:1:43:3:25::18:10:19:24979:42:1:45:65535:3:V2VsY29tZS4uLg==:15:3:30:0:31:0:37:1:38:1:39:10:46:1:47:2:35:674039|1:61865319:2:Utopia:5:4:6:5570844:8:10:9:50:10:148912:12:0:13:21:14:6961:17::43:6:25::18:8:19:24979:42:0:45:13533:3:dXBkYXRlZCwgc2Vjb25kIHBhcnQgZG9lc250IGxvb2sgc28gdWdseSBub3c=:15:3:30:0:31:0:37:3:38:1:39:8:46:1:47:2:35:761926#1647052:DesTicY:95952|4123296:Cdpre:1478680|5570844:Axils:1341135|7226087:Pauze:1705254|8908442:Nikce:2517174|10130943:FaekI:1727914|14098234:AleXins:4322668|14221993:IFuse:5633975|116033399:KumoriGD:11439344#1~|~638150~|~2~|~-ThunderZone v2-~|~3~|~30~|~4~|~Waterflame~|~5~|~8.78~|~6~|~~|~10~|~http%3A%2F%2Faudio.ngfiles.com%2F638000%2F638150_-ThunderZone-v2-.mp3~|~7~|~UCVuv5iaVR55QXIc_BHQLakA~|~8~|~1~:~1~|~674039~|~2~|~Crystal Tokyo~|~3~|~746~|~4~|~Fantomenk~|~5~|~10.54~|~6~|~~|~10~|~http%3A%2F%2Faudio.ngfiles.com%2F674000%2F674039_Crystal-Tokyo.mp3~|~7~|~UCMSBjXolfz29kxnXpBa7LJA~|~8~|~1~:~1~|~761926~|~2~|~mistmurk + 3MBER - Utopia~|~3~|~49123~|~4~|~mistmurk~|~5~|~7.76~|~6~|~~|~10~|~http%3A%2F%2Faudio.ngfiles.com%2F761000%2F761926_mistmurk--3mber---Utopia.mp3~|~7~|~~|~8~|~1~:~1~|~763439~|~2~|~ColBreakz - Mind Control~|~3~|~47795~|~4~|~ColBreakz~|~5~|~10~|~6~|~~|~10~|~http%3A%2F%2Faudio.ngfiles.com%2F763000%2F763439_ColBreakz---Mind-Control.mp3~|~7~|~~|~8~|~1~:~1~|~778510~|~2~|~Hazmat~|~3~|~23384~|~4~|~CricketSaysChill~|~5~|~1.8~|~6~|~~|~10~|~https%3A%2F%2Faudio.ngfiles.com%2F778000%2F778510_Hazmat.mp3%3Ff1512785304~|~7~|~~|~8~|~1~:~1~|~852209~|~2~|~Fried Sushi~|~3~|~28916~|~4~|~lchavasse~|~5~|~5.88~|~6~|~~|~10~|~https%3A%2F%2Faudio.ngfiles.com%2F852000%2F852209_Fried-Sushi.mp3%3Ff1552100587~|~7~|~~|~8~|~1~:~1~|~887253~|~2~|~Adrenaline~|~3~|~51089~|~4~|~PsoGnar~|~5~|~10.35~|~6~|~~|~10~|~https%3A%2F%2Faudio.ngfiles.com%2F887000%2F887253_Adrenaline.mp3%3Ff1570984144~|~7~|~~|~8~|~1~:~1~|~896364~|~2~|~Beethoven - Moonlight Sonata 3rd Movement (meganeko remix)~|~3~|~48917~|~4~|~meganeko~|~5~|~9.45~|~6~|~~|~10~|~https%3A%2F%2Faudio.ngfiles.com%2F896000%2F896364_Beethoven---Moonlight-Sona.mp3%3Ff1575491260~|~7~|~UCP3M2myndqXuAEKKnqm_7SQ~|~8~|~1~:~1~|~936243~|~2~|~Phaera - Ignition~|~3~|~50872~|~4~|~TheArcadium~|~5~|~5.76~|~6~|~~|~10~|~https%3A%2F%2Faudio.ngfiles.com%2F936000%2F936243_Phaera---Ignition.mp3%3Ff1590147327~|~7~|~~|~8~|~1#11389:0:10#f687963dcfd37f857633563ee28b0cfadc727c97";
@@ -26,3 +32,228 @@ fn process_get_gj_levels_response() {
         }
     }
 }
+
+#[test]
+fn get_gj_levels_response_page() {
+    let page = dash_rs::response::parse_get_gj_levels_response(GET_GJ_LEVELS_RESPONSE).unwrap();
+
+    assert_eq!(page.len(), 10);
+    assert_eq!(page.total, 11389);
+    assert_eq!(page.offset, 0);
+    assert_eq!(page.page_size, 10);
+    assert!(!page.is_last());
+}
+
+#[test]
+fn get_gj_levels_response_hash_mismatch() {
+    let tampered = GET_GJ_LEVELS_RESPONSE.replace("f687963dcfd37f857633563ee28b0cfadc727c97", "0000000000000000000000000000000000000000");
+
+    assert!(matches!(
+        dash_rs::response::parse_get_gj_levels_response(&tampered),
+        Err(ResponseError::HashMismatch)
+    ));
+}
+
+const GET_GJ_ACCOUNT_COMMENTS_RESPONSE: &str =
+    "2~SGVsbG8gV29ybGQ=~4~12~9~2 months~6~1234|2~VGVzdGluZw==~4~-3~9~1 year~6~1200#22:20:10";
+
+#[test]
+fn get_gj_account_comments_response_page() {
+    let page = dash_rs::response::parse_get_gj_acccount_comments_response(GET_GJ_ACCOUNT_COMMENTS_RESPONSE).unwrap();
+
+    assert_eq!(page.len(), 2);
+    assert_eq!(page[0].comment_id, 1234);
+    assert_eq!((page.total, page.offset, page.page_size), (22, 20, 10));
+    assert!(page.is_last());
+}
+
+#[test]
+fn login_gj_account_response() {
+    assert_eq!(
+        dash_rs::response::parse_login_gj_account_response("57903,8451").unwrap(),
+        LoginResponse {
+            account_id: 57903,
+            user_id: 8451
+        }
+    );
+}
+
+#[test]
+fn login_gj_account_response_error_codes() {
+    assert!(matches!(
+        dash_rs::response::parse_login_gj_account_response("-11"),
+        Err(ResponseError::Authentication(AuthenticationError::InvalidCredentials))
+    ));
+    assert!(matches!(
+        dash_rs::response::parse_login_gj_account_response("-12"),
+        Err(ResponseError::Authentication(AuthenticationError::AccountDisabled))
+    ));
+    assert!(matches!(
+        dash_rs::response::parse_login_gj_account_response("-1"),
+        Err(ResponseError::Authentication(AuthenticationError::LoginFailed))
+    ));
+}
+
+#[test]
+fn register_gj_account_response() {
+    assert!(dash_rs::response::parse_register_gj_account_response("1").is_ok());
+    assert!(matches!(
+        dash_rs::response::parse_register_gj_account_response("-2"),
+        Err(ResponseError::Registration(RegistrationError::UserNameTaken))
+    ));
+    assert!(matches!(
+        dash_rs::response::parse_register_gj_account_response("-3"),
+        Err(ResponseError::Registration(RegistrationError::EmailTaken))
+    ));
+}
+
+#[test]
+fn get_account_url_response() {
+    assert_eq!(
+        dash_rs::response::parse_get_account_url_response("http://www.robtopgames.org").unwrap(),
+        "http://www.robtopgames.org"
+    );
+    assert!(matches!(
+        dash_rs::response::parse_get_account_url_response("-1"),
+        Err(ResponseError::NotFound)
+    ));
+}
+
+#[test]
+fn sync_gj_account_response() {
+    let response = dash_rs::response::parse_sync_gj_account_response("H4sIAAAAAAAAC_manager;H4sIAAAAAAAAC_levels;21;35;a;b").unwrap();
+
+    assert_eq!(response.game_manager, "H4sIAAAAAAAAC_manager");
+    assert_eq!(response.local_levels, "H4sIAAAAAAAAC_levels");
+    assert_eq!(response.game_version, Some(21));
+
+    assert!(matches!(
+        dash_rs::response::parse_sync_gj_account_response("-2"),
+        Err(ResponseError::Authentication(AuthenticationError::InvalidCredentials))
+    ));
+}
+
+const GET_GJ_MAP_PACKS_RESPONSE: &str = "1:1:2:Easy Map Pack:3:1,2,3:4:4:5:2:6:1:7:255,255,0:8:255,255,0|1:6:2:Demon Map \
+                                         Pack:3:5001,5002,5003:4:10:5:2:6:6:7:255,0,0:8:255,0,0#45:0:10#\
+                                         4d49a933206ec66cc9f51fffe4cded3204a142cd";
+
+#[test]
+fn get_gj_map_packs_response() {
+    let page = dash_rs::response::parse_get_gj_map_packs_response(GET_GJ_MAP_PACKS_RESPONSE);
+    assert!(page.is_ok(), "{}", page.unwrap_err());
+
+    let page = page.unwrap();
+
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.total, 45);
+    assert_eq!(page[1].name, "Demon Map Pack");
+    assert_eq!(page[1].levels, vec![5001, 5002, 5003]);
+    assert_eq!(page[0].bar_color, RgbColor { red: 255, green: 255, blue: 0 });
+}
+
+#[test]
+fn get_gj_map_packs_response_hash_mismatch() {
+    let tampered = GET_GJ_MAP_PACKS_RESPONSE.replace(":4:10:", ":4:11:");
+
+    assert!(matches!(
+        dash_rs::response::parse_get_gj_map_packs_response(&tampered),
+        Err(ResponseError::HashMismatch)
+    ));
+}
+
+const GET_GJ_GAUNTLETS_RESPONSE: &str = "1:1:3:27732941,28200611,27483789,28225110,27448202|1:2:3:20635816,28151870,25969464,24302376,\
+                                         27399722#de19dff7ee227987c3e22746a65035089122fe06";
+
+#[test]
+fn get_gj_gauntlets_response() {
+    let gauntlets = dash_rs::response::parse_get_gj_gauntlets_response(GET_GJ_GAUNTLETS_RESPONSE);
+    assert!(gauntlets.is_ok(), "{}", gauntlets.unwrap_err());
+
+    let gauntlets = gauntlets.unwrap();
+
+    assert_eq!(gauntlets.len(), 2);
+    assert_eq!(gauntlets[1].name(), Some("Ice"));
+    assert_eq!(gauntlets[1].levels[4], 27399722);
+}
+
+#[test]
+fn get_gj_gauntlets_response_hash_mismatch() {
+    let tampered = GET_GJ_GAUNTLETS_RESPONSE.replace("27448202", "27448203");
+
+    assert!(matches!(
+        dash_rs::response::parse_get_gj_gauntlets_response(&tampered),
+        Err(ResponseError::HashMismatch)
+    ));
+}
+
+const GET_GJ_SCORES_RESPONSE: &str = "1:Michigun:2:703929:13:149:17:12312:6:1:9:22:10:15:11:12:14:0:15:2:16:34499:3:61161:8:16:46:11522:4:997|\
+                                      1:Serponge:2:4170784:13:149:17:8432:6:2:9:35:10:12:11:3:14:0:15:0:16:119741:3:54321:8:48:46:23112:4:640|";
+
+#[test]
+fn get_gj_scores_response() {
+    let users = dash_rs::response::parse_get_gj_scores_response(GET_GJ_SCORES_RESPONSE);
+    assert!(users.is_ok(), "{}", users.unwrap_err());
+
+    let users = users.unwrap();
+
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[0].rank, 1);
+    assert_eq!(users[1].name, "Serponge");
+    assert_eq!(users[1].rank, 2);
+    assert!(!users[1].has_glow);
+}
+
+const GET_GJ_LEVEL_SCORES_RESPONSE: &str = "1:Michigun:2:703929:9:22:10:15:11:12:14:0:15:2:16:34499:3:100:6:1:42:3 years:13:3|\
+                                            1:Serponge:2:4170784:9:35:10:12:11:3:14:1:15:0:16:119741:3:87:6:2:42:2 weeks:13:1|";
+
+#[test]
+fn get_gj_level_scores_response() {
+    let scores = dash_rs::response::parse_get_gj_level_scores_response(GET_GJ_LEVEL_SCORES_RESPONSE);
+    assert!(scores.is_ok(), "{}", scores.unwrap_err());
+
+    let scores = scores.unwrap();
+
+    assert_eq!(scores.len(), 2);
+    assert_eq!(scores[1].score, 87);
+    assert_eq!(scores[1].rank, 2);
+    assert_eq!(scores[1].age, "2 weeks");
+    assert_eq!(scores[1].icon_type, IconType::Ship);
+}
+
+const GET_GJ_MESSAGES_RESPONSE: &str = "6:Michigun:3:703929:2:34499:1:64297155:4:WW91ciBsZXZlbA==:8:1:9:0:7:2 days|6:Serponge:3:4170784:2:\
+                                        119741:1:64297012:4:VGhhbmtz:8:0:9:0:7:3 days#2:0:50";
+
+#[test]
+fn get_gj_messages_response() {
+    let page = dash_rs::response::parse_get_gj_messages_response(GET_GJ_MESSAGES_RESPONSE);
+    assert!(page.is_ok(), "{}", page.unwrap_err());
+
+    let mut page = page.unwrap();
+
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.total, 2);
+    assert!(page.is_last());
+    assert_eq!(page[1].folder, MessageFolder::Inbox);
+    assert_eq!(page.items[1].subject.process().unwrap().0, "Thanks");
+}
+
+#[test]
+fn get_gj_messages_response_empty_folder() {
+    let page = dash_rs::response::parse_get_gj_messages_response("-2").unwrap();
+
+    assert!(page.is_empty());
+    assert!(page.is_last());
+}
+
+#[test]
+fn download_gj_message_response() {
+    let message = dash_rs::response::parse_download_gj_message_response(
+        "6:Michigun:3:703929:2:34499:1:64297155:4:WW91ciBsZXZlbA==:5:dltdURFdQVFeEUZdRl0RRVxXFV1UQldZEA==:8:1:9:1:7:2 days",
+    );
+    assert!(message.is_ok(), "{}", message.unwrap_err());
+
+    let message = message.unwrap().into_owned();
+
+    assert_eq!(message.folder, MessageFolder::Sent);
+    assert_eq!(message.body.unwrap().into_processed().unwrap().0, "Good luck with the level!");
+}
@@ -0,0 +1,123 @@
+use dash_rs::{
+    model::level::{
+        local_level::Objects,
+        object::{gameplay::StartPosition, ids, speed::Speed, LevelObject, ObjectData},
+        timeline::Timeline,
+    },
+    ThunkContent,
+};
+
+const DARK_REALM_DATA: &str = include_str!("data/11774780_dark_realm_raw_level_data");
+
+fn normal() -> f32 {
+    f32::from(Speed::Normal)
+}
+
+fn fast() -> f32 {
+    f32::from(Speed::Fast)
+}
+
+fn medium() -> f32 {
+    f32::from(Speed::Medium)
+}
+
+fn object(id: u16, x: f32, metadata: ObjectData) -> LevelObject {
+    LevelObject {
+        id,
+        x,
+        metadata,
+        ..LevelObject::default()
+    }
+}
+
+fn speed_portal(id: u16, x: f32, speed: Speed) -> LevelObject {
+    object(id, x, ObjectData::SpeedPortal { checked: true, speed })
+}
+
+fn level(objects: Vec<LevelObject>) -> Objects {
+    Objects {
+        meta: Default::default(),
+        objects,
+    }
+}
+
+#[test]
+fn timeline_without_portals() {
+    let timeline = Timeline::new(0.0, Speed::Normal, Vec::new());
+
+    assert_eq!(timeline.time_at(normal()), 1.0);
+    assert_eq!(timeline.x_at(2.0), 2.0 * normal());
+    assert_eq!(timeline.speed_at(100.0), Speed::Normal);
+}
+
+#[test]
+fn timeline_with_portals() {
+    // Unsorted on purpose
+    let timeline = Timeline::new(0.0, Speed::Normal, vec![(normal() + fast(), Speed::Normal), (normal(), Speed::Fast)]);
+
+    assert_eq!(timeline.segments().len(), 3);
+    assert_eq!(timeline.speed_at(normal() + 1.0), Speed::Fast);
+    assert_eq!(timeline.time_at(normal() + fast()), 2.0);
+    assert_eq!(timeline.time_at(2.0 * normal() + fast()), 3.0);
+    assert_eq!(timeline.x_at(1.5), normal() + fast() / 2.0);
+    assert_eq!(timeline.x_at(3.0), 2.0 * normal() + fast());
+}
+
+#[test]
+fn length_honours_all_speed_portals() {
+    let objects = level(vec![
+        speed_portal(ids::MEDIUM_PORTAL, normal(), Speed::Medium),
+        object(1, normal() + medium(), ObjectData::None),
+    ]);
+
+    assert_eq!(objects.length_in_seconds(), 2.0);
+}
+
+#[test]
+fn length_with_portal_after_furthest_block() {
+    let objects = level(vec![
+        speed_portal(ids::FAST_PORTAL, 2.0 * normal(), Speed::Fast),
+        object(1, normal(), ObjectData::None),
+        speed_portal(ids::NORMAL_PORTAL, normal() / 2.0, Speed::Normal),
+    ]);
+
+    assert_eq!(objects.length_in_seconds(), 2.0);
+}
+
+#[test]
+fn timeline_from_start_position() {
+    let start_position = object(
+        ids::START_POSITION,
+        normal(),
+        ObjectData::StartPosition(StartPosition {
+            speed: Speed::Fast,
+            ..StartPosition::default()
+        }),
+    );
+    let objects = level(vec![
+        speed_portal(ids::SLOW_PORTAL, normal() / 2.0, Speed::Slow),
+        start_position.clone(),
+        speed_portal(ids::NORMAL_PORTAL, normal() + fast(), Speed::Normal),
+    ]);
+
+    let timeline = Timeline::for_start_position(&objects, &start_position).unwrap();
+
+    assert_eq!(timeline.start_x(), normal());
+    assert_eq!(timeline.speed_at(normal()), Speed::Fast);
+    assert!((timeline.time_at(2.0 * normal() + fast()) - 2.0).abs() < 1e-5);
+    assert!(Timeline::for_start_position(&objects, &objects.objects[0]).is_none());
+}
+
+#[test]
+fn dark_realm_timeline() {
+    let objects = Objects::from_unprocessed(DARK_REALM_DATA.trim()).unwrap();
+    let timeline = objects.timeline();
+    let length = objects.length_in_seconds();
+
+    assert!(timeline.segments().iter().any(|segment| segment.speed == Speed::Medium));
+    assert!((70.0..72.0).contains(&length), "{}", length);
+
+    for segment in timeline.segments() {
+        assert!((timeline.x_at(timeline.time_at(segment.x + 10.0)) - (segment.x + 10.0)).abs() < 0.1);
+    }
+}